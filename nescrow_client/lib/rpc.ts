@@ -35,12 +35,22 @@ export enum NescrowInstruction {
  * 1. `[writable]` escrow: {@link Escrow} 
  * 2. `[writable, signer]` creator: {@link PublicKey} 
  * 3. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 4. `[]` event: {@link Event} Optional, named by an event or event score oracle
  *
  * Data:
  * - counter: {@link BigInt} Counter to make the escrow PDA unique
  * - amount: {@link BigInt} The amount of lamports to wager
  * - description: {@link string} type
  * - expiry_time: {@link BigInt} The time when the escrow expires (Unix timestamp)
+ * - oracle_kind: {@link number} Kind of oracle that can settle the escrow, none by default
+ * - oracle_account: {@link PublicKey} Attestation signer, price feed or event of the oracle
+ * - price_condition: {@link PriceCondition} Threshold for price feed oracles
+ * - event_condition: {@link EventCondition} Outcomes backed by each side for event oracles
+ * - game: {@link GameTerms} Commit-reveal game settling the escrow, with the creator's commitment
+ * - max_extension: {@link BigInt} Seconds the creator may extend the expiry alone once accepted
+ * - min_fill: {@link BigInt} Fill at which the creator may accept, for escrows taken through partial fills
+ * - line: {@link Line} Spread or total settling the escrow from an event's score
+ * - series_length: {@link number} Number of games, odd, in a best-of-N series settling the escrow
  */
     CreateEscrow = 0,
 
@@ -51,10 +61,12 @@ export enum NescrowInstruction {
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[writable]` escrow: {@link Escrow} 
  * 2. `[writable, signer]` taker: {@link PublicKey} 
+ * 3. `[]` system_program: {@link PublicKey} Used to move the taker's stake into the escrow
  *
  * Data:
  * - creator: {@link PublicKey} 
  * - counter: {@link BigInt} 
+ * - commitment: {@link Uint8Array} The taker's commitment, required for games
  */
     AcceptEscrow = 1,
 
@@ -93,6 +105,7 @@ export enum NescrowInstruction {
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[writable]` escrow: {@link Escrow} 
  * 2. `[signer]` creator: {@link PublicKey} 
+ * 3. `[signer]` taker: {@link PublicKey} Optional, consents to extensions beyond the agreed maximum
  *
  * Data:
  * - counter: {@link BigInt} 
@@ -104,10 +117,20 @@ export enum NescrowInstruction {
 export type CreateEscrowArgs = {
 	feePayer: PublicKey;
 	creator: PublicKey;
+	event: PublicKey | undefined;
 	counter: bigint;
 	amount: bigint;
 	description: string;
 	expiryTime: bigint;
+	oracleKind: number;
+	oracleAccount: PublicKey;
+	priceCondition: T.PriceCondition | undefined;
+	eventCondition: T.EventCondition | undefined;
+	game: T.GameTerms | undefined;
+	maxExtension: bigint;
+	minFill: bigint | undefined;
+	line: T.Line | undefined;
+	seriesLength: number | undefined;
 };
 
 /**
@@ -119,12 +142,22 @@ export type CreateEscrowArgs = {
  * 1. `[writable]` escrow: {@link Escrow} 
  * 2. `[writable, signer]` creator: {@link PublicKey} 
  * 3. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 4. `[]` event: {@link Event} Optional, named by an event or event score oracle
  *
  * Data:
  * - counter: {@link BigInt} Counter to make the escrow PDA unique
  * - amount: {@link BigInt} The amount of lamports to wager
  * - description: {@link string} type
  * - expiry_time: {@link BigInt} The time when the escrow expires (Unix timestamp)
+ * - oracle_kind: {@link number} Kind of oracle that can settle the escrow, none by default
+ * - oracle_account: {@link PublicKey} Attestation signer, price feed or event of the oracle
+ * - price_condition: {@link PriceCondition} Threshold for price feed oracles
+ * - event_condition: {@link EventCondition} Outcomes backed by each side for event oracles
+ * - game: {@link GameTerms} Commit-reveal game settling the escrow, with the creator's commitment
+ * - max_extension: {@link BigInt} Seconds the creator may extend the expiry alone once accepted
+ * - min_fill: {@link BigInt} Fill at which the creator may accept, for escrows taken through partial fills
+ * - line: {@link Line} Spread or total settling the escrow from an event's score
+ * - series_length: {@link number} Number of games, odd, in a best-of-N series settling the escrow
  */
 export const createEscrow = (args: CreateEscrowArgs, remainingAccounts: Array<PublicKey> = []): TransactionInstruction => {
 		const data = serialize(
@@ -135,6 +168,15 @@ export const createEscrow = (args: CreateEscrowArgs, remainingAccounts: Array<Pu
 								amount: "u64",
 								description: "string",
 								expiry_time: "i64",
+								oracle_kind: "u8",
+								oracle_account: { array: { type: "u8", len: 32 } },
+								price_condition: { option: T.PriceConditionSchema },
+								event_condition: { option: T.EventConditionSchema },
+								game: { option: T.GameTermsSchema },
+								max_extension: "i64",
+								min_fill: { option: "u64" },
+								line: { option: T.LineSchema },
+								series_length: { option: "u8" },
             },
         },
         {
@@ -143,6 +185,15 @@ export const createEscrow = (args: CreateEscrowArgs, remainingAccounts: Array<Pu
 						amount: args.amount,
 						description: args.description,
 						expiry_time: args.expiryTime,
+						oracle_kind: args.oracleKind,
+						oracle_account: args.oracleAccount.toBytes(),
+						price_condition: args.priceCondition && T.encodePriceCondition(args.priceCondition),
+						event_condition: args.eventCondition && T.encodeEventCondition(args.eventCondition),
+						game: args.game && T.encodeGameTerms(args.game),
+						max_extension: args.maxExtension,
+						min_fill: args.minFill,
+						line: args.line && T.encodeLine(args.line),
+						series_length: args.seriesLength,
         }
     );
 
@@ -158,6 +209,7 @@ export const createEscrow = (args: CreateEscrowArgs, remainingAccounts: Array<Pu
 						{pubkey: escrowPubkey, isSigner: false, isWritable: true},
 						{pubkey: args.creator, isSigner: true, isWritable: true},
 						{pubkey: new PublicKey("11111111111111111111111111111111"), isSigner: false, isWritable: false},
+						...(args.event ? [{pubkey: args.event, isSigner: false, isWritable: false}] : []),
             ...remainingAccounts.map(e => ({pubkey: e, isSigner: false, isWritable: false})),
         ],
         programId: _programId,
@@ -173,12 +225,22 @@ export const createEscrow = (args: CreateEscrowArgs, remainingAccounts: Array<Pu
  * 1. `[writable]` escrow: {@link Escrow} 
  * 2. `[writable, signer]` creator: {@link PublicKey} 
  * 3. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 4. `[]` event: {@link Event} Optional, named by an event or event score oracle
  *
  * Data:
  * - counter: {@link BigInt} Counter to make the escrow PDA unique
  * - amount: {@link BigInt} The amount of lamports to wager
  * - description: {@link string} type
  * - expiry_time: {@link BigInt} The time when the escrow expires (Unix timestamp)
+ * - oracle_kind: {@link number} Kind of oracle that can settle the escrow, none by default
+ * - oracle_account: {@link PublicKey} Attestation signer, price feed or event of the oracle
+ * - price_condition: {@link PriceCondition} Threshold for price feed oracles
+ * - event_condition: {@link EventCondition} Outcomes backed by each side for event oracles
+ * - game: {@link GameTerms} Commit-reveal game settling the escrow, with the creator's commitment
+ * - max_extension: {@link BigInt} Seconds the creator may extend the expiry alone once accepted
+ * - min_fill: {@link BigInt} Fill at which the creator may accept, for escrows taken through partial fills
+ * - line: {@link Line} Spread or total settling the escrow from an event's score
+ * - series_length: {@link number} Number of games, odd, in a best-of-N series settling the escrow
  */
 export const createEscrowSendAndConfirm = async (
 	args: Omit<CreateEscrowArgs, "feePayer" | "creator"> & {
@@ -213,6 +275,7 @@ export type AcceptEscrowArgs = {
 	taker: PublicKey;
 	creator: PublicKey;
 	counter: bigint;
+	commitment: Uint8Array | undefined;
 };
 
 /**
@@ -223,10 +286,12 @@ export type AcceptEscrowArgs = {
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[writable]` escrow: {@link Escrow} 
  * 2. `[writable, signer]` taker: {@link PublicKey} 
+ * 3. `[]` system_program: {@link PublicKey} Used to move the taker's stake into the escrow
  *
  * Data:
  * - creator: {@link PublicKey} 
  * - counter: {@link BigInt} 
+ * - commitment: {@link Uint8Array} The taker's commitment, required for games
  */
 export const acceptEscrow = (args: AcceptEscrowArgs, remainingAccounts: Array<PublicKey> = []): TransactionInstruction => {
 		const data = serialize(
@@ -235,12 +300,14 @@ export const acceptEscrow = (args: AcceptEscrowArgs, remainingAccounts: Array<Pu
                 id: "u8",
 								creator: { array: { type: "u8", len: 32 } },
 								counter: "u64",
+								commitment: { option: { array: { type: "u8", len: 32 } } },
             },
         },
         {
             id: NescrowInstruction.AcceptEscrow,
 						creator: args.creator.toBytes(),
 						counter: args.counter,
+						commitment: args.commitment,
         }
     );
 
@@ -255,6 +322,7 @@ export const acceptEscrow = (args: AcceptEscrowArgs, remainingAccounts: Array<Pu
 						{pubkey: args.feePayer, isSigner: true, isWritable: true},
 						{pubkey: escrowPubkey, isSigner: false, isWritable: true},
 						{pubkey: args.taker, isSigner: true, isWritable: true},
+						{pubkey: new PublicKey("11111111111111111111111111111111"), isSigner: false, isWritable: false},
             ...remainingAccounts.map(e => ({pubkey: e, isSigner: false, isWritable: false})),
         ],
         programId: _programId,
@@ -269,10 +337,12 @@ export const acceptEscrow = (args: AcceptEscrowArgs, remainingAccounts: Array<Pu
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[writable]` escrow: {@link Escrow} 
  * 2. `[writable, signer]` taker: {@link PublicKey} 
+ * 3. `[]` system_program: {@link PublicKey} Used to move the taker's stake into the escrow
  *
  * Data:
  * - creator: {@link PublicKey} 
  * - counter: {@link BigInt} 
+ * - commitment: {@link Uint8Array} The taker's commitment, required for games
  */
 export const acceptEscrowSendAndConfirm = async (
 	args: Omit<AcceptEscrowArgs, "feePayer" | "taker"> & {
@@ -492,6 +562,7 @@ export const cancelEscrowSendAndConfirm = async (
 export type ExtendEscrowArgs = {
 	feePayer: PublicKey;
 	creator: PublicKey;
+	taker: PublicKey | undefined;
 	counter: bigint;
 	newExpiryTime: bigint;
 };
@@ -504,6 +575,7 @@ export type ExtendEscrowArgs = {
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[writable]` escrow: {@link Escrow} 
  * 2. `[signer]` creator: {@link PublicKey} 
+ * 3. `[signer]` taker: {@link PublicKey} Optional, consents to extensions beyond the agreed maximum
  *
  * Data:
  * - counter: {@link BigInt} 
@@ -536,6 +608,7 @@ export const extendEscrow = (args: ExtendEscrowArgs, remainingAccounts: Array<Pu
 						{pubkey: args.feePayer, isSigner: true, isWritable: true},
 						{pubkey: escrowPubkey, isSigner: false, isWritable: true},
 						{pubkey: args.creator, isSigner: true, isWritable: false},
+						...(args.taker ? [{pubkey: args.taker, isSigner: true, isWritable: false}] : []),
             ...remainingAccounts.map(e => ({pubkey: e, isSigner: false, isWritable: false})),
         ],
         programId: _programId,
@@ -550,16 +623,18 @@ export const extendEscrow = (args: ExtendEscrowArgs, remainingAccounts: Array<Pu
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[writable]` escrow: {@link Escrow} 
  * 2. `[signer]` creator: {@link PublicKey} 
+ * 3. `[signer]` taker: {@link PublicKey} Optional, consents to extensions beyond the agreed maximum
  *
  * Data:
  * - counter: {@link BigInt} 
  * - new_expiry_time: {@link BigInt} The new expiry time for the escrow (Unix timestamp)
  */
 export const extendEscrowSendAndConfirm = async (
-	args: Omit<ExtendEscrowArgs, "feePayer" | "creator"> & {
+	args: Omit<ExtendEscrowArgs, "feePayer" | "creator" | "taker"> & {
 	  signers: {
 			feePayer: Keypair,
 			creator: Keypair,
+			taker?: Keypair,
 	  }
   }, 
   remainingAccounts: Array<PublicKey> = []
@@ -571,6 +646,7 @@ export const extendEscrowSendAndConfirm = async (
 		...args,
 		feePayer: args.signers.feePayer.publicKey,
 		creator: args.signers.creator.publicKey,
+		taker: args.signers.taker?.publicKey,
 	}, remainingAccounts));
 
   return await sendAndConfirmTransaction(
@@ -579,6 +655,7 @@ export const extendEscrowSendAndConfirm = async (
     [
 				args.signers.feePayer,
 				args.signers.creator,
+				...(args.signers.taker ? [args.signers.taker] : []),
     ]
   );
 };
@@ -18,6 +18,285 @@ export const EscrowStatusSchema: Schema =  {
     }
 };
 
+/// Outcome of a two-party escrow, as reported by its oracle
+export enum Outcome {
+  CreatorWins = 0,
+  TakerWins = 1,
+}
+
+export const decodeOutcome = (decoded: Decoded): Outcome =>
+    "CreatorWins" in decoded ? Outcome.CreatorWins : Outcome.TakerWins;
+
+export const encodeOutcome = (outcome: Outcome): Decoded =>
+    outcome === Outcome.CreatorWins ? { CreatorWins: {} } : { TakerWins: {} };
+
+export const OutcomeSchema: Schema = {
+    enum: [
+        { struct: { CreatorWins: { struct: {} } } },
+        { struct: { TakerWins: { struct: {} } } },
+    ]
+};
+
+/// Creator wins if the oracle's price is at or above `threshold` at or after `resolveAfter`
+export interface PriceCondition {
+  threshold: bigint;
+  exponent: number;
+  resolveAfter: bigint;
+  maxStaleness: bigint;
+  maxConfidenceBps: number;
+}
+
+export const decodePriceCondition = (decoded: Decoded): PriceCondition => ({
+    threshold: decoded["threshold"] as bigint,
+    exponent: decoded["exponent"] as number,
+    resolveAfter: decoded["resolve_after"] as bigint,
+    maxStaleness: decoded["max_staleness"] as bigint,
+    maxConfidenceBps: decoded["max_confidence_bps"] as number,
+});
+
+export const encodePriceCondition = (condition: PriceCondition): Decoded => ({
+    threshold: condition.threshold,
+    exponent: condition.exponent,
+    resolve_after: condition.resolveAfter,
+    max_staleness: condition.maxStaleness,
+    max_confidence_bps: condition.maxConfidenceBps,
+});
+
+export const PriceConditionSchema: Schema = {
+    struct: {
+        threshold: "i64",
+        exponent: "i32",
+        resolve_after: "i64",
+        max_staleness: "i64",
+        max_confidence_bps: "u16",
+    }
+};
+
+/// Outcomes of an event backed by each side of an escrow
+export interface EventCondition {
+  creatorOutcome: number;
+  takerOutcome: number;
+}
+
+export const decodeEventCondition = (decoded: Decoded): EventCondition => ({
+    creatorOutcome: decoded["creator_outcome"] as number,
+    takerOutcome: decoded["taker_outcome"] as number,
+});
+
+export const encodeEventCondition = (condition: EventCondition): Decoded => ({
+    creator_outcome: condition.creatorOutcome,
+    taker_outcome: condition.takerOutcome,
+});
+
+export const EventConditionSchema: Schema = {
+    struct: {
+        creator_outcome: "u8",
+        taker_outcome: "u8",
+    }
+};
+
+/// Terms of a commit-reveal game settling an escrow, with the creator's commitment
+export interface GameTerms {
+  gameKind: number;
+  revealWindow: bigint;
+  commitment: Uint8Array;
+}
+
+export const encodeGameTerms = (terms: GameTerms): Decoded => ({
+    game_kind: terms.gameKind,
+    reveal_window: terms.revealWindow,
+    commitment: terms.commitment,
+});
+
+export const GameTermsSchema: Schema = {
+    struct: {
+        game_kind: "u8",
+        reveal_window: "i64",
+        commitment: { array: { type: "u8", len: 32 } },
+    }
+};
+
+/// Spread or total line settling an escrow from an event's score, in half points
+export type Line =
+  | { kind: "spread"; creatorHome: boolean; handicap: number }
+  | { kind: "total"; creatorOver: boolean; total: number };
+
+export const decodeLine = (decoded: Decoded): Line => {
+    const spread = decoded["Spread"] as Decoded | undefined;
+    if (spread) {
+        return {
+            kind: "spread",
+            creatorHome: spread["creator_home"] as boolean,
+            handicap: spread["handicap"] as number,
+        };
+    }
+
+    const total = decoded["Total"] as Decoded;
+    return {
+        kind: "total",
+        creatorOver: total["creator_over"] as boolean,
+        total: total["total"] as number,
+    };
+};
+
+export const encodeLine = (line: Line): Decoded =>
+    line.kind === "spread"
+        ? { Spread: { creator_home: line.creatorHome, handicap: line.handicap } }
+        : { Total: { creator_over: line.creatorOver, total: line.total } };
+
+export const LineSchema: Schema = {
+    enum: [
+        { struct: { Spread: { struct: { creator_home: "bool", handicap: "i32" } } } },
+        { struct: { Total: { struct: { creator_over: "bool", total: "u32" } } } },
+    ]
+};
+
+/// Commit-reveal game played between the two parties of an escrow
+export interface Game {
+  gameKind: number;
+  revealWindow: bigint;
+  revealDeadline: bigint;
+  creatorCommitment: Uint8Array;
+  takerCommitment: Uint8Array;
+  creatorChoice: number | undefined;
+  takerChoice: number | undefined;
+}
+
+export const decodeGame = (decoded: Decoded): Game => ({
+    gameKind: decoded["game_kind"] as number,
+    revealWindow: decoded["reveal_window"] as bigint,
+    revealDeadline: decoded["reveal_deadline"] as bigint,
+    creatorCommitment: Uint8Array.from(decoded["creator_commitment"] as number[]),
+    takerCommitment: Uint8Array.from(decoded["taker_commitment"] as number[]),
+    creatorChoice: (decoded["creator_choice"] as number | null) ?? undefined,
+    takerChoice: (decoded["taker_choice"] as number | null) ?? undefined,
+});
+
+export const GameSchema: Schema = {
+    struct: {
+        game_kind: "u8",
+        reveal_window: "i64",
+        reveal_deadline: "i64",
+        creator_commitment: { array: { type: "u8", len: 32 } },
+        taker_commitment: { array: { type: "u8", len: 32 } },
+        creator_choice: { option: "u8" },
+        taker_choice: { option: "u8" },
+    }
+};
+
+/// Linear release of an escrow's amount to its taker
+export interface Stream {
+  startTime: bigint;
+  endTime: bigint;
+  withdrawn: bigint;
+}
+
+export const decodeStream = (decoded: Decoded): Stream => ({
+    startTime: decoded["start_time"] as bigint,
+    endTime: decoded["end_time"] as bigint,
+    withdrawn: decoded["withdrawn"] as bigint,
+});
+
+export const StreamSchema: Schema = {
+    struct: {
+        start_time: "i64",
+        end_time: "i64",
+        withdrawn: "u64",
+    }
+};
+
+/// Equal tranches of an escrow's amount released to its taker once per period
+export interface Schedule {
+  tranche: bigint;
+  startTime: bigint;
+  periodLength: bigint;
+  periods: number;
+  released: number;
+}
+
+export const decodeSchedule = (decoded: Decoded): Schedule => ({
+    tranche: decoded["tranche"] as bigint,
+    startTime: decoded["start_time"] as bigint,
+    periodLength: decoded["period_length"] as bigint,
+    periods: decoded["periods"] as number,
+    released: decoded["released"] as number,
+});
+
+export const ScheduleSchema: Schema = {
+    struct: {
+        tranche: "u64",
+        start_time: "i64",
+        period_length: "i64",
+        periods: "u32",
+        released: "u32",
+    }
+};
+
+/// Partial fills of an escrow's amount by any number of takers
+export interface Fills {
+  minFill: bigint;
+  filled: bigint;
+  unclaimed: bigint;
+}
+
+export const decodeFills = (decoded: Decoded): Fills => ({
+    minFill: decoded["min_fill"] as bigint,
+    filled: decoded["filled"] as bigint,
+    unclaimed: decoded["unclaimed"] as bigint,
+});
+
+export const FillsSchema: Schema = {
+    struct: {
+        min_fill: "u64",
+        filled: "u64",
+        unclaimed: "u64",
+    }
+};
+
+/// Supply-1 tokens standing for the sides of an escrow, with the pot held
+/// for whoever redeems the winning side's token
+export interface PositionTokens {
+  creatorMint: PublicKey | undefined;
+  takerMint: PublicKey | undefined;
+  held: bigint;
+}
+
+export const decodePositionTokens = (decoded: Decoded): PositionTokens => ({
+    creatorMint: decoded["creator_mint"] ? new PublicKey(decoded["creator_mint"]) : undefined,
+    takerMint: decoded["taker_mint"] ? new PublicKey(decoded["taker_mint"]) : undefined,
+    held: decoded["held"] as bigint,
+});
+
+export const PositionTokensSchema: Schema = {
+    struct: {
+        creator_mint: { option: { array: { type: "u8", len: 32 } } },
+        taker_mint: { option: { array: { type: "u8", len: 32 } } },
+        held: "u64",
+    }
+};
+
+/// Best-of-N series settling an escrow once one side wins a majority of games
+export interface Series {
+  length: number;
+  results: Outcome[];
+}
+
+export const decodeSeries = (decoded: Decoded): Series => ({
+    length: decoded["length"] as number,
+    results: (decoded["results"] as Decoded[]).map(decodeOutcome),
+});
+
+export const SeriesSchema: Schema = {
+    struct: {
+        length: "u8",
+        results: { array: { type: OutcomeSchema } },
+    }
+};
+
+/// Decode an optional nested value, which borsh reads back as null when absent
+const decodeOption = <T>(value: unknown, decode: (decoded: Decoded) => T): T | undefined =>
+    value ? decode(value as Decoded) : undefined;
+
 /// Escrow account for wagering
 export interface Escrow {
   creator: PublicKey;
@@ -29,6 +308,23 @@ export interface Escrow {
   expiryTime: bigint;
   escrowBump: number;
   counter: bigint;
+  oracleKind: number;
+  oracleAccount: PublicKey;
+  priceCondition: PriceCondition | undefined;
+  eventCondition: EventCondition | undefined;
+  game: Game | undefined;
+  stream: Stream | undefined;
+  schedule: Schedule | undefined;
+  amendments: number;
+  maxExtension: bigint;
+  rentPayer: PublicKey;
+  fills: Fills | undefined;
+  originalCreator: PublicKey | undefined;
+  positionTokens: PositionTokens;
+  line: Line | undefined;
+  series: Series | undefined;
+  designatedTaker: PublicKey | undefined;
+  createdSlot: bigint;
 }
 
 export const decodeEscrow = (decoded: Decoded): Escrow => ({
@@ -41,6 +337,23 @@ export const decodeEscrow = (decoded: Decoded): Escrow => ({
     expiryTime: decoded["expiry_time"] as bigint,
     escrowBump: decoded["escrow_bump"] as number,
     counter: decoded["counter"] as bigint,
+    oracleKind: decoded["oracle_kind"] as number,
+    oracleAccount: new PublicKey(decoded["oracle_account"] as Uint8Array),
+    priceCondition: decodeOption(decoded["price_condition"], decodePriceCondition),
+    eventCondition: decodeOption(decoded["event_condition"], decodeEventCondition),
+    game: decodeOption(decoded["game"], decodeGame),
+    stream: decodeOption(decoded["stream"], decodeStream),
+    schedule: decodeOption(decoded["schedule"], decodeSchedule),
+    amendments: decoded["amendments"] as number,
+    maxExtension: decoded["max_extension"] as bigint,
+    rentPayer: new PublicKey(decoded["rent_payer"] as Uint8Array),
+    fills: decodeOption(decoded["fills"], decodeFills),
+    originalCreator: decoded["original_creator"] ? new PublicKey(decoded["original_creator"]) : undefined,
+    positionTokens: decodePositionTokens(decoded["position_tokens"] as Decoded),
+    line: decodeOption(decoded["line"], decodeLine),
+    series: decodeOption(decoded["series"], decodeSeries),
+    designatedTaker: decoded["designated_taker"] ? new PublicKey(decoded["designated_taker"]) : undefined,
+    createdSlot: decoded["created_slot"] as bigint,
 });
 
 export const EscrowSchema: Schema =  {
@@ -54,6 +367,23 @@ export const EscrowSchema: Schema =  {
         expiry_time: "i64",
         escrow_bump: "u8",
         counter: "u64",
+        oracle_kind: "u8",
+        oracle_account: { array: { type: "u8", len: 32 } },
+        price_condition: { option: PriceConditionSchema },
+        event_condition: { option: EventConditionSchema },
+        game: { option: GameSchema },
+        stream: { option: StreamSchema },
+        schedule: { option: ScheduleSchema },
+        amendments: "u32",
+        max_extension: "i64",
+        rent_payer: { array: { type: "u8", len: 32 } },
+        fills: { option: FillsSchema },
+        original_creator: { option: { array: { type: "u8", len: 32 } } },
+        position_tokens: PositionTokensSchema,
+        line: { option: LineSchema },
+        series: { option: SeriesSchema },
+        designated_taker: { option: { array: { type: "u8", len: 32 } } },
+        created_slot: "u64",
     }
 };
 
@@ -148,6 +148,8 @@ export class NescrowClient {
   /**
    * Create a new escrow for wagering
    * 
+   * The escrow has no oracle and cannot be extended without the taker's consent.
+   * 
    * @param creator - Creator's keypair
    * @param amount - Amount of lamports to wager
    * @param description - Description of the wager
@@ -196,7 +198,17 @@ export class NescrowClient {
         counter,
         amount: amountLamports,
         description,
-        expiryTime: expiryTimestamp
+        expiryTime: expiryTimestamp,
+        oracleKind: 0,
+        oracleAccount: PublicKey.default,
+        priceCondition: undefined,
+        eventCondition: undefined,
+        game: undefined,
+        maxExtension: BigInt(0),
+        minFill: undefined,
+        line: undefined,
+        seriesLength: undefined,
+        event: undefined
       })
     );
     
@@ -228,6 +240,7 @@ export class NescrowClient {
    * @param counter - Escrow counter
    * @param taker - Taker's keypair
    * @param feePayer - Optional fee payer, defaults to taker
+   * @param commitment - Optional commitment of the taker, required for games
    * @returns Transaction signature
    * @throws If the transaction fails
    */
@@ -235,7 +248,8 @@ export class NescrowClient {
     creator: PublicKey,
     counter: bigint,
     taker: Keypair,
-    feePayer: Keypair = taker
+    feePayer: Keypair = taker,
+    commitment?: Uint8Array
   ): Promise<TransactionSignature> {
     // Create transaction
     const transaction = new Transaction();
@@ -246,7 +260,8 @@ export class NescrowClient {
         feePayer: feePayer.publicKey,
         taker: taker.publicKey,
         creator,
-        counter
+        counter,
+        commitment
       })
     );
     
@@ -354,6 +369,7 @@ export class NescrowClient {
    * @param counter - Escrow counter
    * @param newExpiryTime - New expiry time in seconds since epoch
    * @param feePayer - Optional fee payer, defaults to creator
+   * @param taker - Optional taker consenting to an extension beyond the agreed maximum
    * @returns Transaction signature
    * @throws If the transaction fails
    */
@@ -361,7 +377,8 @@ export class NescrowClient {
     creator: Keypair,
     counter: bigint,
     newExpiryTime: number | bigint,
-    feePayer: Keypair = creator
+    feePayer: Keypair = creator,
+    taker?: Keypair
   ): Promise<TransactionSignature> {
     // Validate inputs
     if (typeof newExpiryTime === 'number' && newExpiryTime <= Math.floor(Date.now() / 1000)) {
@@ -381,6 +398,7 @@ export class NescrowClient {
       rpc.extendEscrow({
         feePayer: feePayer.publicKey,
         creator: creator.publicKey,
+        taker: taker?.publicKey,
         counter,
         newExpiryTime: expiryTimestamp
       })
@@ -391,7 +409,7 @@ export class NescrowClient {
       return await sendAndConfirmTransaction(
         this.connection,
         transaction,
-        [feePayer, creator],
+        taker ? [feePayer, creator, taker] : [feePayer, creator],
         this.options.confirmOptions
       );
     } catch (error) {
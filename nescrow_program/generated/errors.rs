@@ -39,6 +39,134 @@ pub enum NescrowError {
 	InvalidWinner,
 	#[error("EscrowNotAccepted")]
 	EscrowNotAccepted,
+	#[error("NoOracle")]
+	NoOracle,
+	#[error("MissingAttestation")]
+	MissingAttestation,
+	#[error("InvalidAttestation")]
+	InvalidAttestation,
+	#[error("NoPriceCondition")]
+	NoPriceCondition,
+	#[error("InvalidPriceAccount")]
+	InvalidPriceAccount,
+	#[error("PriceConditionNotReady")]
+	PriceConditionNotReady,
+	#[error("StalePrice")]
+	StalePrice,
+	#[error("PriceConfidenceTooWide")]
+	PriceConfidenceTooWide,
+	#[error("InvalidEventOutcomes")]
+	InvalidEventOutcomes,
+	#[error("EventNotStarted")]
+	EventNotStarted,
+	#[error("EventAlreadyReported")]
+	EventAlreadyReported,
+	#[error("EventNotReported")]
+	EventNotReported,
+	#[error("InvalidEventOutcome")]
+	InvalidEventOutcome,
+	#[error("NotAGame")]
+	NotAGame,
+	#[error("InvalidCommitment")]
+	InvalidCommitment,
+	#[error("InvalidChoice")]
+	InvalidChoice,
+	#[error("AlreadyRevealed")]
+	AlreadyRevealed,
+	#[error("RevealDeadlinePassed")]
+	RevealDeadlinePassed,
+	#[error("RevealPending")]
+	RevealPending,
+	#[error("InvalidConditions")]
+	InvalidConditions,
+	#[error("ConditionsNotMet")]
+	ConditionsNotMet,
+	#[error("InvalidMilestones")]
+	InvalidMilestones,
+	#[error("MilestoneNotPending")]
+	MilestoneNotPending,
+	#[error("MilestoneDeadlineNotReached")]
+	MilestoneDeadlineNotReached,
+	#[error("NotAStream")]
+	NotAStream,
+	#[error("InvalidStream")]
+	InvalidStream,
+	#[error("NothingToWithdraw")]
+	NothingToWithdraw,
+	#[error("NotRecurring")]
+	NotRecurring,
+	#[error("InvalidSchedule")]
+	InvalidSchedule,
+	#[error("PeriodNotElapsed")]
+	PeriodNotElapsed,
+	#[error("InvalidStakeChange")]
+	InvalidStakeChange,
+	#[error("EmptyAmendment")]
+	EmptyAmendment,
+	#[error("InvalidExpiry")]
+	InvalidExpiry,
+	#[error("ExtensionNotAgreed")]
+	ExtensionNotAgreed,
+	#[error("InvalidPurchase")]
+	InvalidPurchase,
+	#[error("InvalidPurchaseStatus")]
+	InvalidPurchaseStatus,
+	#[error("InspectionWindowClosed")]
+	InspectionWindowClosed,
+	#[error("InspectionWindowOpen")]
+	InspectionWindowOpen,
+	#[error("NotMutuallyCancellable")]
+	NotMutuallyCancellable,
+	#[error("InvalidPool")]
+	InvalidPool,
+	#[error("PoolNotOpen")]
+	PoolNotOpen,
+	#[error("PoolFull")]
+	PoolFull,
+	#[error("JoinDeadlinePassed")]
+	JoinDeadlinePassed,
+	#[error("InvalidWinners")]
+	InvalidWinners,
+	#[error("PoolNotSettled")]
+	PoolNotSettled,
+	#[error("InvalidMarket")]
+	InvalidMarket,
+	#[error("MarketClosed")]
+	MarketClosed,
+	#[error("MarketNotResolved")]
+	MarketNotResolved,
+	#[error("ZeroStake")]
+	ZeroStake,
+	#[error("InvalidFill")]
+	InvalidFill,
+	#[error("MinFillNotReached")]
+	MinFillNotReached,
+	#[error("FillsNotSettled")]
+	FillsNotSettled,
+	#[error("InvalidCounterOffer")]
+	InvalidCounterOffer,
+	#[error("InvalidTransfer")]
+	InvalidTransfer,
+	#[error("InvalidPositionToken")]
+	InvalidPositionToken,
+	#[error("LegNotSettled")]
+	LegNotSettled,
+	#[error("NoLine")]
+	NoLine,
+	#[error("InvalidSeries")]
+	InvalidSeries,
+	#[error("NotClonable")]
+	NotClonable,
+	#[error("LegVoid")]
+	LegVoid,
+	#[error("JoinWindowOpen")]
+	JoinWindowOpen,
+	#[error("MilestoneDeadlinePassed")]
+	MilestoneDeadlinePassed,
+	#[error("SettlementWindowClosed")]
+	SettlementWindowClosed,
+	#[error("SettlementWindowOpen")]
+	SettlementWindowOpen,
  
 }
 
@@ -79,6 +207,70 @@ impl PrintProgramError for NescrowError {
 			NescrowError::InvalidAuthority => msg!("Error: Only the creator can perform this action"),
 			NescrowError::InvalidWinner => msg!("Error: Winner must be either creator or taker"),
 			NescrowError::EscrowNotAccepted => msg!("Error: Escrow must be in Accepted status to complete"),
+			NescrowError::NoOracle => msg!("Error: Escrow does not name a supported oracle"),
+			NescrowError::MissingAttestation => msg!("Error: Expected an Ed25519 instruction before this one"),
+			NescrowError::InvalidAttestation => msg!("Error: Attestation is not signed by the oracle for this escrow"),
+			NescrowError::NoPriceCondition => msg!("Error: Escrow does not have a price condition"),
+			NescrowError::InvalidPriceAccount => msg!("Error: Price account is not a trading price feed in the expected format"),
+			NescrowError::PriceConditionNotReady => msg!("Error: Price condition cannot be settled before its resolve time"),
+			NescrowError::StalePrice => msg!("Error: Price was published before the resolve time or is too old"),
+			NescrowError::PriceConfidenceTooWide => msg!("Error: Price confidence interval is wider than allowed"),
+			NescrowError::InvalidEventOutcomes => msg!("Error: Event outcomes are empty, too many or too long"),
+			NescrowError::EventNotStarted => msg!("Error: Event result cannot be reported before its start time"),
+			NescrowError::EventAlreadyReported => msg!("Error: Event result has already been reported"),
+			NescrowError::EventNotReported => msg!("Error: Event result has not been reported yet"),
+			NescrowError::InvalidEventOutcome => msg!("Error: Outcome is not one of the event outcomes backed here"),
+			NescrowError::NotAGame => msg!("Error: Escrow is not a commit-reveal game"),
+			NescrowError::InvalidCommitment => msg!("Error: Commitment is missing, unexpected or does not match the reveal"),
+			NescrowError::InvalidChoice => msg!("Error: Choice is not valid for this game"),
+			NescrowError::AlreadyRevealed => msg!("Error: Choice has already been revealed"),
+			NescrowError::RevealDeadlinePassed => msg!("Error: Reveal deadline has passed"),
+			NescrowError::RevealPending => msg!("Error: Game cannot be settled until both reveal or the deadline passes"),
+			NescrowError::InvalidConditions => msg!("Error: Release condition tree is malformed or too large"),
+			NescrowError::ConditionsNotMet => msg!("Error: Release conditions for this winner are not met"),
+			NescrowError::InvalidMilestones => msg!("Error: Milestones are empty, too many, unordered or out of range"),
+			NescrowError::MilestoneNotPending => msg!("Error: Milestone was already released, refunded or disputed"),
+			NescrowError::MilestoneDeadlineNotReached => msg!("Error: No milestone deadline has passed"),
+			NescrowError::NotAStream => msg!("Error: Escrow is not a stream"),
+			NescrowError::InvalidStream => msg!("Error: Stream needs an amount and an end time after its start time"),
+			NescrowError::NothingToWithdraw => msg!("Error: No vested funds left to withdraw"),
+			NescrowError::NotRecurring => msg!("Error: Escrow is not a recurring payment"),
+			NescrowError::InvalidSchedule => msg!("Error: Schedule needs a tranche, at least one period and a positive period length"),
+			NescrowError::PeriodNotElapsed => msg!("Error: Next period boundary has not passed"),
+			NescrowError::InvalidStakeChange => msg!("Error: Stake change is zero, exceeds the stake or is not allowed for this escrow"),
+			NescrowError::EmptyAmendment => msg!("Error: Amendment does not change any term"),
+			NescrowError::InvalidExpiry => msg!("Error: New expiry time must be later than the current one"),
+			NescrowError::ExtensionNotAgreed => msg!("Error: Extension exceeds what the taker agreed to without co-signing"),
+			NescrowError::InvalidPurchase => msg!("Error: Purchase needs an amount and a positive inspection window of at most 90 days"),
+			NescrowError::InvalidPurchaseStatus => msg!("Error: Purchase is not in a status allowing this instruction"),
+			NescrowError::InspectionWindowClosed => msg!("Error: Inspection window has closed"),
+			NescrowError::InspectionWindowOpen => msg!("Error: Inspection window is still open"),
+			NescrowError::NotMutuallyCancellable => msg!("Error: Streams and recurring payments have their own cancel instruction"),
+			NescrowError::InvalidPool => msg!("Error: Pool needs a deposit, room for at least one participant and a settlement deadline after its join deadline"),
+			NescrowError::PoolNotOpen => msg!("Error: Pool has already been settled"),
+			NescrowError::PoolFull => msg!("Error: Pool has reached its participant cap"),
+			NescrowError::JoinDeadlinePassed => msg!("Error: Pool join deadline has passed"),
+			NescrowError::InvalidWinners => msg!("Error: Winners are too many, repeated or not participants of the pool"),
+			NescrowError::PoolNotSettled => msg!("Error: Pool has not been settled yet"),
+			NescrowError::InvalidMarket => msg!("Error: Market fee is too high, or its cutoff is after the event starts"),
+			NescrowError::MarketClosed => msg!("Error: Market no longer accepts stakes"),
+			NescrowError::MarketNotResolved => msg!("Error: Market has not been resolved yet"),
+			NescrowError::ZeroStake => msg!("Error: Stake must not be zero"),
+			NescrowError::InvalidFill => msg!("Error: Fill is out of range, or the escrow is not taken through fills"),
+			NescrowError::MinFillNotReached => msg!("Error: Escrow has not been filled up to its minimum fill"),
+			NescrowError::FillsNotSettled => msg!("Error: Escrow is neither settled nor expired unfilled"),
+			NescrowError::InvalidCounterOffer => msg!("Error: Counter-offer has no stake or an expiry in the past, or the escrow does not take counter-offers"),
+			NescrowError::InvalidTransfer => msg!("Error: Position cannot be transferred to a party of the escrow"),
+			NescrowError::InvalidPositionToken => msg!("Error: Side is already tokenized or cannot be, or the token account does not hold its token"),
+			NescrowError::LegNotSettled => msg!("Error: Escrow referenced by a release condition was not passed or has not settled"),
+			NescrowError::NoLine => msg!("Error: Escrow has no spread or total line"),
+			NescrowError::InvalidSeries => msg!("Error: Series length is invalid, or the escrow is not settled game by game"),
+			NescrowError::NotClonable => msg!("Error: Only escrows completed between two parties, with nothing left to redeem, can be cloned"),
+			NescrowError::LegVoid => msg!("Error: A leg of the parlay is void, so its stakes can only be refunded"),
+			NescrowError::JoinWindowOpen => msg!("Error: Pool can still be joined until its join deadline"),
+			NescrowError::MilestoneDeadlinePassed => msg!("Error: Milestone deadline has passed, so it can no longer be disputed"),
+			NescrowError::SettlementWindowClosed => msg!("Error: Pool's settlement deadline has passed, so it can only be refunded"),
+			NescrowError::SettlementWindowOpen => msg!("Error: Pool can still be settled by its organizer until its settlement deadline"),
  
         }
     }
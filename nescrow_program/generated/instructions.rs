@@ -2,6 +2,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 use crate::generated::errors::NescrowError;
+use crate::generated::state::{ConditionNode, EventCondition, Line, Outcome, PriceCondition};
 
 #[derive(BorshSerialize, Debug)]
 pub enum NescrowInstruction {
@@ -12,12 +13,22 @@ pub enum NescrowInstruction {
 /// 1. `[writable]` escrow: [Escrow] 
 /// 2. `[writable, signer]` creator: [AccountInfo] 
 /// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 4. `[]` event: [Event] Optional, named by an event or event score oracle
 ///
 /// Data:
 /// - counter: [u64] Counter to make the escrow PDA unique
 /// - amount: [u64] The amount of lamports to wager
 /// - description: [String] type
 /// - expiry_time: [i64] The time when the escrow expires (Unix timestamp)
+/// - oracle_kind: [u8] Kind of oracle that can settle the escrow, none by default
+/// - oracle_account: [Pubkey] Attestation signer or price feed of the oracle
+/// - price_condition: [Option<PriceCondition>] Threshold for price feed oracles
+/// - event_condition: [Option<EventCondition>] Outcomes backed by each side for event oracles
+/// - game: [Option<GameTerms>] Commit-reveal game settling the escrow, with the creator's commitment
+/// - max_extension: [i64] Seconds the creator may extend the expiry alone once accepted
+/// - min_fill: [Option<u64>] Fill at which the creator may accept, for escrows taken through partial fills
+/// - line: [Option<Line>] Spread or total settling the escrow from an event's score
+/// - series_length: [Option<u8>] Number of games, odd, in a best-of-N series settling the escrow
 	CreateEscrow(CreateEscrowArgs),
 
 /// Accept an existing escrow
@@ -26,10 +37,12 @@ pub enum NescrowInstruction {
 /// 0. `[writable, signer]` fee_payer: [AccountInfo] 
 /// 1. `[writable]` escrow: [Escrow] 
 /// 2. `[writable, signer]` taker: [AccountInfo] 
+/// 3. `[]` system_program: [AccountInfo] Used to move the taker's stake into the escrow
 ///
 /// Data:
 /// - creator: [Pubkey] 
 /// - counter: [u64] 
+/// - commitment: [Option<[u8; 32]>] The taker's commitment, required for games
 	AcceptEscrow(AcceptEscrowArgs),
 
 /// Complete the escrow and distribute funds to winner
@@ -62,12 +75,751 @@ pub enum NescrowInstruction {
 /// 0. `[writable, signer]` fee_payer: [AccountInfo] 
 /// 1. `[writable]` escrow: [Escrow] 
 /// 2. `[signer]` creator: [AccountInfo] 
+/// 3. `[signer]` taker: [AccountInfo] Optional, consents to extensions beyond the agreed maximum
 ///
 /// Data:
 /// - counter: [u64] 
 /// - new_expiry_time: [i64] The new expiry time for the escrow (Unix timestamp)
 	ExtendEscrow(ExtendEscrowArgs),
 
+/// Settle the escrow from the outcome reported by its oracle
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[]` oracle: [AccountInfo] Instructions sysvar for attestations, otherwise the oracle account
+/// 3. `[writable]` winner: [AccountInfo] The party named by the outcome
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+	ResolveEscrow(ResolveEscrowArgs),
+
+/// Register an event whose result the reporter will post
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` event: [Event] 
+/// 2. `[signer]` reporter: [AccountInfo] 
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - event_id: [u64] Id making the event PDA unique for the reporter
+/// - start_time: [i64] The time the event starts (Unix timestamp)
+/// - outcomes: [Vec<String>] Labels of the possible outcomes
+	CreateEvent(CreateEventArgs),
+
+/// Post the final result of an event
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` event: [Event] 
+/// 2. `[signer]` reporter: [AccountInfo] 
+///
+/// Data:
+/// - event_id: [u64] 
+/// - outcome: [u8] Index of the winning outcome
+	ReportEventResult(ReportEventResultArgs),
+
+/// Reveal a committed game choice
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[signer]` party: [AccountInfo] Creator or taker revealing their choice
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+/// - choice: [u8] The committed choice
+/// - salt: [[u8; 32]] The salt the commitment was made with
+	RevealChoice(RevealChoiceArgs),
+
+/// Settle a game from the revealed choices, or against a party who never revealed
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable]` creator: [AccountInfo] 
+/// 3. `[writable]` taker: [AccountInfo] 
+///
+/// Data:
+/// - counter: [u64] 
+	SettleGame(SettleGameArgs),
+
+/// Attach release condition trees to an accepted escrow, agreed by both parties
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` escrow: [Escrow] 
+/// 2. `[writable]` conditions: [ReleaseConditions] 
+/// 3. `[signer]` creator: [AccountInfo] 
+/// 4. `[signer]` taker: [AccountInfo] 
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - counter: [u64] 
+/// - creator_wins: [Vec<ConditionNode>] Tree releasing the pot to the creator
+/// - taker_wins: [Vec<ConditionNode>] Tree releasing the pot to the taker
+	SetReleaseConditions(SetReleaseConditionsArgs),
+
+/// Release the pot to a party whose condition tree is satisfied
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[]` conditions: [ReleaseConditions] 
+/// 3. `[writable]` winner: [AccountInfo] 
+/// 4. `[]` remaining: [AccountInfo] Signers, event and escrow accounts referenced by the tree
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+/// - preimages: [Vec<Vec<u8>>] Preimages for hash conditions
+	ReleaseIfConditionsMet(ReleaseIfConditionsMetArgs),
+
+/// Create a freelance escrow funded by the client for an ordered list of milestones
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [MilestoneEscrow] 
+/// 2. `[writable, signer]` client: [AccountInfo] 
+/// 3. `[]` worker: [AccountInfo] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - counter: [u64] 
+/// - milestones: [Vec<MilestoneTerms>] Amount, description hash and deadline of each milestone
+	CreateMilestoneEscrow(CreateMilestoneEscrowArgs),
+
+/// Release one milestone's funds to the worker
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [MilestoneEscrow] 
+/// 2. `[signer]` client: [AccountInfo] 
+/// 3. `[writable]` worker: [AccountInfo] 
+///
+/// Data:
+/// - counter: [u64] 
+/// - index: [u8] Milestone to release
+	ApproveMilestone(ApproveMilestoneArgs),
+
+/// Freeze one milestone of a freelance escrow
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [MilestoneEscrow] 
+/// 2. `[signer]` party: [AccountInfo] Client or worker raising the dispute
+///
+/// Data:
+/// - client: [Pubkey] 
+/// - counter: [u64] 
+/// - index: [u8] Milestone to dispute
+	DisputeMilestone(DisputeMilestoneArgs),
+
+/// Return overdue milestone funds to the client, and disputed ones after the final deadline
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [MilestoneEscrow] 
+/// 2. `[writable, signer]` client: [AccountInfo] 
+///
+/// Data:
+/// - counter: [u64] 
+	ReclaimMilestones(ReclaimMilestonesArgs),
+
+/// Create an escrow releasing its amount linearly to a beneficiary
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable, signer]` creator: [AccountInfo] Payer funding the stream
+/// 3. `[]` beneficiary: [AccountInfo] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - counter: [u64] 
+/// - amount: [u64] Lamports streamed in total
+/// - start_time: [i64] When funds start vesting (Unix timestamp)
+/// - end_time: [i64] When all funds have vested (Unix timestamp)
+	CreateStream(CreateStreamArgs),
+
+/// Withdraw the vested portion of a stream
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable, signer]` beneficiary: [AccountInfo] 
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+	Withdraw(WithdrawArgs),
+
+/// Stop a stream, paying out what has vested and refunding the rest to the creator
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable, signer]` creator: [AccountInfo] 
+/// 3. `[writable]` beneficiary: [AccountInfo] 
+///
+/// Data:
+/// - counter: [u64] 
+	CancelStream(CancelStreamArgs),
+
+/// Create an escrow funded for a number of periods, releasing one tranche per period
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable, signer]` creator: [AccountInfo] Payer funding every period
+/// 3. `[]` payee: [AccountInfo] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - counter: [u64] 
+/// - tranche: [u64] Lamports released each period
+/// - start_time: [i64] Start of the first period (Unix timestamp)
+/// - period_length: [i64] Length of each period in seconds
+/// - periods: [u32] Number of periods funded
+	CreateRecurringPayment(CreateRecurringPaymentArgs),
+
+/// Release the next tranche once its period boundary has passed
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable]` payee: [AccountInfo] 
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+	ReleaseNextPeriod(ReleaseNextPeriodArgs),
+
+/// Stop future periods, refunding the tranches that are not due yet
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable, signer]` creator: [AccountInfo] 
+/// 3. `[writable]` payee: [AccountInfo] 
+///
+/// Data:
+/// - counter: [u64] 
+	CancelRecurringPayment(CancelRecurringPaymentArgs),
+
+/// Raise the stake of an escrow, matched by the taker once accepted
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable, signer]` creator: [AccountInfo] 
+/// 3. `[]` system_program: [AccountInfo] 
+/// 4. `[writable, signer]` taker: [AccountInfo] Only for accepted escrows
+///
+/// Data:
+/// - counter: [u64] 
+/// - increase: [u64] Lamports added to each side's stake
+	IncreaseStake(IncreaseStakeArgs),
+
+/// Lower the stake of an open escrow, returning the difference to the creator
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable, signer]` creator: [AccountInfo] 
+///
+/// Data:
+/// - counter: [u64] 
+/// - decrease: [u64] Lamports removed from the stake
+	DecreaseStake(DecreaseStakeArgs),
+
+/// Change the terms of an accepted escrow with the consent of both parties
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable, signer]` creator: [AccountInfo] 
+/// 3. `[writable, signer]` taker: [AccountInfo] 
+/// 4. `[]` system_program: [AccountInfo] 
+///
+/// Data:
+/// - counter: [u64] 
+/// - description: [Option<String>] New description, if changed
+/// - amount: [Option<u64>] New stake of each side, if changed
+/// - expiry_time: [Option<i64>] New expiry time, if changed
+	AmendEscrow(AmendEscrowArgs),
+
+/// Pay for a marketplace purchase, held until the buyer inspects the goods
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` purchase: [Purchase] 
+/// 2. `[writable, signer]` buyer: [AccountInfo] 
+/// 3. `[]` seller: [AccountInfo] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - counter: [u64] 
+/// - amount: [u64] Price paid to the seller
+/// - inspection_window: [i64] Seconds the buyer has to inspect once shipped
+	CreatePurchase(CreatePurchaseArgs),
+
+/// Mark a purchase shipped, starting the inspection window
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` purchase: [Purchase] 
+/// 2. `[signer]` seller: [AccountInfo] 
+///
+/// Data:
+/// - buyer: [Pubkey] 
+/// - counter: [u64] 
+	MarkShipped(MarkShippedArgs),
+
+/// Confirm receipt of a purchase, paying the seller
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` purchase: [Purchase] 
+/// 2. `[signer]` buyer: [AccountInfo] 
+/// 3. `[writable]` seller: [AccountInfo] 
+///
+/// Data:
+/// - counter: [u64] 
+	ConfirmPurchase(ConfirmPurchaseArgs),
+
+/// Dispute a shipped purchase within its inspection window
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` purchase: [Purchase] 
+/// 2. `[signer]` buyer: [AccountInfo] 
+///
+/// Data:
+/// - counter: [u64] 
+	DisputePurchase(DisputePurchaseArgs),
+
+/// Release a shipped purchase to the seller once the inspection window passes undisputed
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Anyone may release
+/// 1. `[writable]` purchase: [Purchase] 
+/// 2. `[writable]` seller: [AccountInfo] 
+///
+/// Data:
+/// - buyer: [Pubkey] 
+/// - counter: [u64] 
+	AutoReleasePurchase(AutoReleasePurchaseArgs),
+
+/// Refund a purchase to the buyer, by the seller or by the buyer before shipping
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` purchase: [Purchase] 
+/// 2. `[signer]` party: [AccountInfo] Seller, or buyer while not shipped
+/// 3. `[writable]` buyer: [AccountInfo] 
+///
+/// Data:
+/// - counter: [u64] 
+	RefundPurchase(RefundPurchaseArgs),
+
+/// Call off an accepted escrow with the consent of both parties
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable, signer]` creator: [AccountInfo] 
+/// 3. `[writable, signer]` taker: [AccountInfo] 
+/// 4. `[writable]` rent_payer: [AccountInfo] Account that paid the escrow's rent, refunded on close
+///
+/// Data:
+/// - counter: [u64] 
+	MutualCancel(MutualCancelArgs),
+
+/// Concede an accepted escrow, awarding the pot to the counterparty
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[signer]` party: [AccountInfo] Creator or taker conceding
+/// 3. `[writable]` counterparty: [AccountInfo] The other party, receiving the pot
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+	Concede(ConcedeArgs),
+
+/// Create a pool that participants join with a fixed deposit
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` pool: [Pool] 
+/// 2. `[signer]` organizer: [AccountInfo] Settles the pool
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - counter: [u64] 
+/// - deposit: [u64] Lamports each participant deposits
+/// - max_participants: [u32] Participant cap
+/// - join_deadline: [i64] Last time participants can join (Unix timestamp)
+/// - settle_deadline: [i64] Last time the organizer can settle, after which anyone can refund everyone (Unix timestamp)
+	CreatePool(CreatePoolArgs),
+
+/// Join a pool, depositing into it
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` pool: [Pool] 
+/// 2. `[writable]` participant_record: [Participant] 
+/// 3. `[writable, signer]` participant: [AccountInfo] Pays the deposit and the record's rent
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - organizer: [Pubkey] 
+/// - counter: [u64] 
+	JoinPool(JoinPoolArgs),
+
+/// Settle a pool between its join and settlement deadlines by naming its winners, or refund everyone with no winners
+///
+/// The organizer is trusted as the arbiter of who won.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` pool: [Pool] 
+/// 2. `[signer]` organizer: [AccountInfo] 
+/// 3. `[]` remaining: [Participant] Participant record of each winner, in order
+///
+/// Data:
+/// - counter: [u64] 
+/// - winners: [Vec<Pubkey>] Participants sharing the pot, empty to refund everyone
+	SettlePool(SettlePoolArgs),
+
+/// Claim a participant's payout or refund from a settled pool and close their record
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` pool: [Pool] 
+/// 2. `[writable]` participant_record: [Participant] 
+/// 3. `[writable]` participant: [AccountInfo] Receives the payout and the record's rent
+///
+/// Data:
+/// - organizer: [Pubkey] 
+/// - counter: [u64] 
+	ClaimPool(ClaimPoolArgs),
+
+/// Create a parimutuel market on the outcomes of an event
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` market: [Market] 
+/// 2. `[signer]` creator: [AccountInfo] Receives the market fee
+/// 3. `[]` event: [Event] Event whose outcomes are staked on
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - counter: [u64] 
+/// - cutoff: [i64] Last time stakes are accepted (Unix timestamp)
+/// - fee_bps: [u16] Fee on the total staked, in basis points
+	CreateMarket(CreateMarketArgs),
+
+/// Stake on one outcome of a market
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` market: [Market] 
+/// 2. `[writable]` position: [Position] 
+/// 3. `[writable, signer]` owner: [AccountInfo] Pays the stake and the position's rent
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+/// - outcome: [u8] Index of the event outcome staked on
+/// - stake: [u64] Lamports staked
+	PlaceStake(PlaceStakeArgs),
+
+/// Resolve a market from its event's reported result
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Anyone may resolve
+/// 1. `[writable]` market: [Market] 
+/// 2. `[]` event: [Event] 
+/// 3. `[writable]` creator: [AccountInfo] Receives the market fee
+///
+/// Data:
+/// - counter: [u64] 
+	ResolveMarket(ResolveMarketArgs),
+
+/// Pay out a position of a resolved market and close it
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` market: [Market] 
+/// 2. `[writable]` position: [Position] 
+/// 3. `[writable]` owner: [AccountInfo] Receives the payout and the position's rent
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+/// - outcome: [u8] 
+	ClaimPosition(ClaimPositionArgs),
+
+/// Fill part of an escrow's amount
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable]` fill: [Fill] 
+/// 3. `[writable, signer]` taker: [AccountInfo] Pays the fill and the fill record's rent
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+/// - amount: [u64] Lamports filled
+	FillEscrow(FillEscrowArgs),
+
+/// Accept a partially filled escrow once its minimum fill is reached
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable, signer]` creator: [AccountInfo] Receives the unfilled part of the stake
+///
+/// Data:
+/// - counter: [u64] 
+	AcceptFills(AcceptFillsArgs),
+
+/// Settle an escrow taken through fills from the outcome reported by its oracle
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Anyone may settle
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[]` oracle: [AccountInfo] Instructions sysvar for attestations, the feed for prices
+/// 3. `[writable]` creator: [AccountInfo] Receives the pot if the creator wins
+///
+/// Data:
+/// - counter: [u64] 
+	SettleFills(SettleFillsArgs),
+
+/// Pay out a fill of a settled or expired escrow and close it
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable]` fill: [Fill] 
+/// 3. `[writable]` taker: [AccountInfo] Receives the payout and the fill record's rent
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+	ClaimFill(ClaimFillArgs),
+
+/// Propose a different amount or expiry for an open escrow
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` escrow: [Escrow] 
+/// 2. `[writable]` counter_offer: [CounterOffer] 
+/// 3. `[writable, signer]` taker: [AccountInfo] Pays the offered stake and the counter-offer's rent
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+/// - amount: [u64] Stake proposed for each side
+/// - expiry_time: [i64] Expiry proposed for the escrow (Unix timestamp)
+	PostCounterOffer(PostCounterOfferArgs),
+
+/// Accept a counter-offer, taking on its terms and binding its taker
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable]` counter_offer: [CounterOffer] 
+/// 3. `[writable, signer]` creator: [AccountInfo] Tops up or gets back the difference in stake
+/// 4. `[writable]` taker: [AccountInfo] Receives the counter-offer's rent
+/// 5. `[]` system_program: [AccountInfo] Used to top up the creator's stake
+///
+/// Data:
+/// - counter: [u64] 
+	AcceptCounterOffer(AcceptCounterOfferArgs),
+
+/// Withdraw, reject or clean up a counter-offer, refunding its taker
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` escrow: [Escrow] 
+/// 2. `[writable]` counter_offer: [CounterOffer] 
+/// 3. `[writable]` taker: [AccountInfo] Receives the offered stake and the counter-offer's rent
+/// 4. `[signer]` authority: [AccountInfo] Creator or taker, or anyone once the offer is stale
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+	CloseCounterOffer(CloseCounterOfferArgs),
+
+/// Transfer a party's side of an accepted escrow to another account
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable, signer]` party: [AccountInfo] Creator or taker selling their side, receives the price
+/// 3. `[writable, signer]` buyer: [AccountInfo] New holder of the side, pays the price
+/// 4. `[]` system_program: [AccountInfo] Used to pay the price
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+/// - price: [u64] Lamports paid by the buyer to the party, if any
+	TransferPosition(TransferPositionArgs),
+
+/// Mint a supply-1 token standing for one side of an accepted escrow
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Pays the rent of the mint and the token account
+/// 1. `[writable]` escrow: [Escrow] Mint authority of the token
+/// 2. `[signer]` party: [AccountInfo] Creator or taker holding the side
+/// 3. `[writable]` mint: [AccountInfo] Mint of the side's token
+/// 4. `[writable]` token_account: [AccountInfo] Associated token account of the party for the mint
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 6. `[]` token_program: [AccountInfo] SPL Token program
+/// 7. `[]` associated_token_program: [AccountInfo] SPL Associated Token Account program
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+/// - side: [u8] Side to tokenize, the creator's or the taker's
+	TokenizePosition(TokenizePositionArgs),
+
+/// Burn the winning side's token of a settled escrow for the pot
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable]` mint: [AccountInfo] Mint of the side's token
+/// 3. `[writable]` token_account: [AccountInfo] Token account holding the token
+/// 4. `[writable, signer]` holder: [AccountInfo] Owner of the token account, receives the pot
+/// 5. `[]` token_program: [AccountInfo] SPL Token program
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+/// - side: [u8] 
+	RedeemPositionToken(RedeemPositionTokenArgs),
+
+/// Post the final score of an event
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` event: [Event] 
+/// 2. `[signer]` reporter: [AccountInfo] 
+///
+/// Data:
+/// - event_id: [u64] 
+/// - home: [u32] Points scored by the home team
+/// - away: [u32] Points scored by the away team
+	ReportEventScore(ReportEventScoreArgs),
+
+/// Settle an escrow from its line and the score of its event
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[]` event: [Event] Event named by the escrow's oracle
+/// 3. `[writable]` creator: [AccountInfo] 
+/// 4. `[writable]` taker: [AccountInfo] 
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+	SettleLine(SettleLineArgs),
+
+/// Record the result of the next game of a series, confirmed by both parties
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[writable, signer]` creator: [AccountInfo] 
+/// 3. `[writable, signer]` taker: [AccountInfo] 
+///
+/// Data:
+/// - counter: [u64] 
+/// - outcome: [Outcome] Winner of the game
+	ReportSeriesGame(ReportSeriesGameArgs),
+
+/// Record the result of the next game of a series from an oracle attestation
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[]` oracle: [AccountInfo] Instructions sysvar holding the attestation
+/// 3. `[writable]` creator: [AccountInfo] 
+/// 4. `[writable]` taker: [AccountInfo] 
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+	ResolveSeriesGame(ResolveSeriesGameArgs),
+
+/// Open a rematch of a completed escrow, reserved for its taker
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Tops up the rent if it has risen
+/// 1. `[writable]` escrow: [Escrow] Completed escrow, closed into the rematch
+/// 2. `[writable]` rematch: [Escrow] Escrow to be created at the creator's rematch counter
+/// 3. `[writable, signer]` creator: [AccountInfo] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - counter: [u64] 
+/// - rematch_counter: [u64] Unused counter of the current creator to create the rematch at
+/// - expiry_time: [i64] The time when the rematch expires (Unix timestamp)
+	CloneEscrow(CloneEscrowArgs),
+
+/// Refund both stakes of a parlay with a void leg
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[]` conditions: [ReleaseConditions] 
+/// 3. `[writable]` creator: [AccountInfo] 
+/// 4. `[writable]` taker: [AccountInfo] 
+/// 5. `[]` remaining: [AccountInfo] Escrow accounts referenced by either tree
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+	RefundParlay(RefundParlayArgs),
+
+/// Refund the stakes of an event escrow whose result neither side backed
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` escrow: [Escrow] 
+/// 2. `[]` event: [Event] 
+/// 3. `[writable]` creator: [AccountInfo] 
+/// 4. `[writable]` taker: [AccountInfo] Unused if taken through fills
+///
+/// Data:
+/// - creator: [Pubkey] 
+/// - counter: [u64] 
+	RefundEventEscrow(RefundEventEscrowArgs),
+
+/// Refund everyone in a pool its organizer did not settle by the settlement deadline
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` pool: [Pool] 
+///
+/// Data:
+/// - organizer: [Pubkey] 
+/// - counter: [u64] 
+	RefundPool(RefundPoolArgs),
+
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
@@ -76,12 +828,29 @@ pub struct CreateEscrowArgs {
 	pub amount: u64,
 	pub description: String,
 	pub expiry_time: i64,
+	pub oracle_kind: u8,
+	pub oracle_account: Pubkey,
+	pub price_condition: Option<PriceCondition>,
+	pub event_condition: Option<EventCondition>,
+	pub game: Option<GameTerms>,
+	pub max_extension: i64,
+	pub min_fill: Option<u64>,
+	pub line: Option<Line>,
+	pub series_length: Option<u8>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct GameTerms {
+	pub game_kind: u8,
+	pub reveal_window: i64,
+	pub commitment: [u8; 32],
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub struct AcceptEscrowArgs {
 	pub creator: Pubkey,
 	pub counter: u64,
+	pub commitment: Option<[u8; 32]>,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
@@ -101,6 +870,354 @@ pub struct ExtendEscrowArgs {
 	pub new_expiry_time: i64,
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ResolveEscrowArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CreateEventArgs {
+	pub event_id: u64,
+	pub start_time: i64,
+	pub outcomes: Vec<String>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ReportEventResultArgs {
+	pub event_id: u64,
+	pub outcome: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct RevealChoiceArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+	pub choice: u8,
+	pub salt: [u8; 32],
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct SettleGameArgs {
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct SetReleaseConditionsArgs {
+	pub counter: u64,
+	pub creator_wins: Vec<ConditionNode>,
+	pub taker_wins: Vec<ConditionNode>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ReleaseIfConditionsMetArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+	pub preimages: Vec<Vec<u8>>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct MilestoneTerms {
+	pub amount: u64,
+	pub description_hash: [u8; 32],
+	pub deadline: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CreateMilestoneEscrowArgs {
+	pub counter: u64,
+	pub milestones: Vec<MilestoneTerms>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ApproveMilestoneArgs {
+	pub counter: u64,
+	pub index: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct DisputeMilestoneArgs {
+	pub client: Pubkey,
+	pub counter: u64,
+	pub index: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ReclaimMilestonesArgs {
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CreateStreamArgs {
+	pub counter: u64,
+	pub amount: u64,
+	pub start_time: i64,
+	pub end_time: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct WithdrawArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CancelStreamArgs {
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CreateRecurringPaymentArgs {
+	pub counter: u64,
+	pub tranche: u64,
+	pub start_time: i64,
+	pub period_length: i64,
+	pub periods: u32,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ReleaseNextPeriodArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CancelRecurringPaymentArgs {
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct IncreaseStakeArgs {
+	pub counter: u64,
+	pub increase: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct DecreaseStakeArgs {
+	pub counter: u64,
+	pub decrease: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct AmendEscrowArgs {
+	pub counter: u64,
+	pub description: Option<String>,
+	pub amount: Option<u64>,
+	pub expiry_time: Option<i64>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CreatePurchaseArgs {
+	pub counter: u64,
+	pub amount: u64,
+	pub inspection_window: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct MarkShippedArgs {
+	pub buyer: Pubkey,
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ConfirmPurchaseArgs {
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct DisputePurchaseArgs {
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct AutoReleasePurchaseArgs {
+	pub buyer: Pubkey,
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct RefundPurchaseArgs {
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct MutualCancelArgs {
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ConcedeArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CreatePoolArgs {
+	pub counter: u64,
+	pub deposit: u64,
+	pub max_participants: u32,
+	pub join_deadline: i64,
+	pub settle_deadline: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct JoinPoolArgs {
+	pub organizer: Pubkey,
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct SettlePoolArgs {
+	pub counter: u64,
+	pub winners: Vec<Pubkey>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ClaimPoolArgs {
+	pub organizer: Pubkey,
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CreateMarketArgs {
+	pub counter: u64,
+	pub cutoff: i64,
+	pub fee_bps: u16,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct PlaceStakeArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+	pub outcome: u8,
+	pub stake: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ResolveMarketArgs {
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ClaimPositionArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+	pub outcome: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct FillEscrowArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+	pub amount: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct AcceptFillsArgs {
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct SettleFillsArgs {
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ClaimFillArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct PostCounterOfferArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+	pub amount: u64,
+	pub expiry_time: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct AcceptCounterOfferArgs {
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CloseCounterOfferArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct TransferPositionArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+	pub price: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct TokenizePositionArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+	pub side: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct RedeemPositionTokenArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+	pub side: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ReportEventScoreArgs {
+	pub event_id: u64,
+	pub home: u32,
+	pub away: u32,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct SettleLineArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ReportSeriesGameArgs {
+	pub counter: u64,
+	pub outcome: Outcome,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ResolveSeriesGameArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CloneEscrowArgs {
+	pub counter: u64,
+	pub rematch_counter: u64,
+	pub expiry_time: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct RefundParlayArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct RefundEventEscrowArgs {
+	pub creator: Pubkey,
+	pub counter: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct RefundPoolArgs {
+	pub organizer: Pubkey,
+	pub counter: u64,
+}
+
 impl NescrowInstruction {
     pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
         let (&variant, rest) = input.split_first().ok_or(NescrowError::InvalidInstruction)?;
@@ -111,6 +1228,60 @@ impl NescrowInstruction {
 			2 => Self::CompleteEscrow(CompleteEscrowArgs::try_from_slice(rest).unwrap()),
 			3 => Self::CancelEscrow(CancelEscrowArgs::try_from_slice(rest).unwrap()),
 			4 => Self::ExtendEscrow(ExtendEscrowArgs::try_from_slice(rest).unwrap()),
+			5 => Self::ResolveEscrow(ResolveEscrowArgs::try_from_slice(rest).unwrap()),
+			6 => Self::CreateEvent(CreateEventArgs::try_from_slice(rest).unwrap()),
+			7 => Self::ReportEventResult(ReportEventResultArgs::try_from_slice(rest).unwrap()),
+			8 => Self::RevealChoice(RevealChoiceArgs::try_from_slice(rest).unwrap()),
+			9 => Self::SettleGame(SettleGameArgs::try_from_slice(rest).unwrap()),
+			10 => Self::SetReleaseConditions(SetReleaseConditionsArgs::try_from_slice(rest).unwrap()),
+			11 => Self::ReleaseIfConditionsMet(ReleaseIfConditionsMetArgs::try_from_slice(rest).unwrap()),
+			12 => Self::CreateMilestoneEscrow(CreateMilestoneEscrowArgs::try_from_slice(rest).unwrap()),
+			13 => Self::ApproveMilestone(ApproveMilestoneArgs::try_from_slice(rest).unwrap()),
+			14 => Self::DisputeMilestone(DisputeMilestoneArgs::try_from_slice(rest).unwrap()),
+			15 => Self::ReclaimMilestones(ReclaimMilestonesArgs::try_from_slice(rest).unwrap()),
+			16 => Self::CreateStream(CreateStreamArgs::try_from_slice(rest).unwrap()),
+			17 => Self::Withdraw(WithdrawArgs::try_from_slice(rest).unwrap()),
+			18 => Self::CancelStream(CancelStreamArgs::try_from_slice(rest).unwrap()),
+			19 => Self::CreateRecurringPayment(CreateRecurringPaymentArgs::try_from_slice(rest).unwrap()),
+			20 => Self::ReleaseNextPeriod(ReleaseNextPeriodArgs::try_from_slice(rest).unwrap()),
+			21 => Self::CancelRecurringPayment(CancelRecurringPaymentArgs::try_from_slice(rest).unwrap()),
+			22 => Self::IncreaseStake(IncreaseStakeArgs::try_from_slice(rest).unwrap()),
+			23 => Self::DecreaseStake(DecreaseStakeArgs::try_from_slice(rest).unwrap()),
+			24 => Self::AmendEscrow(AmendEscrowArgs::try_from_slice(rest).unwrap()),
+			25 => Self::CreatePurchase(CreatePurchaseArgs::try_from_slice(rest).unwrap()),
+			26 => Self::MarkShipped(MarkShippedArgs::try_from_slice(rest).unwrap()),
+			27 => Self::ConfirmPurchase(ConfirmPurchaseArgs::try_from_slice(rest).unwrap()),
+			28 => Self::DisputePurchase(DisputePurchaseArgs::try_from_slice(rest).unwrap()),
+			29 => Self::AutoReleasePurchase(AutoReleasePurchaseArgs::try_from_slice(rest).unwrap()),
+			30 => Self::RefundPurchase(RefundPurchaseArgs::try_from_slice(rest).unwrap()),
+			31 => Self::MutualCancel(MutualCancelArgs::try_from_slice(rest).unwrap()),
+			32 => Self::Concede(ConcedeArgs::try_from_slice(rest).unwrap()),
+			33 => Self::CreatePool(CreatePoolArgs::try_from_slice(rest).unwrap()),
+			34 => Self::JoinPool(JoinPoolArgs::try_from_slice(rest).unwrap()),
+			35 => Self::SettlePool(SettlePoolArgs::try_from_slice(rest).unwrap()),
+			36 => Self::ClaimPool(ClaimPoolArgs::try_from_slice(rest).unwrap()),
+			37 => Self::CreateMarket(CreateMarketArgs::try_from_slice(rest).unwrap()),
+			38 => Self::PlaceStake(PlaceStakeArgs::try_from_slice(rest).unwrap()),
+			39 => Self::ResolveMarket(ResolveMarketArgs::try_from_slice(rest).unwrap()),
+			40 => Self::ClaimPosition(ClaimPositionArgs::try_from_slice(rest).unwrap()),
+			41 => Self::FillEscrow(FillEscrowArgs::try_from_slice(rest).unwrap()),
+			42 => Self::AcceptFills(AcceptFillsArgs::try_from_slice(rest).unwrap()),
+			43 => Self::SettleFills(SettleFillsArgs::try_from_slice(rest).unwrap()),
+			44 => Self::ClaimFill(ClaimFillArgs::try_from_slice(rest).unwrap()),
+			45 => Self::PostCounterOffer(PostCounterOfferArgs::try_from_slice(rest).unwrap()),
+			46 => Self::AcceptCounterOffer(AcceptCounterOfferArgs::try_from_slice(rest).unwrap()),
+			47 => Self::CloseCounterOffer(CloseCounterOfferArgs::try_from_slice(rest).unwrap()),
+			48 => Self::TransferPosition(TransferPositionArgs::try_from_slice(rest).unwrap()),
+			49 => Self::TokenizePosition(TokenizePositionArgs::try_from_slice(rest).unwrap()),
+			50 => Self::RedeemPositionToken(RedeemPositionTokenArgs::try_from_slice(rest).unwrap()),
+			51 => Self::ReportEventScore(ReportEventScoreArgs::try_from_slice(rest).unwrap()),
+			52 => Self::SettleLine(SettleLineArgs::try_from_slice(rest).unwrap()),
+			53 => Self::ReportSeriesGame(ReportSeriesGameArgs::try_from_slice(rest).unwrap()),
+			54 => Self::ResolveSeriesGame(ResolveSeriesGameArgs::try_from_slice(rest).unwrap()),
+			55 => Self::CloneEscrow(CloneEscrowArgs::try_from_slice(rest).unwrap()),
+			56 => Self::RefundParlay(RefundParlayArgs::try_from_slice(rest).unwrap()),
+			57 => Self::RefundEventEscrow(RefundEventEscrowArgs::try_from_slice(rest).unwrap()),
+			58 => Self::RefundPool(RefundPoolArgs::try_from_slice(rest).unwrap()),
 			_ => return Err(NescrowError::InvalidInstruction.into())
         })
     }
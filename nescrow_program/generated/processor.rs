@@ -30,6 +30,15 @@ impl Processor {
 					args.amount,
 					args.description,
 					args.expiry_time,
+					args.oracle_kind,
+					args.oracle_account,
+					args.price_condition,
+					args.event_condition,
+					args.game,
+					args.max_extension,
+					args.min_fill,
+					args.line,
+					args.series_length,
 				)
 			}
 			NescrowInstruction::AcceptEscrow(args) => {
@@ -39,6 +48,7 @@ impl Processor {
 					accounts, 
 					args.creator,
 					args.counter,
+					args.commitment,
 				)
 			}
 			NescrowInstruction::CompleteEscrow(args) => {
@@ -67,6 +77,509 @@ impl Processor {
 					args.new_expiry_time,
 				)
 			}
+			NescrowInstruction::ResolveEscrow(args) => {
+				msg!("Instruction: ResolveEscrow");
+				resolve_escrow::resolve_escrow(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+				)
+			}
+			NescrowInstruction::CreateEvent(args) => {
+				msg!("Instruction: CreateEvent");
+				create_event::create_event(
+					program_id,
+					accounts, 
+					args.event_id,
+					args.start_time,
+					args.outcomes,
+				)
+			}
+			NescrowInstruction::ReportEventResult(args) => {
+				msg!("Instruction: ReportEventResult");
+				report_event_result::report_event_result(
+					program_id,
+					accounts, 
+					args.event_id,
+					args.outcome,
+				)
+			}
+			NescrowInstruction::RevealChoice(args) => {
+				msg!("Instruction: RevealChoice");
+				reveal_choice::reveal_choice(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+					args.choice,
+					args.salt,
+				)
+			}
+			NescrowInstruction::SettleGame(args) => {
+				msg!("Instruction: SettleGame");
+				settle_game::settle_game(
+					program_id,
+					accounts, 
+					args.counter,
+				)
+			}
+			NescrowInstruction::SetReleaseConditions(args) => {
+				msg!("Instruction: SetReleaseConditions");
+				set_release_conditions::set_release_conditions(
+					program_id,
+					accounts, 
+					args.counter,
+					args.creator_wins,
+					args.taker_wins,
+				)
+			}
+			NescrowInstruction::ReleaseIfConditionsMet(args) => {
+				msg!("Instruction: ReleaseIfConditionsMet");
+				release_if_conditions_met::release_if_conditions_met(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+					args.preimages,
+				)
+			}
+			NescrowInstruction::CreateMilestoneEscrow(args) => {
+				msg!("Instruction: CreateMilestoneEscrow");
+				create_milestone_escrow::create_milestone_escrow(
+					program_id,
+					accounts, 
+					args.counter,
+					args.milestones,
+				)
+			}
+			NescrowInstruction::ApproveMilestone(args) => {
+				msg!("Instruction: ApproveMilestone");
+				approve_milestone::approve_milestone(
+					program_id,
+					accounts, 
+					args.counter,
+					args.index,
+				)
+			}
+			NescrowInstruction::DisputeMilestone(args) => {
+				msg!("Instruction: DisputeMilestone");
+				dispute_milestone::dispute_milestone(
+					program_id,
+					accounts, 
+					args.client,
+					args.counter,
+					args.index,
+				)
+			}
+			NescrowInstruction::ReclaimMilestones(args) => {
+				msg!("Instruction: ReclaimMilestones");
+				reclaim_milestones::reclaim_milestones(
+					program_id,
+					accounts, 
+					args.counter,
+				)
+			}
+			NescrowInstruction::CreateStream(args) => {
+				msg!("Instruction: CreateStream");
+				create_stream::create_stream(
+					program_id,
+					accounts, 
+					args.counter,
+					args.amount,
+					args.start_time,
+					args.end_time,
+				)
+			}
+			NescrowInstruction::Withdraw(args) => {
+				msg!("Instruction: Withdraw");
+				withdraw::withdraw(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+				)
+			}
+			NescrowInstruction::CancelStream(args) => {
+				msg!("Instruction: CancelStream");
+				cancel_stream::cancel_stream(
+					program_id,
+					accounts, 
+					args.counter,
+				)
+			}
+			NescrowInstruction::CreateRecurringPayment(args) => {
+				msg!("Instruction: CreateRecurringPayment");
+				create_recurring_payment::create_recurring_payment(
+					program_id,
+					accounts, 
+					args.counter,
+					args.tranche,
+					args.start_time,
+					args.period_length,
+					args.periods,
+				)
+			}
+			NescrowInstruction::ReleaseNextPeriod(args) => {
+				msg!("Instruction: ReleaseNextPeriod");
+				release_next_period::release_next_period(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+				)
+			}
+			NescrowInstruction::CancelRecurringPayment(args) => {
+				msg!("Instruction: CancelRecurringPayment");
+				cancel_recurring_payment::cancel_recurring_payment(
+					program_id,
+					accounts, 
+					args.counter,
+				)
+			}
+			NescrowInstruction::IncreaseStake(args) => {
+				msg!("Instruction: IncreaseStake");
+				increase_stake::increase_stake(
+					program_id,
+					accounts, 
+					args.counter,
+					args.increase,
+				)
+			}
+			NescrowInstruction::DecreaseStake(args) => {
+				msg!("Instruction: DecreaseStake");
+				decrease_stake::decrease_stake(
+					program_id,
+					accounts, 
+					args.counter,
+					args.decrease,
+				)
+			}
+			NescrowInstruction::AmendEscrow(args) => {
+				msg!("Instruction: AmendEscrow");
+				amend_escrow::amend_escrow(
+					program_id,
+					accounts, 
+					args.counter,
+					args.description,
+					args.amount,
+					args.expiry_time,
+				)
+			}
+			NescrowInstruction::CreatePurchase(args) => {
+				msg!("Instruction: CreatePurchase");
+				create_purchase::create_purchase(
+					program_id,
+					accounts, 
+					args.counter,
+					args.amount,
+					args.inspection_window,
+				)
+			}
+			NescrowInstruction::MarkShipped(args) => {
+				msg!("Instruction: MarkShipped");
+				mark_shipped::mark_shipped(
+					program_id,
+					accounts, 
+					args.buyer,
+					args.counter,
+				)
+			}
+			NescrowInstruction::ConfirmPurchase(args) => {
+				msg!("Instruction: ConfirmPurchase");
+				confirm_purchase::confirm_purchase(
+					program_id,
+					accounts, 
+					args.counter,
+				)
+			}
+			NescrowInstruction::DisputePurchase(args) => {
+				msg!("Instruction: DisputePurchase");
+				dispute_purchase::dispute_purchase(
+					program_id,
+					accounts, 
+					args.counter,
+				)
+			}
+			NescrowInstruction::AutoReleasePurchase(args) => {
+				msg!("Instruction: AutoReleasePurchase");
+				auto_release_purchase::auto_release_purchase(
+					program_id,
+					accounts, 
+					args.buyer,
+					args.counter,
+				)
+			}
+			NescrowInstruction::RefundPurchase(args) => {
+				msg!("Instruction: RefundPurchase");
+				refund_purchase::refund_purchase(
+					program_id,
+					accounts, 
+					args.counter,
+				)
+			}
+			NescrowInstruction::MutualCancel(args) => {
+				msg!("Instruction: MutualCancel");
+				mutual_cancel::mutual_cancel(
+					program_id,
+					accounts, 
+					args.counter,
+				)
+			}
+			NescrowInstruction::Concede(args) => {
+				msg!("Instruction: Concede");
+				concede::concede(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+				)
+			}
+			NescrowInstruction::CreatePool(args) => {
+				msg!("Instruction: CreatePool");
+				create_pool::create_pool(
+					program_id,
+					accounts, 
+					args.counter,
+					args.deposit,
+					args.max_participants,
+					args.join_deadline,
+					args.settle_deadline,
+				)
+			}
+			NescrowInstruction::JoinPool(args) => {
+				msg!("Instruction: JoinPool");
+				join_pool::join_pool(
+					program_id,
+					accounts, 
+					args.organizer,
+					args.counter,
+				)
+			}
+			NescrowInstruction::SettlePool(args) => {
+				msg!("Instruction: SettlePool");
+				settle_pool::settle_pool(
+					program_id,
+					accounts, 
+					args.counter,
+					args.winners,
+				)
+			}
+			NescrowInstruction::ClaimPool(args) => {
+				msg!("Instruction: ClaimPool");
+				claim_pool::claim_pool(
+					program_id,
+					accounts, 
+					args.organizer,
+					args.counter,
+				)
+			}
+			NescrowInstruction::CreateMarket(args) => {
+				msg!("Instruction: CreateMarket");
+				create_market::create_market(
+					program_id,
+					accounts, 
+					args.counter,
+					args.cutoff,
+					args.fee_bps,
+				)
+			}
+			NescrowInstruction::PlaceStake(args) => {
+				msg!("Instruction: PlaceStake");
+				place_stake::place_stake(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+					args.outcome,
+					args.stake,
+				)
+			}
+			NescrowInstruction::ResolveMarket(args) => {
+				msg!("Instruction: ResolveMarket");
+				resolve_market::resolve_market(
+					program_id,
+					accounts, 
+					args.counter,
+				)
+			}
+			NescrowInstruction::ClaimPosition(args) => {
+				msg!("Instruction: ClaimPosition");
+				claim_position::claim_position(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+					args.outcome,
+				)
+			}
+			NescrowInstruction::FillEscrow(args) => {
+				msg!("Instruction: FillEscrow");
+				fill_escrow::fill_escrow(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+					args.amount,
+				)
+			}
+			NescrowInstruction::AcceptFills(args) => {
+				msg!("Instruction: AcceptFills");
+				accept_fills::accept_fills(
+					program_id,
+					accounts, 
+					args.counter,
+				)
+			}
+			NescrowInstruction::SettleFills(args) => {
+				msg!("Instruction: SettleFills");
+				settle_fills::settle_fills(
+					program_id,
+					accounts, 
+					args.counter,
+				)
+			}
+			NescrowInstruction::ClaimFill(args) => {
+				msg!("Instruction: ClaimFill");
+				claim_fill::claim_fill(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+				)
+			}
+			NescrowInstruction::PostCounterOffer(args) => {
+				msg!("Instruction: PostCounterOffer");
+				post_counter_offer::post_counter_offer(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+					args.amount,
+					args.expiry_time,
+				)
+			}
+			NescrowInstruction::AcceptCounterOffer(args) => {
+				msg!("Instruction: AcceptCounterOffer");
+				accept_counter_offer::accept_counter_offer(
+					program_id,
+					accounts, 
+					args.counter,
+				)
+			}
+			NescrowInstruction::CloseCounterOffer(args) => {
+				msg!("Instruction: CloseCounterOffer");
+				close_counter_offer::close_counter_offer(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+				)
+			}
+			NescrowInstruction::TransferPosition(args) => {
+				msg!("Instruction: TransferPosition");
+				transfer_position::transfer_position(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+					args.price,
+				)
+			}
+			NescrowInstruction::TokenizePosition(args) => {
+				msg!("Instruction: TokenizePosition");
+				tokenize_position::tokenize_position(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+					args.side,
+				)
+			}
+			NescrowInstruction::RedeemPositionToken(args) => {
+				msg!("Instruction: RedeemPositionToken");
+				redeem_position_token::redeem_position_token(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+					args.side,
+				)
+			}
+			NescrowInstruction::ReportEventScore(args) => {
+				msg!("Instruction: ReportEventScore");
+				report_event_score::report_event_score(
+					program_id,
+					accounts, 
+					args.event_id,
+					args.home,
+					args.away,
+				)
+			}
+			NescrowInstruction::SettleLine(args) => {
+				msg!("Instruction: SettleLine");
+				settle_line::settle_line(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+				)
+			}
+			NescrowInstruction::ReportSeriesGame(args) => {
+				msg!("Instruction: ReportSeriesGame");
+				report_series_game::report_series_game(
+					program_id,
+					accounts, 
+					args.counter,
+					args.outcome,
+				)
+			}
+			NescrowInstruction::ResolveSeriesGame(args) => {
+				msg!("Instruction: ResolveSeriesGame");
+				resolve_series_game::resolve_series_game(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+				)
+			}
+			NescrowInstruction::CloneEscrow(args) => {
+				msg!("Instruction: CloneEscrow");
+				clone_escrow::clone_escrow(
+					program_id,
+					accounts, 
+					args.counter,
+					args.rematch_counter,
+					args.expiry_time,
+				)
+			}
+			NescrowInstruction::RefundParlay(args) => {
+				msg!("Instruction: RefundParlay");
+				refund_parlay::refund_parlay(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+				)
+			}
+			NescrowInstruction::RefundEventEscrow(args) => {
+				msg!("Instruction: RefundEventEscrow");
+				refund_event_escrow::refund_event_escrow(
+					program_id,
+					accounts, 
+					args.creator,
+					args.counter,
+				)
+			}
+			NescrowInstruction::RefundPool(args) => {
+				msg!("Instruction: RefundPool");
+				refund_pool::refund_pool(
+					program_id,
+					accounts, 
+					args.organizer,
+					args.counter,
+				)
+			}
         }
     }
 
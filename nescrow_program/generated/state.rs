@@ -45,5 +45,335 @@ pub struct Escrow {
 	pub expiry_time: i64,
 	pub escrow_bump: u8,
 	pub counter: u64,
+	pub oracle_kind: u8,
+	pub oracle_account: Pubkey,
+	pub price_condition: Option<PriceCondition>,
+	pub event_condition: Option<EventCondition>,
+	pub game: Option<Game>,
+	pub stream: Option<Stream>,
+	pub schedule: Option<Schedule>,
+	pub amendments: u32,
+	pub max_extension: i64,
+	pub rent_payer: Pubkey,
+	pub fills: Option<Fills>,
+	pub original_creator: Option<Pubkey>,
+	pub position_tokens: PositionTokens,
+	pub line: Option<Line>,
+	pub series: Option<Series>,
+	pub designated_taker: Option<Pubkey>,
+	pub created_slot: u64,
 }
 
+impl Escrow {
+	/// Space allocated for an escrow account
+	pub const LEN: usize = 833;
+}
+
+/// Best-of-N series settling an escrow once one side wins a majority of games
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Series {
+	pub length: u8,
+	pub results: Vec<Outcome>,
+}
+
+impl Series {
+	/// Longest series an escrow can be played over
+	pub const MAX_LENGTH: u8 = 9;
+}
+
+/// Supply-1 tokens standing for the sides of an escrow, with the pot held
+/// for whoever redeems the winning side's token
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PositionTokens {
+	pub creator_mint: Option<Pubkey>,
+	pub taker_mint: Option<Pubkey>,
+	pub held: u64,
+}
+
+/// Partial fills of an escrow's amount by any number of takers
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Fills {
+	pub min_fill: u64,
+	pub filled: u64,
+	pub unclaimed: u64,
+}
+
+/// Linear release of an escrow's amount to its taker
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stream {
+	pub start_time: i64,
+	pub end_time: i64,
+	pub withdrawn: u64,
+}
+
+/// Equal tranches of an escrow's amount released to its taker once per period
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Schedule {
+	pub tranche: u64,
+	pub start_time: i64,
+	pub period_length: i64,
+	pub periods: u32,
+	pub released: u32,
+}
+
+/// Commit-reveal game played between the two parties of an escrow
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Game {
+	pub game_kind: u8,
+	pub reveal_window: i64,
+	pub reveal_deadline: i64,
+	pub creator_commitment: [u8; 32],
+	pub taker_commitment: [u8; 32],
+	pub creator_choice: Option<u8>,
+	pub taker_choice: Option<u8>,
+}
+
+/// Creator wins if the oracle's price is at or above `threshold` at or after `resolve_after`
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PriceCondition {
+	pub threshold: i64,
+	pub exponent: i32,
+	pub resolve_after: i64,
+	pub max_staleness: i64,
+	pub max_confidence_bps: u16,
+}
+
+/// Outcomes of an event backed by each side of an escrow
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventCondition {
+	pub creator_outcome: u8,
+	pub taker_outcome: u8,
+}
+
+/// Real-world event whose result is posted by its reporter
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
+pub struct Event {
+	pub reporter: Pubkey,
+	pub event_id: u64,
+	pub start_time: i64,
+	pub outcomes: Vec<String>,
+	pub result: Option<u8>,
+	pub score: Option<Score>,
+	pub event_bump: u8,
+}
+
+impl Event {
+	/// Maximum number of outcomes an event can list
+	pub const MAX_OUTCOMES: usize = 8;
+	/// Maximum length in bytes of an outcome label
+	pub const MAX_OUTCOME_LEN: usize = 32;
+	/// Space allocated for an event account
+	pub const LEN: usize = 32 + 8 + 8 + 4 + Self::MAX_OUTCOMES * (4 + Self::MAX_OUTCOME_LEN) + 2 + 9 + 1;
+}
+
+/// Final score of an event, as posted by its reporter
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Score {
+	pub home: u32,
+	pub away: u32,
+}
+
+/// Spread or total line settling an escrow from an event's score
+///
+/// Lines are in half points, so a spread of -3.5 is stored as -7 and a
+/// total of 210.5 as 421. Whole-point lines can land exactly on the score,
+/// in which case the escrow is a push and both stakes are refunded.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Line {
+	/// The creator backs the home team, or the away team if not
+	/// `creator_home`, with `handicap` added to its score
+	Spread { creator_home: bool, handicap: i32 },
+	/// The creator backs the combined score going over `total`, or under it
+	/// if not `creator_over`
+	Total { creator_over: bool, total: u32 },
+}
+
+/// Outcome of a two-party escrow, as reported by its oracle
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+	CreatorWins,
+	TakerWins,
+}
+
+
+/// Node of a release condition tree
+///
+/// Trees are stored flat, with combinators referring to their children by
+/// index. Children always come before their parent and the last node is the
+/// root, so a tree is evaluated in one pass over its nodes.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub enum ConditionNode {
+	TimePassed { after: i64 },
+	HashRevealed { hash: [u8; 32] },
+	EventResult { event: Pubkey, outcome: u8 },
+	SignedBy { signer: Pubkey },
+	All { children: Vec<u8> },
+	Any { children: Vec<u8> },
+	Threshold { count: u8, children: Vec<u8> },
+	EscrowOutcome { escrow: Pubkey, created_slot: u64, outcome: Outcome },
+}
+
+/// Conditions under which an escrow's pot is released to either party
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
+pub struct ReleaseConditions {
+	pub escrow: Pubkey,
+	pub escrow_created_slot: u64,
+	pub creator_wins: Vec<ConditionNode>,
+	pub taker_wins: Vec<ConditionNode>,
+	pub conditions_bump: u8,
+}
+
+impl ReleaseConditions {
+	/// Maximum number of nodes in each tree
+	pub const MAX_NODES: usize = 16;
+	/// Largest encoded node, an escrow outcome
+	pub const MAX_NODE_LEN: usize = 1 + 32 + 8 + 1;
+	/// Space allocated for a release conditions account
+	pub const LEN: usize = 32 + 8 + 2 * (4 + Self::MAX_NODES * Self::MAX_NODE_LEN) + 1;
+}
+
+/// Milestone of a freelance escrow
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Milestone {
+	pub amount: u64,
+	pub description_hash: [u8; 32],
+	pub deadline: i64,
+	pub status: u8,
+}
+
+/// Freelance escrow funded by a client and released milestone by milestone
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
+pub struct MilestoneEscrow {
+	pub client: Pubkey,
+	pub worker: Pubkey,
+	pub counter: u64,
+	pub milestones: Vec<Milestone>,
+	pub escrow_bump: u8,
+}
+
+impl MilestoneEscrow {
+	/// Maximum number of milestones in an escrow
+	pub const MAX_MILESTONES: usize = 10;
+	/// Space allocated for a milestone escrow account
+	pub const LEN: usize = 32 + 32 + 8 + 4 + Self::MAX_MILESTONES * (8 + 32 + 8 + 1) + 1;
+}
+
+/// Marketplace purchase paid by a buyer and released to the seller after inspection
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
+pub struct Purchase {
+	pub buyer: Pubkey,
+	pub seller: Pubkey,
+	pub counter: u64,
+	pub amount: u64,
+	pub inspection_window: i64,
+	pub inspection_deadline: i64,
+	pub status: u8,
+	pub purchase_bump: u8,
+}
+
+impl Purchase {
+	/// Space allocated for a purchase account
+	pub const LEN: usize = 32 + 32 + 8 + 8 + 8 + 8 + 1 + 1;
+}
+
+/// Group escrow that any number of participants join with the same deposit
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
+pub struct Pool {
+	pub organizer: Pubkey,
+	pub counter: u64,
+	pub deposit: u64,
+	pub max_participants: u32,
+	pub participants: u32,
+	pub join_deadline: i64,
+	pub settle_deadline: i64,
+	pub status: u8,
+	pub winners: Vec<Pubkey>,
+	pub unclaimed_winners: u32,
+	pub pool_bump: u8,
+}
+
+impl Pool {
+	/// Maximum number of winners a pool can pay
+	pub const MAX_WINNERS: usize = 10;
+	/// Space allocated for a pool account
+	pub const LEN: usize = 32 + 8 + 8 + 4 + 4 + 8 + 8 + 1 + 4 + Self::MAX_WINNERS * 32 + 4 + 1;
+}
+
+/// Record of one participant's deposit into a pool
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
+pub struct Participant {
+	pub pool: Pubkey,
+	pub participant: Pubkey,
+	pub participant_bump: u8,
+}
+
+impl Participant {
+	/// Space allocated for a participant account
+	pub const LEN: usize = 32 + 32 + 1;
+}
+
+/// Parimutuel market on the outcomes of an event
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
+pub struct Market {
+	pub creator: Pubkey,
+	pub counter: u64,
+	pub event: Pubkey,
+	pub cutoff: i64,
+	pub fee_bps: u16,
+	pub outcome_totals: Vec<u64>,
+	pub status: u8,
+	pub winning_outcome: Option<u8>,
+	pub payout_pool: u64,
+	pub unclaimed_winning_stake: u64,
+	pub market_bump: u8,
+}
+
+impl Market {
+	/// Space allocated for a market account
+	pub const LEN: usize = 32 + 8 + 32 + 8 + 2 + 4 + Event::MAX_OUTCOMES * 8 + 1 + 2 + 8 + 8 + 1;
+}
+
+/// Stake of one account on one outcome of a market
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
+pub struct Position {
+	pub market: Pubkey,
+	pub owner: Pubkey,
+	pub outcome: u8,
+	pub stake: u64,
+	pub position_bump: u8,
+}
+
+impl Position {
+	/// Space allocated for a position account
+	pub const LEN: usize = 32 + 32 + 1 + 8 + 1;
+}
+
+/// Record of one taker's fill of an escrow
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
+pub struct Fill {
+	pub escrow: Pubkey,
+	pub taker: Pubkey,
+	pub amount: u64,
+	pub fill_bump: u8,
+}
+
+impl Fill {
+	/// Space allocated for a fill account
+	pub const LEN: usize = 32 + 32 + 8 + 1;
+}
+
+/// Alternative terms proposed by a prospective taker of an open escrow,
+/// holding the taker's stake until accepted or closed
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
+pub struct CounterOffer {
+	pub escrow: Pubkey,
+	pub taker: Pubkey,
+	pub amount: u64,
+	pub expiry_time: i64,
+	pub offer_bump: u8,
+}
+
+impl CounterOffer {
+	/// Space allocated for a counter-offer account
+	pub const LEN: usize = 32 + 32 + 8 + 8 + 1;
+}
@@ -0,0 +1,105 @@
+use std::str::FromStr;
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    pubkey::Pubkey,
+    system_instruction::transfer,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::{close_account, load_escrow};
+use crate::src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_OPEN};
+use crate::src::post_counter_offer::load_counter_offer;
+
+/// Accept a counter-offer, taking on its terms and binding its taker
+///
+/// The creator's stake is brought to the offered amount, topped up from
+/// the creator or partly returned to them, and the taker's stake moves from
+/// the counter-offer into the escrow. The counter-offer is then closed and
+/// its rent returned to the taker. Other counter-offers on the escrow
+/// become stale and can be closed by anyone.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to accept
+/// * `[writable]` counter_offer: Counter-offer to accept
+/// * `[writable, signer]` creator: Creator of the escrow
+/// * `[writable]` taker: Taker who made the counter-offer
+/// * `[]` system_program: System program for the stake top-up
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotOpen` - If the escrow was already accepted, completed or cancelled
+/// * `EscrowExpired` - If the escrow or the offered expiry time has passed
+pub fn accept_counter_offer(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let counter_offer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let taker_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, creator_info.key, counter)?;
+    let counter_offer = load_counter_offer(program_id, counter_offer_info, escrow_info.key, taker_info.key)?;
+
+    if !fee_payer_info.is_signer || !creator_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    if *system_program_info.key != system_program_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_OPEN {
+        return Err(NescrowError::EscrowNotOpen.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    if now >= escrow.data.expiry_time || now >= counter_offer.data.expiry_time {
+        return Err(NescrowError::EscrowExpired.into());
+    }
+
+    // Bring the creator's stake to the offered amount
+    let amount = counter_offer.data.amount;
+    if amount > escrow.data.amount {
+        invoke(
+            &transfer(creator_info.key, escrow_info.key, amount - escrow.data.amount),
+            &[creator_info.clone(), escrow_info.clone(), system_program_info.clone()],
+        )?;
+    } else {
+        let decrease = escrow.data.amount - amount;
+        **escrow_info.try_borrow_mut_lamports()? -= decrease;
+        **creator_info.try_borrow_mut_lamports()? += decrease;
+    }
+
+    // Move the taker's stake into the escrow
+    **counter_offer_info.try_borrow_mut_lamports()? -= amount;
+    **escrow_info.try_borrow_mut_lamports()? += amount;
+
+    close_account(counter_offer_info, taker_info)?;
+
+    escrow.data.amount = amount;
+    escrow.data.expiry_time = counter_offer.data.expiry_time;
+    escrow.data.taker = Some(*taker_info.key);
+    escrow.data.status = ESCROW_STATUS_ACCEPTED;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Counter-offer of {} lamports by {} accepted", amount, taker_info.key);
+    Ok(())
+}
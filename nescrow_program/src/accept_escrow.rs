@@ -1,111 +1,114 @@
 use std::str::FromStr;
-use std::ops::DerefMut;
-use borsh::{BorshDeserialize, BorshSerialize};
-
-// need to dynamically include this when there is an invoke
-use solana_program::instruction::{AccountMeta, Instruction};
-
-// need to dynamically include this when there is an invoke
-use solana_program::program::{invoke, invoke_signed};
-
-use solana_program::borsh0_10::try_from_slice_unchecked;
-use solana_program::account_info::{AccountInfo, next_account_info, next_account_infos};
-use solana_program::entrypoint::ProgramResult;
-use solana_program::pubkey::Pubkey;
-use solana_program::rent::Rent;
-use solana_program::system_instruction::create_account;
-use solana_program::{msg, system_program};
-use solana_program::sysvar::Sysvar;
-use solana_program::program_pack::Pack;
-use crate::generated::errors::NescrowError;
-use crate::generated::state::*;
-use solana_program::keccak;
-
-use crate::generated::state::{
-	AccountPDA,
-	Escrow,
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    pubkey::Pubkey,
+    system_instruction::transfer,
+    sysvar::Sysvar,
 };
 
-
-
+use crate::generated::errors::NescrowError;
+use crate::generated::state::Escrow;
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_OPEN};
 
 /// Accept an existing escrow
 ///
-/// Accounts:
-/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
-/// 1. `[writable]` escrow: [Escrow] 
-/// 2. `[writable, signer]` taker: [AccountInfo] 
+/// The taker matches the creator's stake, which is moved into the escrow
+/// account alongside it. For games the taker also commits to their choice,
+/// which starts the reveal window.
+///
+/// # Accounts
 ///
-/// Data:
-/// - creator: [Pubkey] 
-/// - counter: [u64] 
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to accept
+/// * `[writable, signer]` taker: Counterparty of the escrow
+/// * `[]` system_program: System program for the stake transfer
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `WrongAccountOwner` - If the escrow is not owned by the program
+/// * `InvalidAccountLen` - If account data length is incorrect
+/// * `EscrowNotOpen` - If the escrow was already accepted, completed or cancelled
+/// * `EscrowExpired` - If the escrow expiry time has passed
+/// * `InvalidAuthority` - If the creator tries to take their own escrow, or
+///   the escrow is reserved for another taker
+/// * `InvalidCommitment` - If a commitment is missing for a game or given for anything else
+/// * `InvalidFill` - If the escrow is taken through partial fills
 pub fn accept_escrow(
-	program_id: &Pubkey,
-	accounts: &[AccountInfo],
-	creator: Pubkey,
-	counter: u64,
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+    commitment: Option<[u8; 32]>,
 ) -> ProgramResult {
-    // Implement your business logic here...
-let account_info_iter = &mut accounts.iter();
-	let fee_payer_info = next_account_info(account_info_iter)?;
-	let escrow_info = next_account_info(account_info_iter)?;
-	let taker_info = next_account_info(account_info_iter)?;
-
-	// Derive PDAs
-	let (escrow_pubkey, escrow_bump) = Pubkey::find_program_address(
-		&[b"escrow", creator.as_ref(), counter.to_le_bytes().as_ref()],
-		program_id,
-	);
-
-	// Security Checks
-	if fee_payer_info.is_signer != true {
-		return Err(NescrowError::InvalidSignerPermission.into());
-	}
-
-	if *escrow_info.key != escrow_pubkey {
-		return Err(NescrowError::NotExpectedAddress.into());
-	}
-
-	if taker_info.is_signer != true {
-		return Err(NescrowError::InvalidSignerPermission.into());
-	}
-
-
-
-	// Security Checks
-	if *escrow_info.owner != *program_id {
-		return Err(NescrowError::WrongAccountOwner.into());
-	}
-
-	if *taker_info.owner != *program_id {
-		return Err(NescrowError::WrongAccountOwner.into());
-	}
-
-	if escrow_info.data_len() != 383usize {
-		return Err(NescrowError::InvalidAccountLen.into());
-	}
-
-
-	// Accounts Deserialization
-	let escrow = &mut AccountPDA::new(
-		&escrow_info,
-		try_from_slice_unchecked::<Escrow>(&escrow_info.data.borrow()).unwrap(),
-		escrow_bump,
-	);
-
-
-	// Compressions
-
-
-
-	// CPIs - Modify Default Values
-
-
-
-	// Compression Calls - Modify Default Values
-
-	// Accounts Serialization
-	escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
-
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let taker_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, &creator, counter)?;
+
+    if !fee_payer_info.is_signer || !taker_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    if *system_program_info.key != system_program_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    // Validate escrow state
+    if escrow.data.status != ESCROW_STATUS_OPEN {
+        return Err(NescrowError::EscrowNotOpen.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    if now >= escrow.data.expiry_time {
+        return Err(NescrowError::EscrowExpired.into());
+    }
+
+    if *taker_info.key == escrow.data.creator || is_reserved_for_other(&escrow.data, taker_info.key) {
+        return Err(NescrowError::InvalidAuthority.into());
+    }
+
+    if escrow.data.fills.is_some() {
+        return Err(NescrowError::InvalidFill.into());
+    }
+
+    match (&mut escrow.data.game, commitment) {
+        (Some(game), Some(commitment)) => {
+            game.taker_commitment = commitment;
+            game.reveal_deadline = now.saturating_add(game.reveal_window);
+        }
+        (None, None) => {}
+        _ => return Err(NescrowError::InvalidCommitment.into()),
+    }
+
+    // Match the creator's stake
+    invoke(
+        &transfer(taker_info.key, escrow_info.key, escrow.data.amount),
+        &[taker_info.clone(), escrow_info.clone(), system_program_info.clone()],
+    )?;
+
+    escrow.data.taker = Some(*taker_info.key);
+    escrow.data.status = ESCROW_STATUS_ACCEPTED;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Escrow accepted by {}", taker_info.key);
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Whether the escrow can only be taken by someone other than `key`
+pub fn is_reserved_for_other(escrow: &Escrow, key: &Pubkey) -> bool {
+    escrow.designated_taker.is_some_and(|taker| taker != *key)
+}
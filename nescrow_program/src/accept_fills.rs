@@ -0,0 +1,76 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_OPEN};
+
+/// Accept a partially filled escrow once its minimum fill is reached
+///
+/// The creator's stake is lowered to the filled amount and the difference
+/// returned to the creator, so both sides of the pot stay equal.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to accept
+/// * `[writable, signer]` creator: Creator of the escrow
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotOpen` - If the escrow was already accepted, completed or cancelled
+/// * `EscrowExpired` - If the escrow expiry time has passed
+/// * `InvalidFill` - If the escrow does not take fills
+/// * `MinFillNotReached` - If less than the minimum fill has been filled
+pub fn accept_fills(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, creator_info.key, counter)?;
+
+    if !fee_payer_info.is_signer || !creator_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_OPEN {
+        return Err(NescrowError::EscrowNotOpen.into());
+    }
+
+    if Clock::get()?.unix_timestamp >= escrow.data.expiry_time {
+        return Err(NescrowError::EscrowExpired.into());
+    }
+
+    let fills = escrow.data.fills.as_ref().ok_or(NescrowError::InvalidFill)?;
+    if fills.filled < fills.min_fill {
+        return Err(NescrowError::MinFillNotReached.into());
+    }
+
+    let unfilled = escrow.data.amount - fills.filled;
+
+    **escrow_info.try_borrow_mut_lamports()? -= unfilled;
+    **creator_info.try_borrow_mut_lamports()? += unfilled;
+
+    escrow.data.amount = fills.filled;
+    escrow.data.status = ESCROW_STATUS_ACCEPTED;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Escrow accepted with {} lamports filled", escrow.data.amount);
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    pubkey::Pubkey,
+    system_instruction::transfer,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::ESCROW_STATUS_ACCEPTED;
+use crate::src::tokenize_position::is_tokenized;
+
+/// Change any subset of the terms of an accepted escrow
+///
+/// Both parties sign. When the amount changes, each side pays in or gets
+/// back the difference so the pot stays equal to twice the stored amount.
+/// Every amendment bumps the escrow's amendment counter.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to amend
+/// * `[writable, signer]` creator: Creator of the escrow
+/// * `[writable, signer]` taker: Taker of the escrow
+/// * `[]` system_program: System program for the transfers
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the escrow is not in Accepted status
+/// * `EmptyAmendment` - If no term is given
+/// * `InvalidStakeChange` - If the amount changes on a stream, recurring
+///   payment or tokenized escrow
+pub fn amend_escrow(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+    description: Option<String>,
+    amount: Option<u64>,
+    expiry_time: Option<i64>,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let taker_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, creator_info.key, counter)?;
+
+    if !fee_payer_info.is_signer || !creator_info.is_signer || !taker_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    if Some(*taker_info.key) != escrow.data.taker || *system_program_info.key != system_program_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    if description.is_none() && amount.is_none() && expiry_time.is_none() {
+        return Err(NescrowError::EmptyAmendment.into());
+    }
+
+    if let Some(amount) = amount {
+        if escrow.data.stream.is_some() || escrow.data.schedule.is_some() || is_tokenized(&escrow.data) {
+            return Err(NescrowError::InvalidStakeChange.into());
+        }
+
+        match amount.cmp(&escrow.data.amount) {
+            Ordering::Greater => {
+                let increase = amount - escrow.data.amount;
+                for party_info in [creator_info, taker_info] {
+                    invoke(
+                        &transfer(party_info.key, escrow_info.key, increase),
+                        &[party_info.clone(), escrow_info.clone(), system_program_info.clone()],
+                    )?;
+                }
+            }
+            Ordering::Less => {
+                let decrease = escrow.data.amount - amount;
+                **escrow_info.try_borrow_mut_lamports()? -= 2 * decrease;
+                **creator_info.try_borrow_mut_lamports()? += decrease;
+                **taker_info.try_borrow_mut_lamports()? += decrease;
+            }
+            Ordering::Equal => {}
+        }
+
+        escrow.data.amount = amount;
+    }
+
+    if let Some(description) = description {
+        escrow.data.description = description;
+    }
+
+    if let Some(expiry_time) = expiry_time {
+        escrow.data.expiry_time = expiry_time;
+    }
+
+    escrow.data.amendments += 1;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Escrow amended, amendment {}", escrow.data.amendments);
+    Ok(())
+}
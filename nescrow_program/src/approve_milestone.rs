@@ -0,0 +1,75 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::create_milestone_escrow::{
+    load_milestone_escrow, MILESTONE_STATUS_DISPUTED, MILESTONE_STATUS_PENDING, MILESTONE_STATUS_RELEASED,
+};
+
+/// Release one milestone's funds to the worker
+///
+/// Milestones can be approved in any order and at any time, including a
+/// disputed one, which settles the dispute in the worker's favour.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Milestone escrow holding the funds
+/// * `[signer]` client: Client of the escrow
+/// * `[writable]` worker: Worker of the escrow
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `InvalidMilestones` - If the milestone index is out of range
+/// * `MilestoneNotPending` - If the milestone was already released or refunded
+pub fn approve_milestone(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+    index: u8,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let client_info = next_account_info(account_info_iter)?;
+    let worker_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_milestone_escrow(program_id, escrow_info, client_info.key, counter)?;
+
+    if !fee_payer_info.is_signer || !client_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if *worker_info.key != escrow.data.worker {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    let milestone = escrow
+        .data
+        .milestones
+        .get_mut(index as usize)
+        .ok_or(NescrowError::InvalidMilestones)?;
+
+    if milestone.status != MILESTONE_STATUS_PENDING && milestone.status != MILESTONE_STATUS_DISPUTED {
+        return Err(NescrowError::MilestoneNotPending.into());
+    }
+
+    **escrow_info.try_borrow_mut_lamports()? -= milestone.amount;
+    **worker_info.try_borrow_mut_lamports()? += milestone.amount;
+
+    milestone.status = MILESTONE_STATUS_RELEASED;
+
+    msg!("Released milestone {} of {} lamports", index, milestone.amount);
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
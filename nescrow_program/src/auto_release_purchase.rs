@@ -0,0 +1,70 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::create_purchase::{load_purchase, PURCHASE_STATUS_RELEASED, PURCHASE_STATUS_SHIPPED};
+
+/// Release an undisputed purchase to the seller after its inspection window
+///
+/// Anyone may call this, so the seller is paid even if the buyer never
+/// confirms.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` purchase: Purchase holding the payment
+/// * `[writable]` seller: Seller of the goods
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `InvalidPurchaseStatus` - If the purchase is not shipped, or was disputed
+/// * `InspectionWindowOpen` - If the inspection window has not passed
+pub fn auto_release_purchase(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    buyer: Pubkey,
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let purchase_info = next_account_info(account_info_iter)?;
+    let seller_info = next_account_info(account_info_iter)?;
+
+    let purchase = &mut load_purchase(program_id, purchase_info, &buyer, counter)?;
+
+    if !fee_payer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if *seller_info.key != purchase.data.seller {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if purchase.data.status != PURCHASE_STATUS_SHIPPED {
+        return Err(NescrowError::InvalidPurchaseStatus.into());
+    }
+
+    if Clock::get()?.unix_timestamp <= purchase.data.inspection_deadline {
+        return Err(NescrowError::InspectionWindowOpen.into());
+    }
+
+    **purchase_info.try_borrow_mut_lamports()? -= purchase.data.amount;
+    **seller_info.try_borrow_mut_lamports()? += purchase.data.amount;
+
+    purchase.data.status = PURCHASE_STATUS_RELEASED;
+
+    purchase.data.serialize(&mut &mut purchase_info.data.borrow_mut()[..])?;
+
+    msg!("Purchase released, {} lamports paid to {}", purchase.data.amount, seller_info.key);
+    Ok(())
+}
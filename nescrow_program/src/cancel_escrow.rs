@@ -1,109 +1,76 @@
-use std::str::FromStr;
-use std::ops::DerefMut;
-use borsh::{BorshDeserialize, BorshSerialize};
-
-// need to dynamically include this when there is an invoke
-use solana_program::instruction::{AccountMeta, Instruction};
-
-// need to dynamically include this when there is an invoke
-use solana_program::program::{invoke, invoke_signed};
-
-use solana_program::borsh0_10::try_from_slice_unchecked;
-use solana_program::account_info::{AccountInfo, next_account_info, next_account_infos};
-use solana_program::entrypoint::ProgramResult;
-use solana_program::pubkey::Pubkey;
-use solana_program::rent::Rent;
-use solana_program::system_instruction::create_account;
-use solana_program::{msg, system_program};
-use solana_program::sysvar::Sysvar;
-use solana_program::program_pack::Pack;
-use crate::generated::errors::NescrowError;
-use crate::generated::state::*;
-use solana_program::keccak;
-
-use crate::generated::state::{
-	AccountPDA,
-	Escrow,
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
 };
 
-
-
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::{ESCROW_STATUS_CANCELLED, ESCROW_STATUS_OPEN};
 
 /// Cancel an escrow if not yet accepted
 ///
-/// Accounts:
-/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
-/// 1. `[writable]` escrow: [Escrow] 
-/// 2. `[writable, signer]` creator: [AccountInfo] 
+/// The creator's stake, everything above the rent-exempt minimum, goes back
+/// to the creator and the escrow is marked cancelled.
+///
+/// # Accounts
 ///
-/// Data:
-/// - counter: [u64] 
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to cancel
+/// * `[writable, signer]` creator: Creator of the escrow
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `WrongAccountOwner` - If the escrow is not owned by the program
+/// * `InvalidAccountLen` - If account data length is incorrect
+/// * `EscrowNotOpen` - If the escrow was already accepted, completed or cancelled
+/// * `InvalidFill` - If takers have already filled part of the escrow
 pub fn cancel_escrow(
-	program_id: &Pubkey,
-	accounts: &[AccountInfo],
-	counter: u64,
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
 ) -> ProgramResult {
-    // Implement your business logic here...
-let account_info_iter = &mut accounts.iter();
-	let fee_payer_info = next_account_info(account_info_iter)?;
-	let escrow_info = next_account_info(account_info_iter)?;
-	let creator_info = next_account_info(account_info_iter)?;
-
-	// Derive PDAs
-	let (escrow_pubkey, escrow_bump) = Pubkey::find_program_address(
-		&[b"escrow", creator_info.key.as_ref(), counter.to_le_bytes().as_ref()],
-		program_id,
-	);
-
-	// Security Checks
-	if fee_payer_info.is_signer != true {
-		return Err(NescrowError::InvalidSignerPermission.into());
-	}
-
-	if *escrow_info.key != escrow_pubkey {
-		return Err(NescrowError::NotExpectedAddress.into());
-	}
-
-	if creator_info.is_signer != true {
-		return Err(NescrowError::InvalidSignerPermission.into());
-	}
-
-
-
-	// Security Checks
-	if *escrow_info.owner != *program_id {
-		return Err(NescrowError::WrongAccountOwner.into());
-	}
-
-	if *creator_info.owner != *program_id {
-		return Err(NescrowError::WrongAccountOwner.into());
-	}
-
-	if escrow_info.data_len() != 383usize {
-		return Err(NescrowError::InvalidAccountLen.into());
-	}
-
-
-	// Accounts Deserialization
-	let escrow = &mut AccountPDA::new(
-		&escrow_info,
-		try_from_slice_unchecked::<Escrow>(&escrow_info.data.borrow()).unwrap(),
-		escrow_bump,
-	);
-
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
 
-	// Compressions
+    let escrow = &mut load_escrow(program_id, escrow_info, creator_info.key, counter)?;
 
+    if !fee_payer_info.is_signer || !creator_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
 
+    if escrow.data.status != ESCROW_STATUS_OPEN {
+        return Err(NescrowError::EscrowNotOpen.into());
+    }
 
-	// CPIs - Modify Default Values
+    // Fills are refunded to their takers through ClaimFill once the escrow expires
+    if escrow.data.fills.as_ref().is_some_and(|fills| fills.filled > 0) {
+        return Err(NescrowError::InvalidFill.into());
+    }
 
+    let rent_minimum_balance = Rent::get()?.minimum_balance(escrow_info.data_len());
+    let refund = escrow_info
+        .lamports()
+        .checked_sub(rent_minimum_balance)
+        .ok_or(ProgramError::InsufficientFunds)?;
 
+    **escrow_info.try_borrow_mut_lamports()? -= refund;
+    **creator_info.try_borrow_mut_lamports()? += refund;
 
-	// Compression Calls - Modify Default Values
+    escrow.data.status = ESCROW_STATUS_CANCELLED;
 
-	// Accounts Serialization
-	escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
 
+    msg!("Escrow {} cancelled, refunding {} lamports to the creator", escrow_info.key, refund);
     Ok(())
-}
\ No newline at end of file
+}
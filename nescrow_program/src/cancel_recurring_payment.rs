@@ -0,0 +1,78 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_CANCELLED};
+use crate::src::create_recurring_payment::elapsed_periods;
+
+/// Stop the future periods of a recurring payment
+///
+/// Tranches for periods that have already ended are paid to the payee, even
+/// if nobody released them yet. Only the tranches of periods still to come
+/// are refunded to the creator.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account of the recurring payment
+/// * `[writable, signer]` creator: Creator of the recurring payment
+/// * `[writable]` payee: Payee of the recurring payment
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the payment was cancelled or fully released
+/// * `NotRecurring` - If the escrow is not a recurring payment
+pub fn cancel_recurring_payment(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let payee_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, creator_info.key, counter)?;
+
+    if !fee_payer_info.is_signer || !creator_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    if Some(*payee_info.key) != escrow.data.taker {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    let schedule = escrow.data.schedule.as_mut().ok_or(NescrowError::NotRecurring)?;
+
+    let elapsed = elapsed_periods(schedule, Clock::get()?.unix_timestamp);
+    let owed = schedule.tranche * (elapsed - schedule.released) as u64;
+    let refunded = schedule.tranche * (schedule.periods - elapsed) as u64;
+
+    **escrow_info.try_borrow_mut_lamports()? -= owed + refunded;
+    **payee_info.try_borrow_mut_lamports()? += owed;
+    **creator_info.try_borrow_mut_lamports()? += refunded;
+
+    schedule.released = elapsed;
+    escrow.data.status = ESCROW_STATUS_CANCELLED;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Recurring payment cancelled, {} lamports paid out and {} refunded", owed, refunded);
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_CANCELLED};
+use crate::src::create_stream::vested_amount;
+
+/// Stop a stream and refund the unvested remainder to its creator
+///
+/// Whatever has vested but was not withdrawn yet is paid to the beneficiary
+/// in the same instruction, so cancelling never claws back vested funds.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account of the stream
+/// * `[writable, signer]` creator: Creator of the stream
+/// * `[writable]` beneficiary: Beneficiary of the stream
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the stream was cancelled or fully withdrawn
+/// * `NotAStream` - If the escrow is not a stream
+pub fn cancel_stream(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let beneficiary_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, creator_info.key, counter)?;
+
+    if !fee_payer_info.is_signer || !creator_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    if Some(*beneficiary_info.key) != escrow.data.taker {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    let amount = escrow.data.amount;
+    let stream = escrow.data.stream.as_mut().ok_or(NescrowError::NotAStream)?;
+
+    let vested = vested_amount(amount, stream, Clock::get()?.unix_timestamp);
+    let owed = vested - stream.withdrawn;
+    let unvested = amount - vested;
+
+    **escrow_info.try_borrow_mut_lamports()? -= owed + unvested;
+    **beneficiary_info.try_borrow_mut_lamports()? += owed;
+    **creator_info.try_borrow_mut_lamports()? += unvested;
+
+    stream.withdrawn = vested;
+    escrow.data.status = ESCROW_STATUS_CANCELLED;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Stream cancelled, {} lamports paid out and {} refunded", owed, unvested);
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::{close_account, load_escrow};
+use crate::src::create_escrow::{ESCROW_STATUS_CANCELLED, ESCROW_STATUS_COMPLETED, ESCROW_STATUS_OPEN};
+use crate::src::fill_escrow::load_fill;
+
+/// Pay out a fill of a settled or expired escrow and close it
+///
+/// When the takers won, each fill receives its share of what is left of
+/// the pot in proportion to the fills not yet claimed, so the last claim
+/// also collects the rounding remainder. When the creator won, fills are
+/// only closed. Fills of an escrow that expired before being accepted, or
+/// that was refunded because its event result was backed by neither side,
+/// are refunded. The fill record's rent always returns to its taker. Anyone
+/// may call this, since funds only ever go to the taker.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account the fill belongs to
+/// * `[writable]` fill: Fill record to pay out and close
+/// * `[writable]` taker: Taker of the fill
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `InvalidFill` - If the escrow is not taken through fills
+/// * `FillsNotSettled` - If the escrow is neither settled, refunded nor expired unfilled
+pub fn claim_fill(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let fill_info = next_account_info(account_info_iter)?;
+    let taker_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, &creator, counter)?;
+    let fill = load_fill(program_id, fill_info, escrow_info.key, taker_info.key)?;
+
+    if !fee_payer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let status = escrow.data.status;
+    let expired = Clock::get()?.unix_timestamp >= escrow.data.expiry_time;
+    let refundable = status == ESCROW_STATUS_CANCELLED || expired;
+    let creator_won = escrow.data.winner == Some(escrow.data.creator);
+    let fills = escrow.data.fills.as_mut().ok_or(NescrowError::InvalidFill)?;
+
+    let payout = match status {
+        ESCROW_STATUS_COMPLETED if creator_won => 0,
+        ESCROW_STATUS_COMPLETED => {
+            let rent_minimum_balance = Rent::get()?.minimum_balance(escrow_info.data_len());
+            let pot = escrow_info
+                .lamports()
+                .checked_sub(rent_minimum_balance)
+                .ok_or(ProgramError::InsufficientFunds)?;
+            let payout = (pot as u128 * fill.data.amount as u128 / fills.unclaimed as u128) as u64;
+            fills.unclaimed -= fill.data.amount;
+            payout
+        }
+        ESCROW_STATUS_OPEN | ESCROW_STATUS_CANCELLED if refundable => {
+            fills.filled -= fill.data.amount;
+            fill.data.amount
+        }
+        _ => return Err(NescrowError::FillsNotSettled.into()),
+    };
+
+    **escrow_info.try_borrow_mut_lamports()? -= payout;
+    **taker_info.try_borrow_mut_lamports()? += payout;
+
+    close_account(fill_info, taker_info)?;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Paid {} lamports to {} for their fill", payout, taker_info.key);
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::close_account;
+use crate::src::create_pool::{load_participant, load_pool, POOL_STATUS_REFUNDED, POOL_STATUS_SETTLED};
+
+/// Claim a participant's payout or refund from a settled pool
+///
+/// Refunded pools, whether by the organizer or after the settlement
+/// deadline, return each deposit. Settled pools split the pot evenly
+/// between the winners, the last winner to claim also receiving the
+/// rounding remainder; other participants receive nothing. Either way the
+/// participant record is closed and its rent returned. Anyone may call
+/// this, since funds only ever go to the participant.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` pool: Settled pool
+/// * `[writable]` participant_record: Participant record to close
+/// * `[writable]` participant: Participant receiving the payout
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `PoolNotSettled` - If the pool has not been settled
+pub fn claim_pool(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    organizer: Pubkey,
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let pool_info = next_account_info(account_info_iter)?;
+    let record_info = next_account_info(account_info_iter)?;
+    let participant_info = next_account_info(account_info_iter)?;
+
+    let pool = &mut load_pool(program_id, pool_info, &organizer, counter)?;
+    load_participant(program_id, record_info, pool_info.key, participant_info.key)?;
+
+    if !fee_payer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let payout = match pool.data.status {
+        POOL_STATUS_REFUNDED => pool.data.deposit,
+        POOL_STATUS_SETTLED if pool.data.winners.contains(participant_info.key) => {
+            pool.data.unclaimed_winners -= 1;
+            if pool.data.unclaimed_winners == 0 {
+                pool_info
+                    .lamports()
+                    .checked_sub(Rent::get()?.minimum_balance(pool_info.data_len()))
+                    .ok_or(ProgramError::InsufficientFunds)?
+            } else {
+                pool.data.deposit * pool.data.participants as u64 / pool.data.winners.len() as u64
+            }
+        }
+        POOL_STATUS_SETTLED => 0,
+        _ => return Err(NescrowError::PoolNotSettled.into()),
+    };
+
+    **pool_info.try_borrow_mut_lamports()? -= payout;
+    **participant_info.try_borrow_mut_lamports()? += payout;
+
+    close_account(record_info, participant_info)?;
+
+    pool.data.serialize(&mut &mut pool_info.data.borrow_mut()[..])?;
+
+    msg!("Paid {} lamports from pool to {}", payout, participant_info.key);
+    Ok(())
+}
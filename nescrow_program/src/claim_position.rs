@@ -0,0 +1,79 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::close_account;
+use crate::src::create_market::{
+    load_market, load_position, MARKET_STATUS_REFUNDED, MARKET_STATUS_RESOLVED,
+};
+
+/// Pay out a position of a resolved market and close it
+///
+/// A winning position receives its share of what is left of the payout
+/// pool in proportion to the winning stake not yet claimed, so the last
+/// claim also collects the rounding remainder. Losing positions receive
+/// nothing and are only closed. Positions of a refunded market get their
+/// stake back. The position's rent always returns to its owner. Anyone
+/// may call this, since funds only ever go to the owner.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` market: Resolved market
+/// * `[writable]` position: Position to pay out and close
+/// * `[writable]` owner: Owner of the position
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `MarketNotResolved` - If the market has not been resolved
+pub fn claim_position(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+    outcome: u8,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let market_info = next_account_info(account_info_iter)?;
+    let position_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+
+    let market = &mut load_market(program_id, market_info, &creator, counter)?;
+    let position = load_position(program_id, position_info, market_info.key, owner_info.key, outcome)?;
+
+    if !fee_payer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let payout = match market.data.status {
+        MARKET_STATUS_REFUNDED => position.data.stake,
+        MARKET_STATUS_RESOLVED if market.data.winning_outcome == Some(outcome) => {
+            let payout = (market.data.payout_pool as u128 * position.data.stake as u128
+                / market.data.unclaimed_winning_stake as u128) as u64;
+            market.data.payout_pool -= payout;
+            market.data.unclaimed_winning_stake -= position.data.stake;
+            payout
+        }
+        MARKET_STATUS_RESOLVED => 0,
+        _ => return Err(NescrowError::MarketNotResolved.into()),
+    };
+
+    **market_info.try_borrow_mut_lamports()? -= payout;
+    **owner_info.try_borrow_mut_lamports()? += payout;
+
+    close_account(position_info, owner_info)?;
+
+    market.data.serialize(&mut &mut market_info.data.borrow_mut()[..])?;
+
+    msg!("Paid {} lamports to {} for outcome {}", payout, owner_info.key, outcome);
+    Ok(())
+}
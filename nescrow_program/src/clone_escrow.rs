@@ -0,0 +1,158 @@
+use std::str::FromStr;
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::{allocate, assign, transfer},
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::Escrow;
+use crate::src::complete_escrow::{close_account, is_closable, load_escrow};
+use crate::src::create_escrow::{ESCROW_STATUS_COMPLETED, ESCROW_STATUS_OPEN};
+
+/// Open a rematch of a completed escrow, reserved for its taker
+///
+/// The rematch is created at `rematch_counter` with the same description
+/// and stake, and the creator's stake is locked as on creation. It is a new
+/// escrow of the current creator, so its address is derived from them even
+/// if the completed escrow's was derived from an original creator who has
+/// since transferred their position.
+/// The completed escrow is closed into the new account so its rent carries
+/// over, topped up by the fee payer if rent has risen since. Only the
+/// previous taker can accept the rematch.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account topping up the rent if needed
+/// * `[writable]` escrow: Completed escrow to rematch
+/// * `[writable]` rematch: Escrow account to be created
+/// * `[writable, signer]` creator: Current creator of the escrow, and creator of the rematch
+/// * `[]` system_program: System program for account creation
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `NotClonable` - If the escrow has not completed, had no taker, was taken
+///   through fills, still holds a pot for a position token or was created in
+///   the current slot
+/// * `InvalidExpiry` - If the rematch expiry time is not in the future
+pub fn clone_escrow(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+    rematch_counter: u64,
+    expiry_time: i64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let rematch_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let escrow = load_escrow(program_id, escrow_info, creator_info.key, counter)?;
+
+    // Derive PDA for the rematch from its own creator, not from the
+    // completed escrow's seed creator, which may be a previous owner
+    let rematch_seed_creator = creator_info.key;
+    let (rematch_pubkey, rematch_bump) = Pubkey::find_program_address(
+        &[b"escrow", rematch_seed_creator.as_ref(), rematch_counter.to_le_bytes().as_ref()],
+        program_id,
+    );
+
+    // Validate accounts
+    if !fee_payer_info.is_signer || !creator_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    // The completed escrow is only closed once the rematch exists, so it
+    // cannot also be the rematch
+    if *rematch_info.key != rematch_pubkey
+        || rematch_info.key == escrow_info.key
+        || *system_program_info.key != system_program_id
+    {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    let taker = match escrow.data.taker {
+        Some(taker)
+            if escrow.data.status == ESCROW_STATUS_COMPLETED
+                && escrow.data.fills.is_none()
+                && escrow.data.position_tokens.held == 0
+                && is_closable(&escrow.data)? =>
+        {
+            taker
+        }
+        _ => return Err(NescrowError::NotClonable.into()),
+    };
+
+    if expiry_time <= Clock::get()?.unix_timestamp {
+        return Err(NescrowError::InvalidExpiry.into());
+    }
+
+    let rematch_seeds: &[&[u8]] = &[
+        b"escrow",
+        rematch_seed_creator.as_ref(),
+        &rematch_counter.to_le_bytes(),
+        &[rematch_bump],
+    ];
+
+    invoke_signed(
+        &allocate(rematch_info.key, Escrow::LEN as u64),
+        &[rematch_info.clone(), system_program_info.clone()],
+        &[rematch_seeds],
+    )?;
+
+    invoke_signed(
+        &assign(rematch_info.key, program_id),
+        &[rematch_info.clone(), system_program_info.clone()],
+        &[rematch_seeds],
+    )?;
+
+    // Lock the creator's stake in the rematch
+    invoke(
+        &transfer(creator_info.key, rematch_info.key, escrow.data.amount),
+        &[creator_info.clone(), rematch_info.clone(), system_program_info.clone()],
+    )?;
+
+    // Carry the completed escrow's rent over to the rematch, after the
+    // system program calls so they only see their own transfers
+    let rent_minimum_balance = Rent::get()?.minimum_balance(Escrow::LEN);
+    if escrow_info.lamports() < rent_minimum_balance {
+        invoke(
+            &transfer(fee_payer_info.key, rematch_info.key, rent_minimum_balance - escrow_info.lamports()),
+            &[fee_payer_info.clone(), rematch_info.clone(), system_program_info.clone()],
+        )?;
+    }
+
+    close_account(escrow_info, rematch_info)?;
+
+    let rematch = Escrow {
+        creator: *creator_info.key,
+        amount: escrow.data.amount,
+        status: ESCROW_STATUS_OPEN,
+        description: escrow.data.description.clone(),
+        expiry_time,
+        escrow_bump: rematch_bump,
+        counter: rematch_counter,
+        rent_payer: escrow.data.rent_payer,
+        designated_taker: Some(taker),
+        created_slot: Clock::get()?.slot,
+        ..Escrow::default()
+    };
+
+    rematch.serialize(&mut &mut rematch_info.data.borrow_mut()[..])?;
+
+    msg!("Rematch {} opened for {}", rematch_pubkey, taker);
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::{close_account, load_escrow};
+use crate::src::create_escrow::ESCROW_STATUS_OPEN;
+use crate::src::post_counter_offer::load_counter_offer;
+
+/// Withdraw, reject or clean up a counter-offer, refunding its taker
+///
+/// The taker may withdraw and the creator may reject an offer at any time.
+/// Once the offer is stale, because the escrow is no longer open or either
+/// expiry has passed, anyone may close it. The offered stake and the rent
+/// always return to the taker.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[]` escrow: Escrow the offer was made on
+/// * `[writable]` counter_offer: Counter-offer to close
+/// * `[writable]` taker: Taker who made the counter-offer
+/// * `[signer]` authority: Creator or taker, or anyone once the offer is stale
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `InvalidAuthority` - If the offer is live and the authority is neither
+///   its creator nor its taker
+pub fn close_counter_offer(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let counter_offer_info = next_account_info(account_info_iter)?;
+    let taker_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+
+    let escrow = load_escrow(program_id, escrow_info, &creator, counter)?;
+    let counter_offer = load_counter_offer(program_id, counter_offer_info, escrow_info.key, taker_info.key)?;
+
+    if !fee_payer_info.is_signer || !authority_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let stale = escrow.data.status != ESCROW_STATUS_OPEN
+        || now >= escrow.data.expiry_time
+        || now >= counter_offer.data.expiry_time;
+
+    if !stale && *authority_info.key != escrow.data.creator && *authority_info.key != counter_offer.data.taker {
+        return Err(NescrowError::InvalidAuthority.into());
+    }
+
+    close_account(counter_offer_info, taker_info)
+}
@@ -1,117 +1,220 @@
-use std::str::FromStr;
-use std::ops::DerefMut;
 use borsh::{BorshDeserialize, BorshSerialize};
-
-// need to dynamically include this when there is an invoke
-use solana_program::instruction::{AccountMeta, Instruction};
-
-// need to dynamically include this when there is an invoke
-use solana_program::program::{invoke, invoke_signed};
-
-use solana_program::borsh0_10::try_from_slice_unchecked;
-use solana_program::account_info::{AccountInfo, next_account_info, next_account_infos};
-use solana_program::entrypoint::ProgramResult;
-use solana_program::pubkey::Pubkey;
-use solana_program::rent::Rent;
-use solana_program::system_instruction::create_account;
-use solana_program::{msg, system_program};
-use solana_program::sysvar::Sysvar;
-use solana_program::program_pack::Pack;
-use crate::generated::errors::NescrowError;
-use crate::generated::state::*;
-use solana_program::keccak;
-
-use crate::generated::state::{
-	AccountPDA,
-	Escrow,
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
 };
 
-
-
+use crate::generated::errors::NescrowError;
+use crate::generated::state::{AccountPDA, Escrow};
+use crate::src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED};
+use crate::src::oracle;
+use crate::src::tokenize_position::{is_tokenized, position_mint};
 
 /// Complete the escrow and distribute funds to winner
 ///
-/// Accounts:
-/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
-/// 1. `[writable]` escrow: [Escrow] 
-/// 2. `[signer]` authority: [AccountInfo] Must be either creator or taker
-/// 3. `[writable]` winner: [AccountInfo] The account that will receive the funds
+/// # Accounts
 ///
-/// Data:
-/// - creator: [Pubkey] 
-/// - counter: [u64] 
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to settle
+/// * `[signer]` authority: Must be either creator or taker
+/// * `[writable]` winner: The account that will receive the funds
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `WrongAccountOwner` - If the escrow is not owned by the program
+/// * `InvalidAccountLen` - If account data length is incorrect
+/// * `EscrowNotAccepted` - If the escrow is not in Accepted status
+/// * `InvalidAuthority` - If the authority is not a party to the escrow, or
+///   the escrow is a game, stream, recurring payment, series, taken through
+///   partial fills, settled by an oracle or tokenized, which settle by their
+///   own rules
+/// * `InvalidWinner` - If the winner is not a party to the escrow
 pub fn complete_escrow(
-	program_id: &Pubkey,
-	accounts: &[AccountInfo],
-	creator: Pubkey,
-	counter: u64,
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
 ) -> ProgramResult {
-    // Implement your business logic here...
-let account_info_iter = &mut accounts.iter();
-	let fee_payer_info = next_account_info(account_info_iter)?;
-	let escrow_info = next_account_info(account_info_iter)?;
-	let authority_info = next_account_info(account_info_iter)?;
-	let winner_info = next_account_info(account_info_iter)?;
-
-	// Derive PDAs
-	let (escrow_pubkey, escrow_bump) = Pubkey::find_program_address(
-		&[b"escrow", creator.as_ref(), counter.to_le_bytes().as_ref()],
-		program_id,
-	);
-
-	// Security Checks
-	if fee_payer_info.is_signer != true {
-		return Err(NescrowError::InvalidSignerPermission.into());
-	}
-
-	if *escrow_info.key != escrow_pubkey {
-		return Err(NescrowError::NotExpectedAddress.into());
-	}
-
-	if authority_info.is_signer != true {
-		return Err(NescrowError::InvalidSignerPermission.into());
-	}
-
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let winner_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, &creator, counter)?;
+
+    if !fee_payer_info.is_signer || !authority_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    if !is_party(&escrow.data, authority_info.key)
+        || escrow.data.game.is_some()
+        || escrow.data.stream.is_some()
+        || escrow.data.schedule.is_some()
+        || escrow.data.fills.is_some()
+        || escrow.data.series.is_some()
+        || escrow.data.oracle_kind != oracle::ORACLE_KIND_NONE
+        || is_tokenized(&escrow.data)
+    {
+        return Err(NescrowError::InvalidAuthority.into());
+    }
+
+    pay_out(&mut escrow.data, escrow_info, winner_info)?;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
 
+    Ok(())
+}
 
-	// Security Checks
-	if *escrow_info.owner != *program_id {
-		return Err(NescrowError::WrongAccountOwner.into());
-	}
+/// Load an escrow account after checking its address, owner and size
+///
+/// `creator` must be the escrow's current creator. The address stays
+/// derived from the original creator, see [`escrow_seed_creator`].
+pub fn load_escrow<'a, 'b>(
+    program_id: &Pubkey,
+    escrow_info: &'a AccountInfo<'b>,
+    creator: &Pubkey,
+    counter: u64,
+) -> Result<AccountPDA<'a, 'b, Escrow>, ProgramError> {
+    if escrow_info.owner != program_id {
+        return Err(NescrowError::WrongAccountOwner.into());
+    }
+
+    if escrow_info.data_len() != Escrow::LEN {
+        return Err(NescrowError::InvalidAccountLen.into());
+    }
+
+    let escrow = Escrow::deserialize(&mut &escrow_info.data.borrow()[..])?;
+
+    let (escrow_pubkey, escrow_bump) = Pubkey::find_program_address(
+        &[b"escrow", escrow_seed_creator(&escrow).as_ref(), counter.to_le_bytes().as_ref()],
+        program_id,
+    );
+
+    if *escrow_info.key != escrow_pubkey || escrow.creator != *creator {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    Ok(AccountPDA::new(escrow_info, escrow, escrow_bump))
+}
+
+/// The creator the escrow's address is derived from
+///
+/// This is the creator the escrow was created by, even after the creator's
+/// position was transferred to someone else.
+pub fn escrow_seed_creator(escrow: &Escrow) -> Pubkey {
+    escrow.original_creator.unwrap_or(escrow.creator)
+}
+
+/// Whether `key` is the creator or the taker of the escrow
+pub fn is_party(escrow: &Escrow, key: &Pubkey) -> bool {
+    *key == escrow.creator || Some(*key) == escrow.taker
+}
+
+/// Move the pot held by the escrow to the winner and mark it completed
+///
+/// The pot is everything above the escrow's rent-exempt minimum, so the
+/// account itself stays alive with its settled state. If the winner's side
+/// is tokenized, the pot stays in the escrow for the token's holder.
+pub fn pay_out(
+    escrow: &mut Escrow,
+    escrow_info: &AccountInfo,
+    winner_info: &AccountInfo,
+) -> ProgramResult {
+    if !is_party(escrow, winner_info.key) {
+        return Err(NescrowError::InvalidWinner.into());
+    }
 
-	if *authority_info.owner != *program_id {
-		return Err(NescrowError::WrongAccountOwner.into());
-	}
+    let rent_minimum_balance = Rent::get()?.minimum_balance(escrow_info.data_len());
+    let pot = escrow_info
+        .lamports()
+        .checked_sub(rent_minimum_balance)
+        .ok_or(ProgramError::InsufficientFunds)?;
 
-	if *winner_info.owner != *program_id {
-		return Err(NescrowError::WrongAccountOwner.into());
-	}
+    escrow.status = ESCROW_STATUS_COMPLETED;
+    escrow.winner = Some(*winner_info.key);
 
-	if escrow_info.data_len() != 383usize {
-		return Err(NescrowError::InvalidAccountLen.into());
-	}
+    if position_mint(escrow, winner_info.key).is_some() {
+        escrow.position_tokens.held = pot;
 
+        msg!("Holding {} lamports for the holder of the winning token", pot);
+        return Ok(());
+    }
 
-	// Accounts Deserialization
-	let escrow = &mut AccountPDA::new(
-		&escrow_info,
-		try_from_slice_unchecked::<Escrow>(&escrow_info.data.borrow()).unwrap(),
-		escrow_bump,
-	);
+    **escrow_info.try_borrow_mut_lamports()? -= pot;
+    **winner_info.try_borrow_mut_lamports()? += pot;
 
+    msg!("Paid {} lamports to {}", pot, winner_info.key);
+    Ok(())
+}
 
-	// Compressions
+/// Return each party's stake and mark the escrow completed without a winner
+///
+/// The taker gets back the amount they matched and the creator everything
+/// else above the rent-exempt minimum.
+pub fn refund_stakes(
+    escrow: &mut Escrow,
+    escrow_info: &AccountInfo,
+    creator_info: &AccountInfo,
+    taker_info: &AccountInfo,
+) -> ProgramResult {
+    if *creator_info.key != escrow.creator || Some(*taker_info.key) != escrow.taker {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
 
+    let rent_minimum_balance = Rent::get()?.minimum_balance(escrow_info.data_len());
+    let pot = escrow_info
+        .lamports()
+        .checked_sub(rent_minimum_balance)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    let taker_stake = escrow.amount.min(pot);
 
+    **escrow_info.try_borrow_mut_lamports()? -= pot;
+    **taker_info.try_borrow_mut_lamports()? += taker_stake;
+    **creator_info.try_borrow_mut_lamports()? += pot - taker_stake;
 
-	// CPIs - Modify Default Values
+    escrow.status = ESCROW_STATUS_COMPLETED;
+    escrow.winner = None;
 
+    msg!("Refunded stakes from a pot of {} lamports", pot);
+    Ok(())
+}
 
+/// Whether the escrow was created before the current slot and may be closed
+///
+/// Closing frees the address for a new escrow with the same creator and
+/// counter. Keeping every escrow alive past its creation slot means the
+/// new one always has a later `created_slot`, which is what release
+/// conditions and parlay legs use to tell the two apart.
+pub fn is_closable(escrow: &Escrow) -> Result<bool, ProgramError> {
+    Ok(Clock::get()?.slot > escrow.created_slot)
+}
+
+/// Close a program account, sending all its lamports to `destination_info`
+///
+/// The data is zeroed so the account cannot be read back as live state
+/// within the same transaction; the runtime reclaims it once empty.
+pub fn close_account(account_info: &AccountInfo, destination_info: &AccountInfo) -> ProgramResult {
+    let lamports = account_info.lamports();
 
-	// Compression Calls - Modify Default Values
+    **account_info.try_borrow_mut_lamports()? -= lamports;
+    **destination_info.try_borrow_mut_lamports()? += lamports;
 
-	// Accounts Serialization
-	escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+    account_info.data.borrow_mut().fill(0);
 
+    msg!("Closed {}, returning {} lamports to {}", account_info.key, lamports, destination_info.key);
     Ok(())
-}
\ No newline at end of file
+}
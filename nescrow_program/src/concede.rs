@@ -0,0 +1,75 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::{is_party, load_escrow, pay_out};
+use crate::src::create_escrow::ESCROW_STATUS_ACCEPTED;
+use crate::src::tokenize_position::is_tokenized;
+
+/// Concede an accepted escrow, awarding the pot to the counterparty
+///
+/// Only the conceding party signs: giving up one's own stake needs no
+/// arbiter or counterparty consent.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to settle
+/// * `[signer]` party: Creator or taker conceding
+/// * `[writable]` counterparty: The other party, receiving the pot
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the escrow is not in Accepted status
+/// * `InvalidAuthority` - If the signer is not a party to the escrow, or the
+///   escrow is a stream, recurring payment or tokenized
+/// * `InvalidWinner` - If the counterparty is the conceding party
+pub fn concede(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let party_info = next_account_info(account_info_iter)?;
+    let counterparty_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, &creator, counter)?;
+
+    if !fee_payer_info.is_signer || !party_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    if !is_party(&escrow.data, party_info.key)
+        || escrow.data.stream.is_some()
+        || escrow.data.schedule.is_some()
+        || is_tokenized(&escrow.data)
+    {
+        return Err(NescrowError::InvalidAuthority.into());
+    }
+
+    if counterparty_info.key == party_info.key {
+        return Err(NescrowError::InvalidWinner.into());
+    }
+
+    pay_out(&mut escrow.data, escrow_info, counterparty_info)?;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("{} conceded", party_info.key);
+    Ok(())
+}
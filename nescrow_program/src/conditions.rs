@@ -0,0 +1,159 @@
+use solana_program::{
+    account_info::AccountInfo,
+    hash::hashv,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use borsh::BorshDeserialize;
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::{ConditionNode, Escrow, Outcome, ReleaseConditions};
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::{ESCROW_STATUS_CANCELLED, ESCROW_STATUS_COMPLETED};
+use crate::src::create_event::load_event;
+
+/// Maximum length in bytes of a preimage revealed for a hash condition
+pub const MAX_PREIMAGE_LEN: usize = 64;
+
+/// What a release condition tree is evaluated against
+pub struct ConditionContext<'a, 'b> {
+    pub program_id: &'a Pubkey,
+    pub now: i64,
+    /// Hashes of the preimages revealed with the release
+    pub revealed_hashes: Vec<[u8; 32]>,
+    /// Signers, event and escrow accounts passed with the release
+    pub accounts: &'a [AccountInfo<'b>],
+}
+
+/// Hash a preimage must have to satisfy a hash condition
+pub fn preimage_hash(preimage: &[u8]) -> [u8; 32] {
+    hashv(&[preimage]).to_bytes()
+}
+
+/// Whether `nodes` is a well-formed tree
+///
+/// Every combinator must list at least one child, in increasing order and
+/// before itself, and a threshold must be reachable. An empty tree is valid
+/// and is never met.
+pub fn is_valid(nodes: &[ConditionNode]) -> bool {
+    nodes.len() <= ReleaseConditions::MAX_NODES
+        && nodes.iter().enumerate().all(|(index, node)| match node {
+            ConditionNode::All { children } | ConditionNode::Any { children } => {
+                children_valid(index, children)
+            }
+            ConditionNode::Threshold { count, children } => {
+                children_valid(index, children) && *count >= 1 && *count as usize <= children.len()
+            }
+            _ => true,
+        })
+}
+
+fn children_valid(index: usize, children: &[u8]) -> bool {
+    !children.is_empty()
+        && children.windows(2).all(|pair| pair[0] < pair[1])
+        && children.iter().all(|&child| (child as usize) < index)
+}
+
+/// Evaluate a tree checked with [`is_valid`], returning whether its root is met
+///
+/// Each node is evaluated exactly once, so the cost is bounded by the tree
+/// size limits. Every escrow the tree references must be passed and final,
+/// so a parlay only releases once all of its legs are known. A void leg
+/// meets neither outcome, see [`has_void_leg`].
+pub fn evaluate(nodes: &[ConditionNode], context: &ConditionContext) -> Result<bool, ProgramError> {
+    let mut results: Vec<bool> = Vec::with_capacity(nodes.len());
+
+    for node in nodes {
+        let met = match node {
+            ConditionNode::TimePassed { after } => context.now >= *after,
+            ConditionNode::HashRevealed { hash } => context.revealed_hashes.contains(hash),
+            ConditionNode::EventResult { event, outcome } => {
+                event_result(context, event)? == Some(*outcome)
+            }
+            ConditionNode::SignedBy { signer } => context
+                .accounts
+                .iter()
+                .any(|account| account.key == signer && account.is_signer),
+            ConditionNode::All { children } => children.iter().all(|&child| results[child as usize]),
+            ConditionNode::Any { children } => children.iter().any(|&child| results[child as usize]),
+            ConditionNode::Threshold { count, children } => {
+                children.iter().filter(|&&child| results[child as usize]).count() >= *count as usize
+            }
+            ConditionNode::EscrowOutcome { escrow, created_slot, outcome } => {
+                matches!(leg(context, escrow, *created_slot)?, Leg::Settled(leg_outcome) if leg_outcome == *outcome)
+            }
+        };
+        results.push(met);
+    }
+
+    Ok(results.last().copied().unwrap_or(false))
+}
+
+/// Result posted to `event`, if its account was passed and it has reported
+fn event_result(context: &ConditionContext, event: &Pubkey) -> Result<Option<u8>, ProgramError> {
+    match context.accounts.iter().find(|account| account.key == event) {
+        Some(event_info) => Ok(load_event(context.program_id, event_info)?.data.result),
+        None => Ok(None),
+    }
+}
+
+/// Whether an escrow outcome condition in any of `trees` refers to a void leg
+///
+/// Every leg must be passed and final, so this only answers once all legs
+/// of the parlay are known.
+pub fn has_void_leg(trees: &[&[ConditionNode]], context: &ConditionContext) -> Result<bool, ProgramError> {
+    let mut void = false;
+
+    for node in trees.iter().flat_map(|tree| tree.iter()) {
+        if let ConditionNode::EscrowOutcome { escrow, created_slot, .. } = node {
+            void |= matches!(leg(context, escrow, *created_slot)?, Leg::Void);
+        }
+    }
+
+    Ok(void)
+}
+
+/// Final state of an escrow referenced by a parlay
+enum Leg {
+    Settled(Outcome),
+    Void,
+}
+
+/// Final state of the escrow `escrow` created at `created_slot`, whose
+/// account must have been passed
+///
+/// A leg is void if it completed without a winner, such as a pushed line,
+/// was cancelled, or was closed. The address of a closed leg can be taken
+/// by a new escrow, which is told apart by its later creation slot.
+fn leg(context: &ConditionContext, escrow: &Pubkey, created_slot: u64) -> Result<Leg, ProgramError> {
+    let escrow_info = context
+        .accounts
+        .iter()
+        .find(|account| account.key == escrow)
+        .ok_or(NescrowError::LegNotSettled)?;
+
+    if escrow_info.owner != context.program_id {
+        return Ok(Leg::Void);
+    }
+
+    // Read the creator and counter the address is derived from, then load
+    // the escrow through the usual address checks
+    let leg = Escrow::deserialize(&mut &escrow_info.data.borrow()[..])?;
+    let leg = load_escrow(context.program_id, escrow_info, &leg.creator, leg.counter)?;
+
+    if leg.data.created_slot != created_slot {
+        return Ok(Leg::Void);
+    }
+
+    match (leg.data.status, leg.data.winner) {
+        (ESCROW_STATUS_COMPLETED, Some(winner)) if winner == leg.data.creator => {
+            Ok(Leg::Settled(Outcome::CreatorWins))
+        }
+        (ESCROW_STATUS_COMPLETED, Some(_)) => Ok(Leg::Settled(Outcome::TakerWins)),
+        // Fills have no single taker, so a win by the takers completes without a winner
+        (ESCROW_STATUS_COMPLETED, None) if leg.data.fills.is_some() => Ok(Leg::Settled(Outcome::TakerWins)),
+        (ESCROW_STATUS_COMPLETED, None) | (ESCROW_STATUS_CANCELLED, _) => Ok(Leg::Void),
+        _ => Err(NescrowError::LegNotSettled.into()),
+    }
+}
@@ -0,0 +1,68 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::create_purchase::{
+    load_purchase, PURCHASE_STATUS_DISPUTED, PURCHASE_STATUS_PAID, PURCHASE_STATUS_RELEASED,
+    PURCHASE_STATUS_SHIPPED,
+};
+
+/// Confirm receipt of a purchase and pay the seller
+///
+/// The buyer may confirm at any point before the payment is released or
+/// refunded, including after opening a dispute, which settles it in the
+/// seller's favour.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` purchase: Purchase holding the payment
+/// * `[signer]` buyer: Buyer of the goods
+/// * `[writable]` seller: Seller of the goods
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `InvalidPurchaseStatus` - If the payment was already released or refunded
+pub fn confirm_purchase(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let purchase_info = next_account_info(account_info_iter)?;
+    let buyer_info = next_account_info(account_info_iter)?;
+    let seller_info = next_account_info(account_info_iter)?;
+
+    let purchase = &mut load_purchase(program_id, purchase_info, buyer_info.key, counter)?;
+
+    if !fee_payer_info.is_signer || !buyer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if *seller_info.key != purchase.data.seller {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if ![PURCHASE_STATUS_PAID, PURCHASE_STATUS_SHIPPED, PURCHASE_STATUS_DISPUTED].contains(&purchase.data.status) {
+        return Err(NescrowError::InvalidPurchaseStatus.into());
+    }
+
+    **purchase_info.try_borrow_mut_lamports()? -= purchase.data.amount;
+    **seller_info.try_borrow_mut_lamports()? += purchase.data.amount;
+
+    purchase.data.status = PURCHASE_STATUS_RELEASED;
+
+    purchase.data.serialize(&mut &mut purchase_info.data.borrow_mut()[..])?;
+
+    msg!("Purchase confirmed, {} lamports paid to {}", purchase.data.amount, seller_info.key);
+    Ok(())
+}
@@ -3,17 +3,21 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::{AccountInfo, next_account_info},
     borsh0_10::try_from_slice_unchecked,
+    clock::Clock,
     entrypoint::ProgramResult,
     msg,
-    program::{invoke_signed},
+    program::{invoke, invoke_signed},
     pubkey::Pubkey,
     rent::Rent,
-    system_instruction::create_account,
+    system_instruction::{create_account, transfer},
     sysvar::Sysvar,
 };
 
 use crate::generated::errors::NescrowError;
-use crate::generated::state::{AccountPDA, Escrow};
+use crate::generated::instructions::GameTerms;
+use crate::generated::state::{AccountPDA, Escrow, EventCondition, Fills, Game, Line, PriceCondition, Series};
+use crate::src::create_event::load_event;
+use crate::src::{game, oracle, series};
 
 /// Escrow status constants
 pub const ESCROW_STATUS_OPEN: u8 = 0;
@@ -31,6 +35,18 @@ pub const ESCROW_STATUS_CANCELLED: u8 = 3;
 /// * `amount` - The amount of lamports to wager
 /// * `description` - Description of the escrow
 /// * `expiry_time` - The time when the escrow expires (Unix timestamp)
+/// * `oracle_kind` - Kind of oracle that can settle the escrow, if any
+/// * `oracle_account` - Attestation signer or price feed of the oracle
+/// * `price_condition` - Threshold for price feed oracles
+/// * `event_condition` - Outcomes backed by each side for event oracles
+/// * `game_terms` - Commit-reveal game settling the escrow, with the creator's commitment
+/// * `max_extension` - Seconds the creator may extend the expiry alone once accepted
+/// * `min_fill` - Fill at which the creator may accept, if the escrow is
+///   taken through partial fills by any number of takers
+/// * `line` - Spread or total settling the escrow from the score of the
+///   event named by an event score oracle
+/// * `series_length` - Number of games in a best-of-N series settling the
+///   escrow, each confirmed by both parties or attested by the oracle
 ///
 /// # Accounts
 ///
@@ -38,6 +54,7 @@ pub const ESCROW_STATUS_CANCELLED: u8 = 3;
 /// * `[writable]` escrow: Escrow account to be created
 /// * `[writable, signer]` creator: Creator of the escrow
 /// * `[]` system_program: System program for account creation
+/// * `[]` event: Event named by an event or event score oracle, if any
 ///
 /// # Errors
 ///
@@ -45,6 +62,17 @@ pub const ESCROW_STATUS_CANCELLED: u8 = 3;
 /// * `NotExpectedAddress` - If account addresses don't match expected values
 /// * `WrongAccountOwner` - If account owners don't match expected values
 /// * `InvalidAccountLen` - If account data length is incorrect
+/// * `NoOracle` - If the oracle kind is unknown or does not fit the conditions
+/// * `NotAGame` - If the game kind is unknown or its reveal window is not positive
+/// * `InvalidExpiry` - If the maximum extension is negative, or the escrow
+///   would stay open to takers after its event starts
+/// * `InvalidEventOutcome` - If a side backs an outcome the event does not have
+/// * `InvalidFill` - If the minimum fill is zero or above the amount, or the
+///   escrow has no oracle or a line, which cannot settle its fills
+/// * `InvalidSeries` - If the series length is even, zero or too long, or the
+///   escrow is a game, taken through fills or has an oracle other than an
+///   attestation
+#[allow(clippy::too_many_arguments)]
 pub fn create_escrow(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -52,6 +80,15 @@ pub fn create_escrow(
     amount: u64,
     description: String,
     expiry_time: i64,
+    oracle_kind: u8,
+    oracle_account: Pubkey,
+    price_condition: Option<PriceCondition>,
+    event_condition: Option<EventCondition>,
+    game_terms: Option<GameTerms>,
+    max_extension: i64,
+    min_fill: Option<u64>,
+    line: Option<Line>,
+    series_length: Option<u8>,
 ) -> ProgramResult {
     msg!("Instruction: CreateEscrow");
     
@@ -83,7 +120,7 @@ pub fn create_escrow(
     )?;
 
     // Initialize escrow account
-    let space: usize = 383; // Size of the Escrow struct
+    let space: usize = Escrow::LEN;
     let rent = Rent::get()?;
     let rent_minimum_balance = rent.minimum_balance(space);
 
@@ -111,6 +148,12 @@ pub fn create_escrow(
         return Err(NescrowError::InvalidAccountLen.into());
     }
 
+    // Lock the creator's stake in the escrow
+    invoke(
+        &transfer(creator_info.key, escrow_info.key, amount),
+        &[creator_info.clone(), escrow_info.clone(), system_program_info.clone()],
+    )?;
+
     // Initialize escrow data
     let escrow = &mut AccountPDA::new(
         &escrow_info,
@@ -128,6 +171,90 @@ pub fn create_escrow(
     escrow.data.expiry_time = expiry_time;
     escrow.data.escrow_bump = escrow_bump;
     escrow.data.counter = counter;
+    escrow.data.oracle_kind = oracle_kind;
+    escrow.data.oracle_account = oracle_account;
+    escrow.data.price_condition = price_condition;
+    escrow.data.event_condition = event_condition;
+    escrow.data.line = line;
+    escrow.data.max_extension = max_extension;
+    escrow.data.rent_payer = *fee_payer_info.key;
+    escrow.data.created_slot = Clock::get()?.slot;
+    escrow.data.game = game_terms.map(|terms| Game {
+        game_kind: terms.game_kind,
+        reveal_window: terms.reveal_window,
+        creator_commitment: terms.commitment,
+        ..Game::default()
+    });
+    escrow.data.fills = min_fill.map(|min_fill| Fills {
+        min_fill,
+        ..Fills::default()
+    });
+    escrow.data.series = series_length.map(|length| Series {
+        length,
+        results: vec![],
+    });
+
+    if max_extension < 0 {
+        return Err(NescrowError::InvalidExpiry.into());
+    }
+
+    if !oracle::is_supported(&escrow.data) {
+        return Err(NescrowError::NoOracle.into());
+    }
+
+    if matches!(escrow.data.oracle_kind, oracle::ORACLE_KIND_EVENT | oracle::ORACLE_KIND_EVENT_SCORE) {
+        // Takers must commit before the result can be known
+        let event_info = next_account_info(account_info_iter)?;
+        if *event_info.key != escrow.data.oracle_account {
+            return Err(NescrowError::NotExpectedAddress.into());
+        }
+
+        let event = load_event(program_id, event_info)?;
+        if expiry_time > event.data.start_time {
+            return Err(NescrowError::InvalidExpiry.into());
+        }
+
+        if let Some(condition) = &escrow.data.event_condition {
+            let outcomes = event.data.outcomes.len();
+            if condition.creator_outcome as usize >= outcomes || condition.taker_outcome as usize >= outcomes {
+                return Err(NescrowError::InvalidEventOutcome.into());
+            }
+        }
+    }
+
+    if let Some(game) = &escrow.data.game {
+        // Games settle themselves, so they cannot also name an oracle
+        if escrow.data.oracle_kind != oracle::ORACLE_KIND_NONE {
+            return Err(NescrowError::NoOracle.into());
+        }
+
+        if !game::is_supported(game.game_kind) || game.reveal_window <= 0 {
+            return Err(NescrowError::NotAGame.into());
+        }
+    }
+
+    if let Some(fills) = &escrow.data.fills {
+        // Fills are settled by the oracle alone, since no single taker can co-sign
+        if fills.min_fill == 0
+            || fills.min_fill > amount
+            || escrow.data.oracle_kind == oracle::ORACLE_KIND_NONE
+            || escrow.data.game.is_some()
+            || escrow.data.line.is_some()
+        {
+            return Err(NescrowError::InvalidFill.into());
+        }
+    }
+
+    if let Some(series) = &escrow.data.series {
+        // Games are recorded by both parties or an attestation, one at a time
+        if !series::is_valid_length(series.length)
+            || !matches!(escrow.data.oracle_kind, oracle::ORACLE_KIND_NONE | oracle::ORACLE_KIND_ATTESTATION)
+            || escrow.data.game.is_some()
+            || escrow.data.fills.is_some()
+        {
+            return Err(NescrowError::InvalidSeries.into());
+        }
+    }
 
     // Serialize escrow data back to the account
     escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
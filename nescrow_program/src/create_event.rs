@@ -0,0 +1,137 @@
+use std::str::FromStr;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::create_account,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::{AccountPDA, Event};
+
+/// Register an event whose result the reporter will post
+///
+/// Escrows reference the event through an event oracle and are settled
+/// from the result the reporter posts once the event has started.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` event: Event account to be created
+/// * `[signer]` reporter: Key allowed to post the result
+/// * `[]` system_program: System program for account creation
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `InvalidEventOutcomes` - If there are fewer than two, too many or too long outcomes
+pub fn create_event(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    event_id: u64,
+    start_time: i64,
+    outcomes: Vec<String>,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let event_info = next_account_info(account_info_iter)?;
+    let reporter_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    // Derive PDA for event account
+    let (event_pubkey, event_bump) = Pubkey::find_program_address(
+        &[b"event", reporter_info.key.as_ref(), event_id.to_le_bytes().as_ref()],
+        program_id,
+    );
+
+    // Validate accounts
+    if !fee_payer_info.is_signer || !reporter_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    if *event_info.key != event_pubkey || *system_program_info.key != system_program_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if outcomes.len() < 2
+        || outcomes.len() > Event::MAX_OUTCOMES
+        || outcomes.iter().any(|outcome| outcome.len() > Event::MAX_OUTCOME_LEN)
+    {
+        return Err(NescrowError::InvalidEventOutcomes.into());
+    }
+
+    // Create the event account
+    invoke_signed(
+        &create_account(
+            fee_payer_info.key,
+            event_info.key,
+            Rent::get()?.minimum_balance(Event::LEN),
+            Event::LEN as u64,
+            program_id,
+        ),
+        &[fee_payer_info.clone(), event_info.clone()],
+        &[&[
+            b"event",
+            reporter_info.key.as_ref(),
+            event_id.to_le_bytes().as_ref(),
+            &[event_bump],
+        ]],
+    )?;
+
+    let event = Event {
+        reporter: *reporter_info.key,
+        event_id,
+        start_time,
+        outcomes,
+        result: None,
+        score: None,
+        event_bump,
+    };
+
+    event.serialize(&mut &mut event_info.data.borrow_mut()[..])?;
+
+    msg!("Event created: {}", event_pubkey);
+    Ok(())
+}
+
+/// Load an event account after checking its address, owner and size
+pub fn load_event<'a, 'b>(
+    program_id: &Pubkey,
+    event_info: &'a AccountInfo<'b>,
+) -> Result<AccountPDA<'a, 'b, Event>, ProgramError> {
+    if event_info.owner != program_id {
+        return Err(NescrowError::WrongAccountOwner.into());
+    }
+
+    if event_info.data_len() != Event::LEN {
+        return Err(NescrowError::InvalidAccountLen.into());
+    }
+
+    let event = Event::deserialize(&mut &event_info.data.borrow()[..])?;
+
+    let event_pubkey = Pubkey::create_program_address(
+        &[
+            b"event",
+            event.reporter.as_ref(),
+            event.event_id.to_le_bytes().as_ref(),
+            &[event.event_bump],
+        ],
+        program_id,
+    )?;
+
+    if *event_info.key != event_pubkey {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    let event_bump = event.event_bump;
+    Ok(AccountPDA::new(event_info, event, event_bump))
+}
@@ -0,0 +1,191 @@
+use std::str::FromStr;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::create_account,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::{AccountPDA, Market, Position};
+use crate::src::create_event::load_event;
+
+/// Market status constants
+pub const MARKET_STATUS_OPEN: u8 = 0;
+pub const MARKET_STATUS_RESOLVED: u8 = 1;
+pub const MARKET_STATUS_REFUNDED: u8 = 2;
+
+/// Highest fee a market can charge, in basis points
+pub const MAX_FEE_BPS: u16 = 1_000;
+
+/// Create a parimutuel market on the outcomes of an event
+///
+/// Anyone can stake on any of the event's outcomes until the cutoff, which
+/// cannot be later than the event's start. Once the event is reported the
+/// winning side shares the whole pool, minus the creator's fee, in
+/// proportion to their stakes.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` market: Market account to be created
+/// * `[signer]` creator: Creator receiving the market fee
+/// * `[]` event: Event whose outcomes are staked on
+/// * `[]` system_program: System program for account creation
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EventAlreadyReported` - If the event already has a result
+/// * `InvalidMarket` - If the fee is above `MAX_FEE_BPS` or the cutoff is
+///   after the event starts
+pub fn create_market(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+    cutoff: i64,
+    fee_bps: u16,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let market_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let event_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let event = load_event(program_id, event_info)?;
+
+    // Derive PDA for the market account
+    let (market_pubkey, market_bump) = Pubkey::find_program_address(
+        &[b"market", creator_info.key.as_ref(), counter.to_le_bytes().as_ref()],
+        program_id,
+    );
+
+    // Validate accounts
+    if !fee_payer_info.is_signer || !creator_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    if *market_info.key != market_pubkey || *system_program_info.key != system_program_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if event.data.result.is_some() {
+        return Err(NescrowError::EventAlreadyReported.into());
+    }
+
+    if fee_bps > MAX_FEE_BPS || cutoff > event.data.start_time {
+        return Err(NescrowError::InvalidMarket.into());
+    }
+
+    // Create the market account
+    invoke_signed(
+        &create_account(
+            fee_payer_info.key,
+            market_info.key,
+            Rent::get()?.minimum_balance(Market::LEN),
+            Market::LEN as u64,
+            program_id,
+        ),
+        &[fee_payer_info.clone(), market_info.clone()],
+        &[&[
+            b"market",
+            creator_info.key.as_ref(),
+            counter.to_le_bytes().as_ref(),
+            &[market_bump],
+        ]],
+    )?;
+
+    let market = Market {
+        creator: *creator_info.key,
+        counter,
+        event: *event_info.key,
+        cutoff,
+        fee_bps,
+        outcome_totals: vec![0; event.data.outcomes.len()],
+        status: MARKET_STATUS_OPEN,
+        winning_outcome: None,
+        payout_pool: 0,
+        unclaimed_winning_stake: 0,
+        market_bump,
+    };
+
+    market.serialize(&mut &mut market_info.data.borrow_mut()[..])?;
+
+    msg!("Market created: {}", market_pubkey);
+    Ok(())
+}
+
+/// Load a market account after checking its address, owner and size
+pub fn load_market<'a, 'b>(
+    program_id: &Pubkey,
+    market_info: &'a AccountInfo<'b>,
+    creator: &Pubkey,
+    counter: u64,
+) -> Result<AccountPDA<'a, 'b, Market>, ProgramError> {
+    let (market_pubkey, market_bump) = Pubkey::find_program_address(
+        &[b"market", creator.as_ref(), counter.to_le_bytes().as_ref()],
+        program_id,
+    );
+
+    if *market_info.key != market_pubkey {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if market_info.owner != program_id {
+        return Err(NescrowError::WrongAccountOwner.into());
+    }
+
+    if market_info.data_len() != Market::LEN {
+        return Err(NescrowError::InvalidAccountLen.into());
+    }
+
+    Ok(AccountPDA::new(
+        market_info,
+        Market::deserialize(&mut &market_info.data.borrow()[..])?,
+        market_bump,
+    ))
+}
+
+/// Load a position of `owner` on `outcome` of `market` after checking its
+/// address, owner, size and that it has not been closed
+pub fn load_position<'a, 'b>(
+    program_id: &Pubkey,
+    position_info: &'a AccountInfo<'b>,
+    market: &Pubkey,
+    owner: &Pubkey,
+    outcome: u8,
+) -> Result<AccountPDA<'a, 'b, Position>, ProgramError> {
+    let (position_pubkey, position_bump) = Pubkey::find_program_address(
+        &[b"position", market.as_ref(), owner.as_ref(), &[outcome]],
+        program_id,
+    );
+
+    if *position_info.key != position_pubkey {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if position_info.owner != program_id {
+        return Err(NescrowError::WrongAccountOwner.into());
+    }
+
+    if position_info.data_len() != Position::LEN {
+        return Err(NescrowError::InvalidAccountLen.into());
+    }
+
+    let position = Position::deserialize(&mut &position_info.data.borrow()[..])?;
+    if position.market != *market || position.owner != *owner {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    Ok(AccountPDA::new(position_info, position, position_bump))
+}
@@ -0,0 +1,169 @@
+use std::str::FromStr;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::{create_account, transfer},
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::instructions::MilestoneTerms;
+use crate::generated::state::{AccountPDA, Milestone, MilestoneEscrow};
+
+/// Milestone status constants
+pub const MILESTONE_STATUS_PENDING: u8 = 0;
+pub const MILESTONE_STATUS_RELEASED: u8 = 1;
+pub const MILESTONE_STATUS_DISPUTED: u8 = 2;
+pub const MILESTONE_STATUS_REFUNDED: u8 = 3;
+
+/// Create a freelance escrow for an ordered list of milestones
+///
+/// The client funds every milestone up front. Deadlines must not decrease
+/// from one milestone to the next. The client can reclaim a milestone still
+/// pending after its deadline, and the last one is the final deadline after
+/// which the client can reclaim anything never released.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Milestone escrow account to be created
+/// * `[writable, signer]` client: Client funding the work
+/// * `[]` worker: Worker paid as milestones are approved
+/// * `[]` system_program: System program for account creation
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `InvalidAuthority` - If the client names themselves as the worker
+/// * `InvalidMilestones` - If there are no or too many milestones, an empty
+///   milestone, or deadlines out of order
+pub fn create_milestone_escrow(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+    milestones: Vec<MilestoneTerms>,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let client_info = next_account_info(account_info_iter)?;
+    let worker_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    // Derive PDA for the milestone escrow account
+    let (escrow_pubkey, escrow_bump) = Pubkey::find_program_address(
+        &[b"milestones", client_info.key.as_ref(), counter.to_le_bytes().as_ref()],
+        program_id,
+    );
+
+    // Validate accounts
+    if !fee_payer_info.is_signer || !client_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    if *escrow_info.key != escrow_pubkey || *system_program_info.key != system_program_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if worker_info.key == client_info.key {
+        return Err(NescrowError::InvalidAuthority.into());
+    }
+
+    if milestones.is_empty()
+        || milestones.len() > MilestoneEscrow::MAX_MILESTONES
+        || milestones.iter().any(|milestone| milestone.amount == 0)
+        || milestones.windows(2).any(|pair| pair[0].deadline > pair[1].deadline)
+    {
+        return Err(NescrowError::InvalidMilestones.into());
+    }
+
+    let total = milestones
+        .iter()
+        .try_fold(0u64, |total, milestone| total.checked_add(milestone.amount))
+        .ok_or(NescrowError::InvalidMilestones)?;
+
+    // Create the milestone escrow account
+    invoke_signed(
+        &create_account(
+            fee_payer_info.key,
+            escrow_info.key,
+            Rent::get()?.minimum_balance(MilestoneEscrow::LEN),
+            MilestoneEscrow::LEN as u64,
+            program_id,
+        ),
+        &[fee_payer_info.clone(), escrow_info.clone()],
+        &[&[
+            b"milestones",
+            client_info.key.as_ref(),
+            counter.to_le_bytes().as_ref(),
+            &[escrow_bump],
+        ]],
+    )?;
+
+    // Fund every milestone up front
+    invoke(
+        &transfer(client_info.key, escrow_info.key, total),
+        &[client_info.clone(), escrow_info.clone(), system_program_info.clone()],
+    )?;
+
+    let escrow = MilestoneEscrow {
+        client: *client_info.key,
+        worker: *worker_info.key,
+        counter,
+        milestones: milestones
+            .into_iter()
+            .map(|terms| Milestone {
+                amount: terms.amount,
+                description_hash: terms.description_hash,
+                deadline: terms.deadline,
+                status: MILESTONE_STATUS_PENDING,
+            })
+            .collect(),
+        escrow_bump,
+    };
+
+    escrow.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Milestone escrow created: {}", escrow_pubkey);
+    Ok(())
+}
+
+/// Load a milestone escrow account after checking its address, owner and size
+pub fn load_milestone_escrow<'a, 'b>(
+    program_id: &Pubkey,
+    escrow_info: &'a AccountInfo<'b>,
+    client: &Pubkey,
+    counter: u64,
+) -> Result<AccountPDA<'a, 'b, MilestoneEscrow>, ProgramError> {
+    let (escrow_pubkey, escrow_bump) = Pubkey::find_program_address(
+        &[b"milestones", client.as_ref(), counter.to_le_bytes().as_ref()],
+        program_id,
+    );
+
+    if *escrow_info.key != escrow_pubkey {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if escrow_info.owner != program_id {
+        return Err(NescrowError::WrongAccountOwner.into());
+    }
+
+    if escrow_info.data_len() != MilestoneEscrow::LEN {
+        return Err(NescrowError::InvalidAccountLen.into());
+    }
+
+    Ok(AccountPDA::new(
+        escrow_info,
+        MilestoneEscrow::deserialize(&mut &escrow_info.data.borrow()[..])?,
+        escrow_bump,
+    ))
+}
@@ -0,0 +1,180 @@
+use std::str::FromStr;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::create_account,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::{AccountPDA, Participant, Pool};
+
+/// Pool status constants
+pub const POOL_STATUS_OPEN: u8 = 0;
+pub const POOL_STATUS_SETTLED: u8 = 1;
+pub const POOL_STATUS_REFUNDED: u8 = 2;
+
+/// Create a pool that participants join with a fixed deposit
+///
+/// Participants can join until the join deadline or until the cap is
+/// reached. The organizer settles the pool by naming one or more winners,
+/// or by refunding everyone, until the settlement deadline. A pool left
+/// unsettled past that deadline can be refunded by anyone, so deposits are
+/// never locked by an absent organizer.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` pool: Pool account to be created
+/// * `[signer]` organizer: Organizer settling the pool
+/// * `[]` system_program: System program for account creation
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `InvalidPool` - If the deposit or the participant cap is zero, or the
+///   settlement deadline is not after the join deadline
+pub fn create_pool(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+    deposit: u64,
+    max_participants: u32,
+    join_deadline: i64,
+    settle_deadline: i64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let pool_info = next_account_info(account_info_iter)?;
+    let organizer_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    // Derive PDA for the pool account
+    let (pool_pubkey, pool_bump) = Pubkey::find_program_address(
+        &[b"pool", organizer_info.key.as_ref(), counter.to_le_bytes().as_ref()],
+        program_id,
+    );
+
+    // Validate accounts
+    if !fee_payer_info.is_signer || !organizer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    if *pool_info.key != pool_pubkey || *system_program_info.key != system_program_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if deposit == 0 || max_participants == 0 || settle_deadline <= join_deadline {
+        return Err(NescrowError::InvalidPool.into());
+    }
+
+    // Create the pool account
+    invoke_signed(
+        &create_account(
+            fee_payer_info.key,
+            pool_info.key,
+            Rent::get()?.minimum_balance(Pool::LEN),
+            Pool::LEN as u64,
+            program_id,
+        ),
+        &[fee_payer_info.clone(), pool_info.clone()],
+        &[&[
+            b"pool",
+            organizer_info.key.as_ref(),
+            counter.to_le_bytes().as_ref(),
+            &[pool_bump],
+        ]],
+    )?;
+
+    let pool = Pool {
+        organizer: *organizer_info.key,
+        counter,
+        deposit,
+        max_participants,
+        participants: 0,
+        join_deadline,
+        settle_deadline,
+        status: POOL_STATUS_OPEN,
+        winners: vec![],
+        unclaimed_winners: 0,
+        pool_bump,
+    };
+
+    pool.serialize(&mut &mut pool_info.data.borrow_mut()[..])?;
+
+    msg!("Pool created: {}", pool_pubkey);
+    Ok(())
+}
+
+/// Load a pool account after checking its address, owner and size
+pub fn load_pool<'a, 'b>(
+    program_id: &Pubkey,
+    pool_info: &'a AccountInfo<'b>,
+    organizer: &Pubkey,
+    counter: u64,
+) -> Result<AccountPDA<'a, 'b, Pool>, ProgramError> {
+    let (pool_pubkey, pool_bump) = Pubkey::find_program_address(
+        &[b"pool", organizer.as_ref(), counter.to_le_bytes().as_ref()],
+        program_id,
+    );
+
+    if *pool_info.key != pool_pubkey {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if pool_info.owner != program_id {
+        return Err(NescrowError::WrongAccountOwner.into());
+    }
+
+    if pool_info.data_len() != Pool::LEN {
+        return Err(NescrowError::InvalidAccountLen.into());
+    }
+
+    Ok(AccountPDA::new(
+        pool_info,
+        Pool::deserialize(&mut &pool_info.data.borrow()[..])?,
+        pool_bump,
+    ))
+}
+
+/// Load a participant record of `pool` after checking its address, owner,
+/// size and that it has not been closed
+pub fn load_participant<'a, 'b>(
+    program_id: &Pubkey,
+    record_info: &'a AccountInfo<'b>,
+    pool: &Pubkey,
+    participant: &Pubkey,
+) -> Result<AccountPDA<'a, 'b, Participant>, ProgramError> {
+    let (record_pubkey, record_bump) = Pubkey::find_program_address(
+        &[b"participant", pool.as_ref(), participant.as_ref()],
+        program_id,
+    );
+
+    if *record_info.key != record_pubkey {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if record_info.owner != program_id {
+        return Err(NescrowError::WrongAccountOwner.into());
+    }
+
+    if record_info.data_len() != Participant::LEN {
+        return Err(NescrowError::InvalidAccountLen.into());
+    }
+
+    let record = Participant::deserialize(&mut &record_info.data.borrow()[..])?;
+    if record.pool != *pool || record.participant != *participant {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    Ok(AccountPDA::new(record_info, record, record_bump))
+}
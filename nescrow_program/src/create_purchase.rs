@@ -0,0 +1,158 @@
+use std::str::FromStr;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::{create_account, transfer},
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::{AccountPDA, Purchase};
+
+/// Purchase status constants
+pub const PURCHASE_STATUS_PAID: u8 = 0;
+pub const PURCHASE_STATUS_SHIPPED: u8 = 1;
+pub const PURCHASE_STATUS_DISPUTED: u8 = 2;
+pub const PURCHASE_STATUS_RELEASED: u8 = 3;
+pub const PURCHASE_STATUS_REFUNDED: u8 = 4;
+
+/// Longest inspection window a buyer may ask for, 90 days in seconds
+pub const MAX_INSPECTION_WINDOW: i64 = 90 * 24 * 60 * 60;
+
+/// Pay for a marketplace purchase
+///
+/// The payment is held until the buyer confirms receipt, or until the
+/// inspection window that starts when the seller ships passes without a
+/// dispute.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` purchase: Purchase account to be created
+/// * `[writable, signer]` buyer: Buyer paying for the goods
+/// * `[]` seller: Seller paid once the buyer is satisfied
+/// * `[]` system_program: System program for account creation
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `InvalidAuthority` - If the buyer names themselves as the seller
+/// * `InvalidPurchase` - If the amount is zero or the inspection window is not
+///   positive or longer than `MAX_INSPECTION_WINDOW`
+pub fn create_purchase(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+    amount: u64,
+    inspection_window: i64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let purchase_info = next_account_info(account_info_iter)?;
+    let buyer_info = next_account_info(account_info_iter)?;
+    let seller_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    // Derive PDA for the purchase account
+    let (purchase_pubkey, purchase_bump) = Pubkey::find_program_address(
+        &[b"purchase", buyer_info.key.as_ref(), counter.to_le_bytes().as_ref()],
+        program_id,
+    );
+
+    // Validate accounts
+    if !fee_payer_info.is_signer || !buyer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    if *purchase_info.key != purchase_pubkey || *system_program_info.key != system_program_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if seller_info.key == buyer_info.key {
+        return Err(NescrowError::InvalidAuthority.into());
+    }
+
+    if amount == 0 || inspection_window <= 0 || inspection_window > MAX_INSPECTION_WINDOW {
+        return Err(NescrowError::InvalidPurchase.into());
+    }
+
+    // Create the purchase account
+    invoke_signed(
+        &create_account(
+            fee_payer_info.key,
+            purchase_info.key,
+            Rent::get()?.minimum_balance(Purchase::LEN),
+            Purchase::LEN as u64,
+            program_id,
+        ),
+        &[fee_payer_info.clone(), purchase_info.clone()],
+        &[&[
+            b"purchase",
+            buyer_info.key.as_ref(),
+            counter.to_le_bytes().as_ref(),
+            &[purchase_bump],
+        ]],
+    )?;
+
+    // Hold the payment
+    invoke(
+        &transfer(buyer_info.key, purchase_info.key, amount),
+        &[buyer_info.clone(), purchase_info.clone(), system_program_info.clone()],
+    )?;
+
+    let purchase = Purchase {
+        buyer: *buyer_info.key,
+        seller: *seller_info.key,
+        counter,
+        amount,
+        inspection_window,
+        inspection_deadline: 0,
+        status: PURCHASE_STATUS_PAID,
+        purchase_bump,
+    };
+
+    purchase.serialize(&mut &mut purchase_info.data.borrow_mut()[..])?;
+
+    msg!("Purchase created: {}", purchase_pubkey);
+    Ok(())
+}
+
+/// Load a purchase account after checking its address, owner and size
+pub fn load_purchase<'a, 'b>(
+    program_id: &Pubkey,
+    purchase_info: &'a AccountInfo<'b>,
+    buyer: &Pubkey,
+    counter: u64,
+) -> Result<AccountPDA<'a, 'b, Purchase>, ProgramError> {
+    let (purchase_pubkey, purchase_bump) = Pubkey::find_program_address(
+        &[b"purchase", buyer.as_ref(), counter.to_le_bytes().as_ref()],
+        program_id,
+    );
+
+    if *purchase_info.key != purchase_pubkey {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if purchase_info.owner != program_id {
+        return Err(NescrowError::WrongAccountOwner.into());
+    }
+
+    if purchase_info.data_len() != Purchase::LEN {
+        return Err(NescrowError::InvalidAccountLen.into());
+    }
+
+    Ok(AccountPDA::new(
+        purchase_info,
+        Purchase::deserialize(&mut &purchase_info.data.borrow()[..])?,
+        purchase_bump,
+    ))
+}
@@ -0,0 +1,151 @@
+use std::str::FromStr;
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::{create_account, transfer},
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::{Escrow, Schedule};
+use crate::src::create_escrow::ESCROW_STATUS_ACCEPTED;
+
+/// Create an escrow funded for `periods` periods of one tranche each
+///
+/// Like a stream, the payment lives in the escrow PDA with the payee as its
+/// taker and starts out accepted. Tranche `k` becomes releasable once the
+/// boundary `start_time + k * period_length` has passed, for `k` from 1 to
+/// `periods`.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to be created
+/// * `[writable, signer]` creator: Payer funding every period
+/// * `[]` payee: Account the tranches are released to
+/// * `[]` system_program: System program for account creation
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `InvalidAuthority` - If the creator names themselves as the payee
+/// * `InvalidSchedule` - If the tranche, periods or period length are zero,
+///   or the total amount overflows
+pub fn create_recurring_payment(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+    tranche: u64,
+    start_time: i64,
+    period_length: i64,
+    periods: u32,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let payee_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    // Derive PDA for escrow account
+    let (escrow_pubkey, escrow_bump) = Pubkey::find_program_address(
+        &[b"escrow", creator_info.key.as_ref(), counter.to_le_bytes().as_ref()],
+        program_id,
+    );
+
+    // Validate accounts
+    if !fee_payer_info.is_signer || !creator_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    if *escrow_info.key != escrow_pubkey || *system_program_info.key != system_program_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if payee_info.key == creator_info.key {
+        return Err(NescrowError::InvalidAuthority.into());
+    }
+
+    if tranche == 0 || periods == 0 || period_length <= 0 {
+        return Err(NescrowError::InvalidSchedule.into());
+    }
+
+    let amount = tranche
+        .checked_mul(periods as u64)
+        .ok_or(NescrowError::InvalidSchedule)?;
+    let end_time = (periods as i64)
+        .checked_mul(period_length)
+        .and_then(|duration| start_time.checked_add(duration))
+        .ok_or(NescrowError::InvalidSchedule)?;
+
+    // Create the escrow account
+    invoke_signed(
+        &create_account(
+            fee_payer_info.key,
+            escrow_info.key,
+            Rent::get()?.minimum_balance(Escrow::LEN),
+            Escrow::LEN as u64,
+            program_id,
+        ),
+        &[fee_payer_info.clone(), escrow_info.clone()],
+        &[&[
+            b"escrow",
+            creator_info.key.as_ref(),
+            counter.to_le_bytes().as_ref(),
+            &[escrow_bump],
+        ]],
+    )?;
+
+    // Fund every period up front
+    invoke(
+        &transfer(creator_info.key, escrow_info.key, amount),
+        &[creator_info.clone(), escrow_info.clone(), system_program_info.clone()],
+    )?;
+
+    let escrow = Escrow {
+        creator: *creator_info.key,
+        taker: Some(*payee_info.key),
+        amount,
+        status: ESCROW_STATUS_ACCEPTED,
+        expiry_time: end_time,
+        escrow_bump,
+        counter,
+        rent_payer: *fee_payer_info.key,
+        created_slot: Clock::get()?.slot,
+        schedule: Some(Schedule {
+            tranche,
+            start_time,
+            period_length,
+            periods,
+            released: 0,
+        }),
+        ..Escrow::default()
+    };
+
+    escrow.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Recurring payment created: {}", escrow_pubkey);
+    Ok(())
+}
+
+/// Number of periods whose boundary has passed at `now`
+///
+/// The start time is not bounded at creation, so the time since it
+/// saturates rather than overflowing for schedules starting far in the past.
+pub fn elapsed_periods(schedule: &Schedule, now: i64) -> u32 {
+    if now < schedule.start_time {
+        return 0;
+    }
+
+    let elapsed = now.saturating_sub(schedule.start_time) / schedule.period_length;
+    elapsed.min(schedule.periods as i64) as u32
+}
@@ -0,0 +1,137 @@
+use std::str::FromStr;
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::{create_account, transfer},
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::{Escrow, Stream};
+use crate::src::create_escrow::ESCROW_STATUS_ACCEPTED;
+
+/// Create an escrow that releases its amount linearly to a beneficiary
+///
+/// The stream lives in the same escrow PDA as a wager, with the beneficiary
+/// as its taker. Nothing needs accepting, so the escrow starts out accepted.
+/// Nothing vests before `start_time` and everything has vested at `end_time`.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to be created
+/// * `[writable, signer]` creator: Payer funding the stream
+/// * `[]` beneficiary: Account the funds vest to
+/// * `[]` system_program: System program for account creation
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `InvalidAuthority` - If the creator names themselves as the beneficiary
+/// * `InvalidStream` - If the amount is zero or the end time is not after the start time
+pub fn create_stream(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+    amount: u64,
+    start_time: i64,
+    end_time: i64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let beneficiary_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    // Derive PDA for escrow account
+    let (escrow_pubkey, escrow_bump) = Pubkey::find_program_address(
+        &[b"escrow", creator_info.key.as_ref(), counter.to_le_bytes().as_ref()],
+        program_id,
+    );
+
+    // Validate accounts
+    if !fee_payer_info.is_signer || !creator_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    if *escrow_info.key != escrow_pubkey || *system_program_info.key != system_program_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if beneficiary_info.key == creator_info.key {
+        return Err(NescrowError::InvalidAuthority.into());
+    }
+
+    if amount == 0 || end_time <= start_time {
+        return Err(NescrowError::InvalidStream.into());
+    }
+
+    // Create the escrow account
+    invoke_signed(
+        &create_account(
+            fee_payer_info.key,
+            escrow_info.key,
+            Rent::get()?.minimum_balance(Escrow::LEN),
+            Escrow::LEN as u64,
+            program_id,
+        ),
+        &[fee_payer_info.clone(), escrow_info.clone()],
+        &[&[
+            b"escrow",
+            creator_info.key.as_ref(),
+            counter.to_le_bytes().as_ref(),
+            &[escrow_bump],
+        ]],
+    )?;
+
+    // Lock the streamed amount in the escrow
+    invoke(
+        &transfer(creator_info.key, escrow_info.key, amount),
+        &[creator_info.clone(), escrow_info.clone(), system_program_info.clone()],
+    )?;
+
+    let escrow = Escrow {
+        creator: *creator_info.key,
+        taker: Some(*beneficiary_info.key),
+        amount,
+        status: ESCROW_STATUS_ACCEPTED,
+        expiry_time: end_time,
+        escrow_bump,
+        counter,
+        rent_payer: *fee_payer_info.key,
+        created_slot: Clock::get()?.slot,
+        stream: Some(Stream {
+            start_time,
+            end_time,
+            withdrawn: 0,
+        }),
+        ..Escrow::default()
+    };
+
+    escrow.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Stream created: {}", escrow_pubkey);
+    Ok(())
+}
+
+/// Portion of `amount` that has vested at `now`
+///
+/// The start and end times are not bounded at creation, so the time since
+/// the start and the duration are taken as unsigned distances, which cannot
+/// overflow however far apart the two times are.
+pub fn vested_amount(amount: u64, stream: &Stream, now: i64) -> u64 {
+    let elapsed = now.clamp(stream.start_time, stream.end_time).abs_diff(stream.start_time);
+    let duration = stream.end_time.abs_diff(stream.start_time);
+
+    (amount as u128 * elapsed as u128 / duration as u128) as u64
+}
@@ -0,0 +1,67 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::ESCROW_STATUS_OPEN;
+
+/// Lower the stake of an open escrow
+///
+/// The difference is moved from the escrow back to the creator, so the
+/// escrow keeps holding exactly the stored amount.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to withdraw from
+/// * `[writable, signer]` creator: Creator of the escrow
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotOpen` - If the escrow was already accepted, completed or cancelled
+/// * `InvalidStakeChange` - If the decrease is zero or exceeds the stake, or
+///   would leave less than what takers have already filled
+pub fn decrease_stake(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+    decrease: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, creator_info.key, counter)?;
+
+    if !fee_payer_info.is_signer || !creator_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_OPEN {
+        return Err(NescrowError::EscrowNotOpen.into());
+    }
+
+    let filled = escrow.data.fills.as_ref().map_or(0, |fills| fills.filled);
+    if decrease == 0 || decrease > escrow.data.amount - filled {
+        return Err(NescrowError::InvalidStakeChange.into());
+    }
+
+    escrow.data.amount -= decrease;
+
+    **escrow_info.try_borrow_mut_lamports()? -= decrease;
+    **creator_info.try_borrow_mut_lamports()? += decrease;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Stake decreased to {}", escrow.data.amount);
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::create_milestone_escrow::{
+    load_milestone_escrow, MILESTONE_STATUS_DISPUTED, MILESTONE_STATUS_PENDING,
+};
+
+/// Freeze one milestone of a freelance escrow
+///
+/// A disputed milestone stays frozen until the client approves it, or
+/// until the final deadline passes and it can be reclaimed by the client.
+/// Only a milestone whose deadline has not passed can be disputed, and
+/// other milestones are unaffected.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Milestone escrow holding the funds
+/// * `[signer]` party: Client or worker raising the dispute
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `InvalidAuthority` - If the signer is neither the client nor the worker
+/// * `InvalidMilestones` - If the milestone index is out of range
+/// * `MilestoneNotPending` - If the milestone is not pending
+/// * `MilestoneDeadlinePassed` - If the milestone's deadline has passed
+pub fn dispute_milestone(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    client: Pubkey,
+    counter: u64,
+    index: u8,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let party_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_milestone_escrow(program_id, escrow_info, &client, counter)?;
+
+    if !fee_payer_info.is_signer || !party_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if *party_info.key != escrow.data.client && *party_info.key != escrow.data.worker {
+        return Err(NescrowError::InvalidAuthority.into());
+    }
+
+    let milestone = escrow
+        .data
+        .milestones
+        .get_mut(index as usize)
+        .ok_or(NescrowError::InvalidMilestones)?;
+
+    if milestone.status != MILESTONE_STATUS_PENDING {
+        return Err(NescrowError::MilestoneNotPending.into());
+    }
+
+    // An overdue milestone belongs to the client, and disputing it would only delay the refund
+    if Clock::get()?.unix_timestamp > milestone.deadline {
+        return Err(NescrowError::MilestoneDeadlinePassed.into());
+    }
+
+    milestone.status = MILESTONE_STATUS_DISPUTED;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Milestone {} disputed by {}", index, party_info.key);
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::create_purchase::{load_purchase, PURCHASE_STATUS_DISPUTED, PURCHASE_STATUS_SHIPPED};
+
+/// Dispute a shipped purchase
+///
+/// Stops the automatic release to the seller. The payment stays frozen
+/// until the buyer confirms or the seller refunds it.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` purchase: Purchase holding the payment
+/// * `[signer]` buyer: Buyer of the goods
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `InvalidPurchaseStatus` - If the purchase is not shipped
+/// * `InspectionWindowClosed` - If the inspection window has passed
+pub fn dispute_purchase(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let purchase_info = next_account_info(account_info_iter)?;
+    let buyer_info = next_account_info(account_info_iter)?;
+
+    let purchase = &mut load_purchase(program_id, purchase_info, buyer_info.key, counter)?;
+
+    if !fee_payer_info.is_signer || !buyer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if purchase.data.status != PURCHASE_STATUS_SHIPPED {
+        return Err(NescrowError::InvalidPurchaseStatus.into());
+    }
+
+    if Clock::get()?.unix_timestamp > purchase.data.inspection_deadline {
+        return Err(NescrowError::InspectionWindowClosed.into());
+    }
+
+    purchase.data.status = PURCHASE_STATUS_DISPUTED;
+
+    purchase.data.serialize(&mut &mut purchase_info.data.borrow_mut()[..])?;
+
+    msg!("Purchase disputed by {}", buyer_info.key);
+    Ok(())
+}
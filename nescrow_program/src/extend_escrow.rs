@@ -1,111 +1,104 @@
-use std::str::FromStr;
-use std::ops::DerefMut;
-use borsh::{BorshDeserialize, BorshSerialize};
-
-// need to dynamically include this when there is an invoke
-use solana_program::instruction::{AccountMeta, Instruction};
-
-// need to dynamically include this when there is an invoke
-use solana_program::program::{invoke, invoke_signed};
-
-use solana_program::borsh0_10::try_from_slice_unchecked;
-use solana_program::account_info::{AccountInfo, next_account_info, next_account_infos};
-use solana_program::entrypoint::ProgramResult;
-use solana_program::pubkey::Pubkey;
-use solana_program::rent::Rent;
-use solana_program::system_instruction::create_account;
-use solana_program::{msg, system_program};
-use solana_program::sysvar::Sysvar;
-use solana_program::program_pack::Pack;
-use crate::generated::errors::NescrowError;
-use crate::generated::state::*;
-use solana_program::keccak;
-
-use crate::generated::state::{
-	AccountPDA,
-	Escrow,
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
 };
 
-
-
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_OPEN};
+use crate::src::oracle::{ORACLE_KIND_EVENT, ORACLE_KIND_EVENT_SCORE};
 
 /// Extend the expiry time of an escrow
 ///
-/// Accounts:
-/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
-/// 1. `[writable]` escrow: [Escrow] 
-/// 2. `[signer]` creator: [AccountInfo] 
+/// While the escrow is open the creator extends alone. Once a taker has
+/// committed funds, the taker must co-sign, unless the extension fits in
+/// the maximum extension the taker agreed to by accepting. Extensions made
+/// without the taker use up that allowance. Partial fills commit funds too,
+/// and since they have no single taker to co-sign, only the allowance
+/// applies to them.
 ///
-/// Data:
-/// - counter: [u64] 
-/// - new_expiry_time: [i64] The new expiry time for the escrow (Unix timestamp)
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to extend
+/// * `[signer]` creator: Creator of the escrow
+/// * `[signer]` taker: Optional, taker consenting to the extension
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotOpen` - If the escrow is neither open nor accepted
+/// * `InvalidExpiry` - If the new expiry time is not later than the current one,
+///   the escrow is settled by an event and still open to takers, or it is a
+///   stream or recurring payment
+/// * `ExtensionNotAgreed` - If the taker did not co-sign and the extension
+///   exceeds the remaining allowance
 pub fn extend_escrow(
-	program_id: &Pubkey,
-	accounts: &[AccountInfo],
-	counter: u64,
-	new_expiry_time: i64,
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+    new_expiry_time: i64,
 ) -> ProgramResult {
-    // Implement your business logic here...
-let account_info_iter = &mut accounts.iter();
-	let fee_payer_info = next_account_info(account_info_iter)?;
-	let escrow_info = next_account_info(account_info_iter)?;
-	let creator_info = next_account_info(account_info_iter)?;
-
-	// Derive PDAs
-	let (escrow_pubkey, escrow_bump) = Pubkey::find_program_address(
-		&[b"escrow", creator_info.key.as_ref(), counter.to_le_bytes().as_ref()],
-		program_id,
-	);
-
-	// Security Checks
-	if fee_payer_info.is_signer != true {
-		return Err(NescrowError::InvalidSignerPermission.into());
-	}
-
-	if *escrow_info.key != escrow_pubkey {
-		return Err(NescrowError::NotExpectedAddress.into());
-	}
-
-	if creator_info.is_signer != true {
-		return Err(NescrowError::InvalidSignerPermission.into());
-	}
-
-
-
-	// Security Checks
-	if *escrow_info.owner != *program_id {
-		return Err(NescrowError::WrongAccountOwner.into());
-	}
-
-	if *creator_info.owner != *program_id {
-		return Err(NescrowError::WrongAccountOwner.into());
-	}
-
-	if escrow_info.data_len() != 383usize {
-		return Err(NescrowError::InvalidAccountLen.into());
-	}
-
-
-	// Accounts Deserialization
-	let escrow = &mut AccountPDA::new(
-		&escrow_info,
-		try_from_slice_unchecked::<Escrow>(&escrow_info.data.borrow()).unwrap(),
-		escrow_bump,
-	);
-
-
-	// Compressions
-
-
-
-	// CPIs - Modify Default Values
-
-
-
-	// Compression Calls - Modify Default Values
-
-	// Accounts Serialization
-	escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
-
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let taker_info = next_account_info(account_info_iter).ok();
+
+    let escrow = &mut load_escrow(program_id, escrow_info, creator_info.key, counter)?;
+
+    if !fee_payer_info.is_signer || !creator_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if new_expiry_time <= escrow.data.expiry_time {
+        return Err(NescrowError::InvalidExpiry.into());
+    }
+
+    // The end of a stream or recurring payment is fixed by its schedule
+    if escrow.data.stream.is_some() || escrow.data.schedule.is_some() {
+        return Err(NescrowError::InvalidExpiry.into());
+    }
+
+    // Creation checked the expiry against the event's start time, so takers
+    // of an event escrow cannot commit once its result may be known
+    let settled_by_event = matches!(escrow.data.oracle_kind, ORACLE_KIND_EVENT | ORACLE_KIND_EVENT_SCORE);
+    if escrow.data.status == ESCROW_STATUS_OPEN && settled_by_event {
+        return Err(NescrowError::InvalidExpiry.into());
+    }
+
+    match escrow.data.status {
+        ESCROW_STATUS_OPEN if escrow.data.fills.as_ref().is_none_or(|fills| fills.filled == 0) => {}
+        ESCROW_STATUS_OPEN | ESCROW_STATUS_ACCEPTED => {
+            let taker_consents = match taker_info {
+                Some(taker_info) if Some(*taker_info.key) == escrow.data.taker => taker_info.is_signer,
+                Some(_) => return Err(NescrowError::NotExpectedAddress.into()),
+                None => false,
+            };
+
+            if !taker_consents {
+                let extension = new_expiry_time
+                    .checked_sub(escrow.data.expiry_time)
+                    .ok_or(NescrowError::InvalidExpiry)?;
+                if extension > escrow.data.max_extension {
+                    return Err(NescrowError::ExtensionNotAgreed.into());
+                }
+
+                escrow.data.max_extension -= extension;
+            }
+        }
+        _ => return Err(NescrowError::EscrowNotOpen.into()),
+    }
+
+    escrow.data.expiry_time = new_expiry_time;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Escrow expiry extended to {}", new_expiry_time);
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,178 @@
+use std::str::FromStr;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::{create_account, transfer},
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::{AccountPDA, Fill};
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_OPEN};
+
+/// Fill part of an escrow's amount
+///
+/// The first fill of a taker creates their fill record, paid for by the
+/// taker; later fills add to it. The escrow is accepted as soon as its
+/// whole amount is filled.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to fill
+/// * `[writable]` fill: Fill record of the taker
+/// * `[writable, signer]` taker: Account filling
+/// * `[]` system_program: System program for account creation
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotOpen` - If the escrow was already accepted, completed or cancelled
+/// * `EscrowExpired` - If the escrow expiry time has passed
+/// * `InvalidAuthority` - If the creator tries to fill their own escrow
+/// * `InvalidFill` - If the escrow does not take fills, or the fill is zero
+///   or exceeds what is left to fill
+pub fn fill_escrow(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+    amount: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let fill_info = next_account_info(account_info_iter)?;
+    let taker_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, &creator, counter)?;
+
+    // Validate accounts
+    if !fee_payer_info.is_signer || !taker_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    if *system_program_info.key != system_program_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_OPEN {
+        return Err(NescrowError::EscrowNotOpen.into());
+    }
+
+    if Clock::get()?.unix_timestamp >= escrow.data.expiry_time {
+        return Err(NescrowError::EscrowExpired.into());
+    }
+
+    if *taker_info.key == escrow.data.creator {
+        return Err(NescrowError::InvalidAuthority.into());
+    }
+
+    let escrow_amount = escrow.data.amount;
+    let fills = escrow.data.fills.as_mut().ok_or(NescrowError::InvalidFill)?;
+    if amount == 0 || amount > escrow_amount - fills.filled {
+        return Err(NescrowError::InvalidFill.into());
+    }
+
+    let mut fill = if fill_info.data_is_empty() {
+        let (fill_pubkey, fill_bump) = Pubkey::find_program_address(
+            &[b"fill", escrow_info.key.as_ref(), taker_info.key.as_ref()],
+            program_id,
+        );
+
+        if *fill_info.key != fill_pubkey {
+            return Err(NescrowError::NotExpectedAddress.into());
+        }
+
+        // Create the fill account
+        invoke_signed(
+            &create_account(
+                taker_info.key,
+                fill_info.key,
+                Rent::get()?.minimum_balance(Fill::LEN),
+                Fill::LEN as u64,
+                program_id,
+            ),
+            &[taker_info.clone(), fill_info.clone()],
+            &[&[
+                b"fill",
+                escrow_info.key.as_ref(),
+                taker_info.key.as_ref(),
+                &[fill_bump],
+            ]],
+        )?;
+
+        Fill {
+            escrow: *escrow_info.key,
+            taker: *taker_info.key,
+            amount: 0,
+            fill_bump,
+        }
+    } else {
+        load_fill(program_id, fill_info, escrow_info.key, taker_info.key)?.data
+    };
+
+    // Move the fill into the escrow
+    invoke(
+        &transfer(taker_info.key, escrow_info.key, amount),
+        &[taker_info.clone(), escrow_info.clone(), system_program_info.clone()],
+    )?;
+
+    fill.amount += amount;
+    fills.filled += amount;
+
+    if fills.filled == escrow_amount {
+        escrow.data.status = ESCROW_STATUS_ACCEPTED;
+    }
+
+    fill.serialize(&mut &mut fill_info.data.borrow_mut()[..])?;
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Filled {} lamports by {}", amount, taker_info.key);
+    Ok(())
+}
+
+/// Load the fill record of `taker` on `escrow` after checking its address,
+/// owner, size and that it has not been closed
+pub fn load_fill<'a, 'b>(
+    program_id: &Pubkey,
+    fill_info: &'a AccountInfo<'b>,
+    escrow: &Pubkey,
+    taker: &Pubkey,
+) -> Result<AccountPDA<'a, 'b, Fill>, ProgramError> {
+    let (fill_pubkey, fill_bump) = Pubkey::find_program_address(
+        &[b"fill", escrow.as_ref(), taker.as_ref()],
+        program_id,
+    );
+
+    if *fill_info.key != fill_pubkey {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if fill_info.owner != program_id {
+        return Err(NescrowError::WrongAccountOwner.into());
+    }
+
+    if fill_info.data_len() != Fill::LEN {
+        return Err(NescrowError::InvalidAccountLen.into());
+    }
+
+    let fill = Fill::deserialize(&mut &fill_info.data.borrow()[..])?;
+    if fill.escrow != *escrow || fill.taker != *taker {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    Ok(AccountPDA::new(fill_info, fill, fill_bump))
+}
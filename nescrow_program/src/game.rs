@@ -0,0 +1,52 @@
+use solana_program::{hash::hashv, pubkey::Pubkey};
+
+use crate::generated::state::{Game, Outcome};
+
+/// Game kind constants
+pub const GAME_COIN_TOSS: u8 = 0;
+pub const GAME_ROCK_PAPER_SCISSORS: u8 = 1;
+
+/// Coin toss choices; the creator wins if both call the same side
+pub const COIN_HEADS: u8 = 0;
+pub const COIN_TAILS: u8 = 1;
+
+/// Rock paper scissors choices
+pub const RPS_ROCK: u8 = 0;
+pub const RPS_PAPER: u8 = 1;
+pub const RPS_SCISSORS: u8 = 2;
+
+/// Commitment a party submits before revealing `choice`
+///
+/// The party's key is hashed in so one side cannot copy the other's
+/// commitment and reveal the same choice.
+pub fn commitment(choice: u8, salt: &[u8; 32], party: &Pubkey) -> [u8; 32] {
+    hashv(&[&[choice], salt, party.as_ref()]).to_bytes()
+}
+
+/// Whether the game kind is one the program can settle
+pub fn is_supported(game_kind: u8) -> bool {
+    matches!(game_kind, GAME_COIN_TOSS | GAME_ROCK_PAPER_SCISSORS)
+}
+
+/// Whether `choice` is a valid move in the game
+pub fn is_valid_choice(game_kind: u8, choice: u8) -> bool {
+    match game_kind {
+        GAME_COIN_TOSS => choice <= COIN_TAILS,
+        GAME_ROCK_PAPER_SCISSORS => choice <= RPS_SCISSORS,
+        _ => false,
+    }
+}
+
+/// Outcome of a game once both choices are revealed, `None` on a tie
+pub fn play(game: &Game, creator_choice: u8, taker_choice: u8) -> Option<Outcome> {
+    match game.game_kind {
+        GAME_COIN_TOSS if creator_choice == taker_choice => Some(Outcome::CreatorWins),
+        GAME_COIN_TOSS => Some(Outcome::TakerWins),
+        // Each choice beats the one before it, wrapping around
+        _ => match (3 + creator_choice - taker_choice) % 3 {
+            1 => Some(Outcome::CreatorWins),
+            2 => Some(Outcome::TakerWins),
+            _ => None,
+        },
+    }
+}
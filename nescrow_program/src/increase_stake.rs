@@ -0,0 +1,102 @@
+use std::str::FromStr;
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    pubkey::Pubkey,
+    system_instruction::transfer,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_OPEN};
+
+/// Raise the stake of an escrow
+///
+/// While the escrow is open the creator tops up alone. Once accepted, both
+/// parties sign and each adds `increase`, so the stakes stay matched and the
+/// pot stays equal to twice the stored amount.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to top up
+/// * `[writable, signer]` creator: Creator of the escrow
+/// * `[]` system_program: System program for the transfers
+/// * `[writable, signer]` taker: Taker of the escrow, only for accepted escrows
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotOpen` - If the escrow is neither open nor accepted
+/// * `InvalidStakeChange` - If the increase is zero or overflows, or the
+///   escrow is a stream or recurring payment
+pub fn increase_stake(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+    increase: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, creator_info.key, counter)?;
+
+    if !fee_payer_info.is_signer || !creator_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    if *system_program_info.key != system_program_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_OPEN && escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotOpen.into());
+    }
+
+    if increase == 0 || escrow.data.stream.is_some() || escrow.data.schedule.is_some() {
+        return Err(NescrowError::InvalidStakeChange.into());
+    }
+
+    escrow.data.amount = escrow
+        .data
+        .amount
+        .checked_add(increase)
+        .ok_or(NescrowError::InvalidStakeChange)?;
+
+    invoke(
+        &transfer(creator_info.key, escrow_info.key, increase),
+        &[creator_info.clone(), escrow_info.clone(), system_program_info.clone()],
+    )?;
+
+    // The taker matches the increase once the escrow is accepted
+    if escrow.data.status == ESCROW_STATUS_ACCEPTED {
+        let taker_info = next_account_info(account_info_iter)?;
+
+        if !taker_info.is_signer {
+            return Err(NescrowError::InvalidSignerPermission.into());
+        }
+
+        if Some(*taker_info.key) != escrow.data.taker {
+            return Err(NescrowError::NotExpectedAddress.into());
+        }
+
+        invoke(
+            &transfer(taker_info.key, escrow_info.key, increase),
+            &[taker_info.clone(), escrow_info.clone(), system_program_info.clone()],
+        )?;
+    }
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Stake increased to {}", escrow.data.amount);
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use std::str::FromStr;
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::{create_account, transfer},
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::Participant;
+use crate::src::create_pool::{load_pool, POOL_STATUS_OPEN};
+
+/// Join a pool by depositing into it
+///
+/// The participant pays for their own record, which is created once per
+/// pool, so nobody can join twice. The record's rent is returned when the
+/// participant claims from the settled pool.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` pool: Pool to join
+/// * `[writable]` participant_record: Participant record to be created
+/// * `[writable, signer]` participant: Participant paying the deposit
+/// * `[]` system_program: System program for account creation
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `PoolNotOpen` - If the pool has been settled
+/// * `JoinDeadlinePassed` - If the join deadline has passed
+/// * `PoolFull` - If the pool has reached its participant cap
+pub fn join_pool(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    organizer: Pubkey,
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let pool_info = next_account_info(account_info_iter)?;
+    let record_info = next_account_info(account_info_iter)?;
+    let participant_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let pool = &mut load_pool(program_id, pool_info, &organizer, counter)?;
+
+    // Derive PDA for the participant record
+    let (record_pubkey, record_bump) = Pubkey::find_program_address(
+        &[b"participant", pool_info.key.as_ref(), participant_info.key.as_ref()],
+        program_id,
+    );
+
+    // Validate accounts
+    if !fee_payer_info.is_signer || !participant_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    if *record_info.key != record_pubkey || *system_program_info.key != system_program_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if pool.data.status != POOL_STATUS_OPEN {
+        return Err(NescrowError::PoolNotOpen.into());
+    }
+
+    if Clock::get()?.unix_timestamp > pool.data.join_deadline {
+        return Err(NescrowError::JoinDeadlinePassed.into());
+    }
+
+    if pool.data.participants >= pool.data.max_participants {
+        return Err(NescrowError::PoolFull.into());
+    }
+
+    // Create the participant record
+    invoke_signed(
+        &create_account(
+            participant_info.key,
+            record_info.key,
+            Rent::get()?.minimum_balance(Participant::LEN),
+            Participant::LEN as u64,
+            program_id,
+        ),
+        &[participant_info.clone(), record_info.clone()],
+        &[&[
+            b"participant",
+            pool_info.key.as_ref(),
+            participant_info.key.as_ref(),
+            &[record_bump],
+        ]],
+    )?;
+
+    // Deposit into the pool
+    invoke(
+        &transfer(participant_info.key, pool_info.key, pool.data.deposit),
+        &[participant_info.clone(), pool_info.clone(), system_program_info.clone()],
+    )?;
+
+    let record = Participant {
+        pool: *pool_info.key,
+        participant: *participant_info.key,
+        participant_bump: record_bump,
+    };
+
+    record.serialize(&mut &mut record_info.data.borrow_mut()[..])?;
+
+    pool.data.participants += 1;
+
+    pool.data.serialize(&mut &mut pool_info.data.borrow_mut()[..])?;
+
+    msg!("{} joined pool {}", participant_info.key, pool_info.key);
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use crate::generated::state::{Line, Outcome, Score};
+
+/// Outcome of a line given the final score, `None` on a push
+///
+/// Scores are doubled to compare against half-point lines exactly.
+pub fn settle(line: &Line, score: &Score) -> Option<Outcome> {
+    let home = 2 * score.home as i64;
+    let away = 2 * score.away as i64;
+
+    let creator_margin = match *line {
+        Line::Spread { creator_home: true, handicap } => home + handicap as i64 - away,
+        Line::Spread { creator_home: false, handicap } => away + handicap as i64 - home,
+        Line::Total { creator_over: true, total } => home + away - total as i64,
+        Line::Total { creator_over: false, total } => total as i64 - home - away,
+    };
+
+    match creator_margin.signum() {
+        1 => Some(Outcome::CreatorWins),
+        -1 => Some(Outcome::TakerWins),
+        _ => None,
+    }
+}
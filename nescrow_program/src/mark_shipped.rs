@@ -0,0 +1,67 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::create_purchase::{load_purchase, PURCHASE_STATUS_PAID, PURCHASE_STATUS_SHIPPED};
+
+/// Mark a purchase shipped
+///
+/// Starts the inspection window, at the end of which anyone can release
+/// the payment to the seller unless the buyer confirmed or disputed first.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` purchase: Purchase holding the payment
+/// * `[signer]` seller: Seller of the goods
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `InvalidPurchaseStatus` - If the purchase is not paid and awaiting shipment
+/// * `InvalidPurchase` - If the inspection deadline does not fit in a timestamp
+pub fn mark_shipped(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    buyer: Pubkey,
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let purchase_info = next_account_info(account_info_iter)?;
+    let seller_info = next_account_info(account_info_iter)?;
+
+    let purchase = &mut load_purchase(program_id, purchase_info, &buyer, counter)?;
+
+    if !fee_payer_info.is_signer || !seller_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if *seller_info.key != purchase.data.seller {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if purchase.data.status != PURCHASE_STATUS_PAID {
+        return Err(NescrowError::InvalidPurchaseStatus.into());
+    }
+
+    purchase.data.inspection_deadline = Clock::get()?
+        .unix_timestamp
+        .checked_add(purchase.data.inspection_window)
+        .ok_or(NescrowError::InvalidPurchase)?;
+    purchase.data.status = PURCHASE_STATUS_SHIPPED;
+
+    purchase.data.serialize(&mut &mut purchase_info.data.borrow_mut()[..])?;
+
+    msg!("Purchase shipped, inspection until {}", purchase.data.inspection_deadline);
+    Ok(())
+}
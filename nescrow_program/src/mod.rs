@@ -3,3 +3,64 @@ pub mod accept_escrow;
 pub mod complete_escrow;
 pub mod cancel_escrow;
 pub mod extend_escrow;
+pub mod resolve_escrow;
+pub mod create_event;
+pub mod report_event_result;
+pub mod reveal_choice;
+pub mod settle_game;
+pub mod game;
+pub mod line;
+pub mod series;
+pub mod conditions;
+pub mod set_release_conditions;
+pub mod release_if_conditions_met;
+pub mod create_milestone_escrow;
+pub mod approve_milestone;
+pub mod dispute_milestone;
+pub mod reclaim_milestones;
+pub mod create_stream;
+pub mod withdraw;
+pub mod cancel_stream;
+pub mod create_recurring_payment;
+pub mod release_next_period;
+pub mod cancel_recurring_payment;
+pub mod increase_stake;
+pub mod decrease_stake;
+pub mod amend_escrow;
+pub mod create_purchase;
+pub mod mark_shipped;
+pub mod confirm_purchase;
+pub mod dispute_purchase;
+pub mod auto_release_purchase;
+pub mod refund_purchase;
+pub mod mutual_cancel;
+pub mod concede;
+pub mod create_pool;
+pub mod join_pool;
+pub mod settle_pool;
+pub mod claim_pool;
+pub mod create_market;
+pub mod place_stake;
+pub mod resolve_market;
+pub mod claim_position;
+pub mod fill_escrow;
+pub mod accept_fills;
+pub mod settle_fills;
+pub mod claim_fill;
+pub mod post_counter_offer;
+pub mod accept_counter_offer;
+pub mod close_counter_offer;
+pub mod transfer_position;
+pub mod tokenize_position;
+pub mod redeem_position_token;
+pub mod report_event_score;
+pub mod settle_line;
+pub mod report_series_game;
+pub mod resolve_series_game;
+pub mod clone_escrow;
+pub mod refund_parlay;
+pub mod refund_event_escrow;
+pub mod refund_pool;
+pub mod oracle;
+pub mod pyth;
+pub mod spl_token;
\ No newline at end of file
@@ -0,0 +1,73 @@
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::{close_account, is_closable, load_escrow, refund_stakes};
+use crate::src::create_escrow::ESCROW_STATUS_ACCEPTED;
+use crate::src::tokenize_position::is_tokenized;
+
+/// Call off an accepted escrow with the consent of both parties
+///
+/// Each side gets its stake back, the rent goes back to whoever paid for
+/// the escrow account, and the account is closed.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to cancel
+/// * `[writable, signer]` creator: Creator of the escrow
+/// * `[writable, signer]` taker: Taker of the escrow
+/// * `[writable]` rent_payer: Account that paid the escrow's rent
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the escrow is not in Accepted status
+/// * `NotMutuallyCancellable` - If the escrow is a stream, recurring payment
+///   or tokenized, or was created in the current slot
+pub fn mutual_cancel(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let taker_info = next_account_info(account_info_iter)?;
+    let rent_payer_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, creator_info.key, counter)?;
+
+    if !fee_payer_info.is_signer || !creator_info.is_signer || !taker_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    if escrow.data.stream.is_some()
+        || escrow.data.schedule.is_some()
+        || is_tokenized(&escrow.data)
+        || !is_closable(&escrow.data)?
+    {
+        return Err(NescrowError::NotMutuallyCancellable.into());
+    }
+
+    if *rent_payer_info.key != escrow.data.rent_payer {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    refund_stakes(&mut escrow.data, escrow_info, creator_info, taker_info)?;
+    close_account(escrow_info, rent_payer_info)?;
+
+    msg!("Escrow {} cancelled by both parties", escrow_info.key);
+    Ok(())
+}
@@ -0,0 +1,261 @@
+use borsh::BorshDeserialize;
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    ed25519_program,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{self, Sysvar},
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::{Escrow, Outcome};
+use crate::src::create_event::load_event;
+use crate::src::pyth::{PythPrice, PYTH_STATUS_TRADING};
+
+/// Oracle kind constants
+pub const ORACLE_KIND_NONE: u8 = 0;
+pub const ORACLE_KIND_ATTESTATION: u8 = 1;
+pub const ORACLE_KIND_PRICE_FEED: u8 = 2;
+pub const ORACLE_KIND_EVENT: u8 = 3;
+pub const ORACLE_KIND_EVENT_SCORE: u8 = 4;
+
+/// Size of the header and single offsets entry of an Ed25519 program instruction
+const ED25519_HEADER_LEN: usize = 16;
+
+/// A data source that can decide the outcome of an escrow
+///
+/// Each implementation checks that `oracle_info` is the account it expects
+/// for `escrow` before trusting anything read from it.
+pub trait OutcomeSource {
+    fn read(
+        program_id: &Pubkey,
+        oracle_info: &AccountInfo,
+        escrow_key: &Pubkey,
+        escrow: &Escrow,
+    ) -> Result<Outcome, ProgramError>;
+}
+
+/// Ed25519 attestation signed by `oracle_account`, read through the
+/// instructions sysvar
+pub struct Attestation;
+
+/// Pyth price feed at `oracle_account`, compared against the escrow's price condition
+pub struct PriceFeed;
+
+/// Result posted to the program's own event account at `oracle_account`
+pub struct EventResult;
+
+/// Read the outcome of `escrow` from the source named by its oracle kind
+///
+/// Event score oracles are not read here, since their lines can also end in
+/// a push; those escrows settle with `SettleLine`.
+pub fn read_outcome(
+    program_id: &Pubkey,
+    oracle_info: &AccountInfo,
+    escrow_key: &Pubkey,
+    escrow: &Escrow,
+) -> Result<Outcome, ProgramError> {
+    match escrow.oracle_kind {
+        ORACLE_KIND_ATTESTATION => Attestation::read(program_id, oracle_info, escrow_key, escrow),
+        ORACLE_KIND_PRICE_FEED => PriceFeed::read(program_id, oracle_info, escrow_key, escrow),
+        ORACLE_KIND_EVENT => EventResult::read(program_id, oracle_info, escrow_key, escrow),
+        _ => Err(NescrowError::NoOracle.into()),
+    }
+}
+
+/// Whether an escrow can be created with this oracle configuration
+pub fn is_supported(escrow: &Escrow) -> bool {
+    let has_price_condition = escrow.price_condition.is_some();
+    let has_line = escrow.line.is_some();
+
+    match (escrow.oracle_kind, &escrow.event_condition) {
+        (ORACLE_KIND_NONE | ORACLE_KIND_ATTESTATION, None) => !has_price_condition && !has_line,
+        (ORACLE_KIND_PRICE_FEED, None) => has_price_condition && !has_line,
+        (ORACLE_KIND_EVENT, Some(condition)) => {
+            !has_price_condition && !has_line && condition.creator_outcome != condition.taker_outcome
+        }
+        (ORACLE_KIND_EVENT_SCORE, None) => !has_price_condition && has_line,
+        _ => false,
+    }
+}
+
+/// Message an attestation oracle signs to settle `escrow`, created at
+/// `created_slot`, with `outcome`
+pub fn attestation_message(escrow: &Pubkey, created_slot: u64, outcome: Outcome) -> Vec<u8> {
+    let mut message = attestation_subject(escrow, created_slot);
+    message.push(outcome as u8);
+    message
+}
+
+/// Message an attestation oracle signs for `outcome` of game `game` of the
+/// series settling `escrow`, created at `created_slot`
+pub fn series_attestation_message(escrow: &Pubkey, created_slot: u64, game: u8, outcome: Outcome) -> Vec<u8> {
+    let mut message = attestation_subject(escrow, created_slot);
+    message.push(game);
+    message.push(outcome as u8);
+    message
+}
+
+/// Escrow an attestation is about, named by its address and creation slot
+///
+/// An address is reused once its escrow is closed, so the slot keeps an
+/// attestation for a closed escrow from settling a new one at the same
+/// address, the same way release conditions are bound to it.
+pub fn attestation_subject(escrow: &Pubkey, created_slot: u64) -> Vec<u8> {
+    let mut subject = escrow.to_bytes().to_vec();
+    subject.extend_from_slice(&created_slot.to_le_bytes());
+    subject
+}
+
+impl OutcomeSource for Attestation {
+    /// The oracle signs the message built by [`attestation_message`].
+    fn read(
+        _program_id: &Pubkey,
+        oracle_info: &AccountInfo,
+        escrow_key: &Pubkey,
+        escrow: &Escrow,
+    ) -> Result<Outcome, ProgramError> {
+        let subject = attestation_subject(escrow_key, escrow.created_slot);
+        attested_outcome(oracle_info, &escrow.oracle_account, &subject)
+    }
+}
+
+/// Outcome attested by `signer` in a message of `subject` followed by the outcome
+///
+/// The transaction must carry an Ed25519 program instruction, immediately
+/// before the current one, in which `signer` signs the message. The Ed25519
+/// program verifies the signature itself, so this only checks that the
+/// verified key and message are the expected ones.
+pub fn attested_outcome(
+    oracle_info: &AccountInfo,
+    signer: &Pubkey,
+    subject: &[u8],
+) -> Result<Outcome, ProgramError> {
+    if *oracle_info.key != sysvar::instructions::ID {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    let current_index = load_current_index_checked(oracle_info)?;
+    if current_index == 0 {
+        return Err(NescrowError::MissingAttestation.into());
+    }
+
+    let ix = load_instruction_at_checked(current_index as usize - 1, oracle_info)?;
+    if ix.program_id != ed25519_program::ID {
+        return Err(NescrowError::MissingAttestation.into());
+    }
+
+    let data = &ix.data;
+    if data.len() < ED25519_HEADER_LEN || data[0] != 1 {
+        return Err(NescrowError::InvalidAttestation.into());
+    }
+
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]) as usize;
+    let signature_ix_index = read_u16(4);
+    let public_key_offset = read_u16(6);
+    let public_key_ix_index = read_u16(8);
+    let message_offset = read_u16(10);
+    let message_size = read_u16(12);
+    let message_ix_index = read_u16(14);
+
+    // Every part must live in the Ed25519 instruction itself, otherwise the
+    // key and message we compare are not the ones that were verified
+    let this_ix = u16::MAX as usize;
+    if signature_ix_index != this_ix || public_key_ix_index != this_ix || message_ix_index != this_ix {
+        return Err(NescrowError::InvalidAttestation.into());
+    }
+
+    let public_key = data.get(public_key_offset..public_key_offset + 32);
+    if public_key != Some(signer.as_ref()) {
+        return Err(NescrowError::InvalidAttestation.into());
+    }
+
+    match data.get(message_offset..message_offset + message_size) {
+        Some([signed_subject @ .., outcome]) if signed_subject == subject => {
+            Outcome::try_from_slice(&[*outcome]).map_err(|_| NescrowError::InvalidAttestation.into())
+        }
+        _ => Err(NescrowError::InvalidAttestation.into()),
+    }
+}
+
+impl OutcomeSource for PriceFeed {
+    /// The creator wins if the feed's aggregate price is at or above the
+    /// threshold, otherwise the taker wins. The price must have been published
+    /// at or after the condition's resolve time, be no older than its maximum
+    /// staleness, and have a confidence interval within its limit.
+    fn read(
+        _program_id: &Pubkey,
+        oracle_info: &AccountInfo,
+        _escrow_key: &Pubkey,
+        escrow: &Escrow,
+    ) -> Result<Outcome, ProgramError> {
+        if *oracle_info.key != escrow.oracle_account {
+            return Err(NescrowError::NotExpectedAddress.into());
+        }
+
+        let condition = escrow
+            .price_condition
+            .as_ref()
+            .ok_or(NescrowError::NoPriceCondition)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        if now < condition.resolve_after {
+            return Err(NescrowError::PriceConditionNotReady.into());
+        }
+
+        let price = PythPrice::load(oracle_info)?;
+
+        if price.status != PYTH_STATUS_TRADING || price.exponent != condition.exponent {
+            return Err(NescrowError::InvalidPriceAccount.into());
+        }
+
+        if price.publish_time < condition.resolve_after
+            || now.saturating_sub(price.publish_time) > condition.max_staleness
+        {
+            return Err(NescrowError::StalePrice.into());
+        }
+
+        if !price.confidence_within(condition.max_confidence_bps) {
+            return Err(NescrowError::PriceConfidenceTooWide.into());
+        }
+
+        if price.price >= condition.threshold {
+            Ok(Outcome::CreatorWins)
+        } else {
+            Ok(Outcome::TakerWins)
+        }
+    }
+}
+
+impl OutcomeSource for EventResult {
+    /// Each side wins if the reported result is the outcome it backed. A
+    /// result neither side backed cannot settle the escrow.
+    fn read(
+        program_id: &Pubkey,
+        oracle_info: &AccountInfo,
+        _escrow_key: &Pubkey,
+        escrow: &Escrow,
+    ) -> Result<Outcome, ProgramError> {
+        if *oracle_info.key != escrow.oracle_account {
+            return Err(NescrowError::NotExpectedAddress.into());
+        }
+
+        let condition = escrow
+            .event_condition
+            .as_ref()
+            .ok_or(NescrowError::NoOracle)?;
+
+        let event = load_event(program_id, oracle_info)?;
+        let result = event.data.result.ok_or(NescrowError::EventNotReported)?;
+
+        if result == condition.creator_outcome {
+            Ok(Outcome::CreatorWins)
+        } else if result == condition.taker_outcome {
+            Ok(Outcome::TakerWins)
+        } else {
+            Err(NescrowError::InvalidEventOutcome.into())
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::str::FromStr;
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::{create_account, transfer},
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::Position;
+use crate::src::create_market::{load_market, load_position, MARKET_STATUS_OPEN};
+
+/// Stake on one outcome of a market
+///
+/// The first stake on an outcome creates the owner's position for it,
+/// paid for by the owner; later stakes on the same outcome add to it.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` market: Market to stake in
+/// * `[writable]` position: Position of the owner on the outcome
+/// * `[writable, signer]` owner: Account staking
+/// * `[]` system_program: System program for account creation
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `MarketClosed` - If the market is resolved or the cutoff has passed
+/// * `InvalidEventOutcome` - If the outcome index is out of range
+/// * `ZeroStake` - If the stake is zero
+pub fn place_stake(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+    outcome: u8,
+    stake: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let market_info = next_account_info(account_info_iter)?;
+    let position_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let market = &mut load_market(program_id, market_info, &creator, counter)?;
+
+    // Validate accounts
+    if !fee_payer_info.is_signer || !owner_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    if *system_program_info.key != system_program_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if market.data.status != MARKET_STATUS_OPEN || Clock::get()?.unix_timestamp > market.data.cutoff {
+        return Err(NescrowError::MarketClosed.into());
+    }
+
+    if outcome as usize >= market.data.outcome_totals.len() {
+        return Err(NescrowError::InvalidEventOutcome.into());
+    }
+
+    if stake == 0 {
+        return Err(NescrowError::ZeroStake.into());
+    }
+
+    let mut position = if position_info.data_is_empty() {
+        let (position_pubkey, position_bump) = Pubkey::find_program_address(
+            &[b"position", market_info.key.as_ref(), owner_info.key.as_ref(), &[outcome]],
+            program_id,
+        );
+
+        if *position_info.key != position_pubkey {
+            return Err(NescrowError::NotExpectedAddress.into());
+        }
+
+        // Create the position account
+        invoke_signed(
+            &create_account(
+                owner_info.key,
+                position_info.key,
+                Rent::get()?.minimum_balance(Position::LEN),
+                Position::LEN as u64,
+                program_id,
+            ),
+            &[owner_info.clone(), position_info.clone()],
+            &[&[
+                b"position",
+                market_info.key.as_ref(),
+                owner_info.key.as_ref(),
+                &[outcome],
+                &[position_bump],
+            ]],
+        )?;
+
+        Position {
+            market: *market_info.key,
+            owner: *owner_info.key,
+            outcome,
+            stake: 0,
+            position_bump,
+        }
+    } else {
+        load_position(program_id, position_info, market_info.key, owner_info.key, outcome)?.data
+    };
+
+    // Add the stake to the market
+    invoke(
+        &transfer(owner_info.key, market_info.key, stake),
+        &[owner_info.clone(), market_info.clone(), system_program_info.clone()],
+    )?;
+
+    position.stake += stake;
+    market.data.outcome_totals[outcome as usize] += stake;
+
+    position.serialize(&mut &mut position_info.data.borrow_mut()[..])?;
+    market.data.serialize(&mut &mut market_info.data.borrow_mut()[..])?;
+
+    msg!("Staked {} lamports on outcome {}", stake, outcome);
+    Ok(())
+}
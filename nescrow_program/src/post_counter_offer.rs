@@ -0,0 +1,169 @@
+use std::str::FromStr;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::create_account,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::{AccountPDA, CounterOffer, Escrow};
+use crate::src::accept_escrow::is_reserved_for_other;
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::ESCROW_STATUS_OPEN;
+
+/// Propose a different amount or expiry for an open escrow
+///
+/// The offered stake is locked in the counter-offer, so the creator can
+/// accept it in a single step. A taker has at most one counter-offer per
+/// escrow; it stays open until accepted or closed.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[]` escrow: Open escrow the offer is made on
+/// * `[writable]` counter_offer: Counter-offer account to be created
+/// * `[writable, signer]` taker: Prospective taker making the offer
+/// * `[]` system_program: System program for account creation
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotOpen` - If the escrow was already accepted, completed or cancelled
+/// * `EscrowExpired` - If the escrow expiry time has passed
+/// * `InvalidAuthority` - If the creator makes an offer on their own escrow,
+///   or the escrow is reserved for another taker
+/// * `InvalidCounterOffer` - If the offered stake is zero, the offered
+///   expiry is not in the future, or the escrow is a game or taken through
+///   partial fills
+pub fn post_counter_offer(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+    amount: u64,
+    expiry_time: i64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let counter_offer_info = next_account_info(account_info_iter)?;
+    let taker_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let escrow = load_escrow(program_id, escrow_info, &creator, counter)?;
+
+    // Derive PDA for the counter-offer account
+    let (counter_offer_pubkey, offer_bump) = Pubkey::find_program_address(
+        &[b"counter_offer", escrow_info.key.as_ref(), taker_info.key.as_ref()],
+        program_id,
+    );
+
+    // Validate accounts
+    if !fee_payer_info.is_signer || !taker_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    if *counter_offer_info.key != counter_offer_pubkey || *system_program_info.key != system_program_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_OPEN {
+        return Err(NescrowError::EscrowNotOpen.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    if now >= escrow.data.expiry_time {
+        return Err(NescrowError::EscrowExpired.into());
+    }
+
+    if *taker_info.key == escrow.data.creator || is_reserved_for_other(&escrow.data, taker_info.key) {
+        return Err(NescrowError::InvalidAuthority.into());
+    }
+
+    if !takes_counter_offers(&escrow.data) || amount == 0 || expiry_time <= now {
+        return Err(NescrowError::InvalidCounterOffer.into());
+    }
+
+    // Create the counter-offer account holding the offered stake above rent
+    invoke_signed(
+        &create_account(
+            taker_info.key,
+            counter_offer_info.key,
+            Rent::get()?.minimum_balance(CounterOffer::LEN) + amount,
+            CounterOffer::LEN as u64,
+            program_id,
+        ),
+        &[taker_info.clone(), counter_offer_info.clone()],
+        &[&[
+            b"counter_offer",
+            escrow_info.key.as_ref(),
+            taker_info.key.as_ref(),
+            &[offer_bump],
+        ]],
+    )?;
+
+    let counter_offer = CounterOffer {
+        escrow: *escrow_info.key,
+        taker: *taker_info.key,
+        amount,
+        expiry_time,
+        offer_bump,
+    };
+
+    counter_offer.serialize(&mut &mut counter_offer_info.data.borrow_mut()[..])?;
+
+    msg!("Counter-offer of {} lamports until {} by {}", amount, expiry_time, taker_info.key);
+    Ok(())
+}
+
+/// Whether the escrow is a plain wager whose terms a counter-offer can replace
+pub fn takes_counter_offers(escrow: &Escrow) -> bool {
+    escrow.game.is_none()
+        && escrow.fills.is_none()
+        && escrow.stream.is_none()
+        && escrow.schedule.is_none()
+}
+
+/// Load the counter-offer of `taker` on `escrow` after checking its
+/// address, owner, size and that it has not been closed
+pub fn load_counter_offer<'a, 'b>(
+    program_id: &Pubkey,
+    counter_offer_info: &'a AccountInfo<'b>,
+    escrow: &Pubkey,
+    taker: &Pubkey,
+) -> Result<AccountPDA<'a, 'b, CounterOffer>, ProgramError> {
+    let (counter_offer_pubkey, offer_bump) = Pubkey::find_program_address(
+        &[b"counter_offer", escrow.as_ref(), taker.as_ref()],
+        program_id,
+    );
+
+    if *counter_offer_info.key != counter_offer_pubkey {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if counter_offer_info.owner != program_id {
+        return Err(NescrowError::WrongAccountOwner.into());
+    }
+
+    if counter_offer_info.data_len() != CounterOffer::LEN {
+        return Err(NescrowError::InvalidAccountLen.into());
+    }
+
+    let counter_offer = CounterOffer::deserialize(&mut &counter_offer_info.data.borrow()[..])?;
+    if counter_offer.escrow != *escrow || counter_offer.taker != *taker {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    Ok(AccountPDA::new(counter_offer_info, counter_offer, offer_bump))
+}
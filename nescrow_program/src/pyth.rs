@@ -0,0 +1,84 @@
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey, pubkey::Pubkey};
+
+use crate::generated::errors::NescrowError;
+
+/// Pyth oracle program owning the v2 price accounts
+pub const PYTH_PROGRAM_ID: Pubkey = pubkey!("FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH");
+
+/// Magic number at the start of every Pyth account
+pub const PYTH_MAGIC: u32 = 0xa1b2c3d4;
+/// Pyth account layout version we understand
+pub const PYTH_VERSION: u32 = 2;
+/// Account type tag of a Pyth price account
+pub const PYTH_ACCOUNT_TYPE_PRICE: u32 = 3;
+/// Aggregate status of a feed that is currently trading
+pub const PYTH_STATUS_TRADING: u32 = 1;
+
+// Offsets into the Pyth v2 price account layout
+const MAGIC_OFFSET: usize = 0;
+const VERSION_OFFSET: usize = 4;
+const ACCOUNT_TYPE_OFFSET: usize = 8;
+const EXPONENT_OFFSET: usize = 20;
+const TIMESTAMP_OFFSET: usize = 96;
+const AGGREGATE_PRICE_OFFSET: usize = 208;
+const AGGREGATE_CONF_OFFSET: usize = 216;
+const AGGREGATE_STATUS_OFFSET: usize = 224;
+
+/// Length of the price account header, up to the end of the aggregate price
+pub const PYTH_PRICE_HEADER_LEN: usize = 240;
+
+/// Aggregate price read from a Pyth price account
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PythPrice {
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub status: u32,
+    pub publish_time: i64,
+}
+
+impl PythPrice {
+    /// Parse the aggregate price out of a Pyth v2 price account
+    ///
+    /// The account must be owned by the Pyth oracle program, since anyone can
+    /// write an account in the same layout.
+    pub fn load(price_info: &AccountInfo) -> Result<Self, ProgramError> {
+        if *price_info.owner != PYTH_PROGRAM_ID {
+            return Err(NescrowError::WrongAccountOwner.into());
+        }
+
+        Self::unpack(&price_info.data.borrow())
+    }
+
+    /// Parse the aggregate price out of raw price account data
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < PYTH_PRICE_HEADER_LEN
+            || read_u32(data, MAGIC_OFFSET) != PYTH_MAGIC
+            || read_u32(data, VERSION_OFFSET) != PYTH_VERSION
+            || read_u32(data, ACCOUNT_TYPE_OFFSET) != PYTH_ACCOUNT_TYPE_PRICE
+        {
+            return Err(NescrowError::InvalidPriceAccount.into());
+        }
+
+        Ok(Self {
+            price: read_u64(data, AGGREGATE_PRICE_OFFSET) as i64,
+            conf: read_u64(data, AGGREGATE_CONF_OFFSET),
+            exponent: read_u32(data, EXPONENT_OFFSET) as i32,
+            status: read_u32(data, AGGREGATE_STATUS_OFFSET),
+            publish_time: read_u64(data, TIMESTAMP_OFFSET) as i64,
+        })
+    }
+
+    /// Whether the confidence interval is within `max_bps` of the price
+    pub fn confidence_within(&self, max_bps: u16) -> bool {
+        (self.conf as u128) * 10_000 <= (self.price.unsigned_abs() as u128) * max_bps as u128
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
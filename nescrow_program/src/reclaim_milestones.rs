@@ -0,0 +1,79 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::create_milestone_escrow::{
+    load_milestone_escrow, MILESTONE_STATUS_DISPUTED, MILESTONE_STATUS_PENDING, MILESTONE_STATUS_REFUNDED,
+};
+
+/// Return the funds of every overdue milestone to the client
+///
+/// A pending milestone is overdue once its own deadline has passed. A
+/// disputed milestone stays frozen until the final deadline, the last one,
+/// has passed, so a dispute can never lock the client's funds for good.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Milestone escrow holding the funds
+/// * `[writable, signer]` client: Client of the escrow
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `MilestoneDeadlineNotReached` - If no milestone deadline has passed
+pub fn reclaim_milestones(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let client_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_milestone_escrow(program_id, escrow_info, client_info.key, counter)?;
+
+    if !fee_payer_info.is_signer || !client_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    // Deadlines never decrease, so the first one is the earliest and the last the final one
+    let now = Clock::get()?.unix_timestamp;
+    let first_deadline = escrow.data.milestones.first().map_or(0, |milestone| milestone.deadline);
+    let final_deadline = escrow.data.milestones.last().map_or(0, |milestone| milestone.deadline);
+    if now <= first_deadline {
+        return Err(NescrowError::MilestoneDeadlineNotReached.into());
+    }
+
+    let mut reclaimed: u64 = 0;
+    for milestone in escrow.data.milestones.iter_mut() {
+        let overdue = match milestone.status {
+            MILESTONE_STATUS_PENDING => now > milestone.deadline,
+            MILESTONE_STATUS_DISPUTED => now > final_deadline,
+            _ => false,
+        };
+
+        if overdue {
+            reclaimed += milestone.amount;
+            milestone.status = MILESTONE_STATUS_REFUNDED;
+        }
+    }
+
+    **escrow_info.try_borrow_mut_lamports()? -= reclaimed;
+    **client_info.try_borrow_mut_lamports()? += reclaimed;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Reclaimed {} lamports", reclaimed);
+    Ok(())
+}
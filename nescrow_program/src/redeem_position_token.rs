@@ -0,0 +1,102 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    pubkey::Pubkey,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::ESCROW_STATUS_COMPLETED;
+use crate::src::spl_token::{self, TokenAccount, TOKEN_PROGRAM_ID};
+use crate::src::tokenize_position::{POSITION_SIDE_CREATOR, POSITION_SIDE_TAKER};
+
+/// Burn the winning side's token of a settled escrow for the pot
+///
+/// Settlement holds the pot in the escrow when the winning side is
+/// tokenized; whoever holds the token then burns it here to collect.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Completed escrow
+/// * `[writable]` mint: Mint of the side's token
+/// * `[writable]` token_account: Token account holding the token
+/// * `[writable, signer]` holder: Owner of the token account
+/// * `[]` token_program: SPL Token program
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the escrow has not been settled
+/// * `InvalidPositionToken` - If the side is not tokenized with this mint,
+///   or the token account does not hold its token
+/// * `InvalidWinner` - If the side did not win
+pub fn redeem_position_token(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+    side: u8,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let token_account_info = next_account_info(account_info_iter)?;
+    let holder_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, &creator, counter)?;
+
+    if !fee_payer_info.is_signer || !holder_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if *token_program_info.key != TOKEN_PROGRAM_ID {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_COMPLETED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    let (party, position_mint) = match side {
+        POSITION_SIDE_CREATOR => (Some(escrow.data.creator), escrow.data.position_tokens.creator_mint),
+        POSITION_SIDE_TAKER => (escrow.data.taker, escrow.data.position_tokens.taker_mint),
+        _ => return Err(NescrowError::InvalidPositionToken.into()),
+    };
+
+    if position_mint != Some(*mint_info.key) {
+        return Err(NescrowError::InvalidPositionToken.into());
+    }
+
+    if party.is_none() || party != escrow.data.winner {
+        return Err(NescrowError::InvalidWinner.into());
+    }
+
+    let token_account = TokenAccount::load(token_account_info)?;
+    if token_account.mint != *mint_info.key || token_account.owner != *holder_info.key || token_account.amount != 1 {
+        return Err(NescrowError::InvalidPositionToken.into());
+    }
+
+    invoke(
+        &spl_token::burn(token_account_info.key, mint_info.key, holder_info.key, 1),
+        &[token_account_info.clone(), mint_info.clone(), holder_info.clone(), token_program_info.clone()],
+    )?;
+
+    let payout = escrow.data.position_tokens.held;
+    escrow.data.position_tokens.held = 0;
+
+    **escrow_info.try_borrow_mut_lamports()? -= payout;
+    **holder_info.try_borrow_mut_lamports()? += payout;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Redeemed side {} for {} lamports to {}", side, payout, holder_info.key);
+    Ok(())
+}
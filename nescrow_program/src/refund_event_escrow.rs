@@ -0,0 +1,117 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::{load_escrow, refund_stakes};
+use crate::src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_CANCELLED};
+use crate::src::create_event::load_event;
+use crate::src::oracle::ORACLE_KIND_EVENT;
+use crate::src::tokenize_position::is_tokenized;
+
+/// Refund the stakes of an event escrow whose result neither side backed
+///
+/// An event can report any of its outcomes, so an escrow between two of
+/// them cannot be settled when a third one happens. Each party gets back
+/// their stake. An escrow taken through fills goes back to its creator
+/// and is marked cancelled, and each taker reclaims their fill with
+/// `ClaimFill`. Anyone can refund, since each stake goes back to its
+/// party.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to refund
+/// * `[]` event: Event the escrow is settled by
+/// * `[writable]` creator: Creator of the escrow
+/// * `[writable]` taker: Taker of the escrow, unused if taken through fills
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If the fee payer is not a signer
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the escrow is not in Accepted status
+/// * `NoOracle` - If the escrow is not settled by an event result
+/// * `InvalidPositionToken` - If a side of the escrow is tokenized
+/// * `EventNotReported` - If the event has no result yet
+/// * `InvalidEventOutcome` - If the result is one of the outcomes backed
+pub fn refund_event_escrow(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let event_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let taker_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, &creator, counter)?;
+
+    if !fee_payer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    if escrow.data.oracle_kind != ORACLE_KIND_EVENT {
+        return Err(NescrowError::NoOracle.into());
+    }
+
+    // Stakes go back to the parties' keys, which may no longer hold a tokenized side
+    if is_tokenized(&escrow.data) {
+        return Err(NescrowError::InvalidPositionToken.into());
+    }
+
+    if *event_info.key != escrow.data.oracle_account {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    let condition = escrow.data.event_condition.as_ref().ok_or(NescrowError::NoOracle)?;
+    let event = load_event(program_id, event_info)?;
+    let result = event.data.result.ok_or(NescrowError::EventNotReported)?;
+
+    if result == condition.creator_outcome || result == condition.taker_outcome {
+        return Err(NescrowError::InvalidEventOutcome.into());
+    }
+
+    match escrow.data.fills.as_ref() {
+        Some(fills) => {
+            if *creator_info.key != escrow.data.creator {
+                return Err(NescrowError::NotExpectedAddress.into());
+            }
+
+            let rent_minimum_balance = Rent::get()?.minimum_balance(escrow_info.data_len());
+            let creator_stake = escrow_info
+                .lamports()
+                .checked_sub(rent_minimum_balance)
+                .and_then(|pot| pot.checked_sub(fills.filled))
+                .ok_or(ProgramError::InsufficientFunds)?;
+
+            **escrow_info.try_borrow_mut_lamports()? -= creator_stake;
+            **creator_info.try_borrow_mut_lamports()? += creator_stake;
+
+            escrow.data.status = ESCROW_STATUS_CANCELLED;
+
+            msg!("Refunded {} lamports to the creator, fills can be reclaimed", creator_stake);
+        }
+        None => refund_stakes(&mut escrow.data, escrow_info, creator_info, taker_info)?,
+    }
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Escrow {} refunded, event result {} was backed by neither side", escrow_info.key, result);
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::ConditionNode;
+use crate::src::complete_escrow::{load_escrow, refund_stakes};
+use crate::src::conditions::{self, ConditionContext};
+use crate::src::create_escrow::ESCROW_STATUS_ACCEPTED;
+use crate::src::set_release_conditions::load_release_conditions;
+use crate::src::tokenize_position::is_tokenized;
+
+/// Refund both stakes of a parlay with a void leg
+///
+/// A leg is void once it completed without a winner, was cancelled or was
+/// closed. Every escrow referenced by either tree is passed after the fixed
+/// accounts and must be final, so a parlay is only voided once all of its
+/// legs are known. Anyone can refund, since each stake goes back to its
+/// party.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to refund
+/// * `[]` conditions: Release conditions of the escrow
+/// * `[writable]` creator: Creator of the escrow
+/// * `[writable]` taker: Taker of the escrow
+/// * `[]` remaining: Escrow accounts referenced by either tree
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If the fee payer is not a signer
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the escrow is not in Accepted status
+/// * `InvalidPositionToken` - If a side of the escrow is tokenized
+/// * `InvalidConditions` - If the escrow is a stream or recurring payment
+/// * `LegNotSettled` - If an escrow referenced by either tree is missing or unsettled
+/// * `ConditionsNotMet` - If no leg is void
+pub fn refund_parlay(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let conditions_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let taker_info = next_account_info(account_info_iter)?;
+    let remaining_accounts = account_info_iter.as_slice();
+
+    let escrow = &mut load_escrow(program_id, escrow_info, &creator, counter)?;
+    let release_conditions =
+        load_release_conditions(program_id, conditions_info, escrow_info.key, escrow.data.created_slot)?;
+
+    if !fee_payer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    // Stakes go back to the parties' keys, which may no longer hold a tokenized side
+    if is_tokenized(&escrow.data) {
+        return Err(NescrowError::InvalidPositionToken.into());
+    }
+
+    // Their pot is paid out period by period, never refunded at once
+    if escrow.data.stream.is_some() || escrow.data.schedule.is_some() {
+        return Err(NescrowError::InvalidConditions.into());
+    }
+
+    let context = ConditionContext {
+        program_id,
+        now: Clock::get()?.unix_timestamp,
+        revealed_hashes: vec![],
+        accounts: remaining_accounts,
+    };
+
+    let trees: [&[ConditionNode]; 2] = [&release_conditions.data.creator_wins, &release_conditions.data.taker_wins];
+    if !conditions::has_void_leg(&trees, &context)? {
+        return Err(NescrowError::ConditionsNotMet.into());
+    }
+
+    refund_stakes(&mut escrow.data, escrow_info, creator_info, taker_info)?;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Parlay {} refunded for a void leg", escrow_info.key);
+    Ok(())
+}
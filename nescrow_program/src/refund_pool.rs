@@ -0,0 +1,64 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::create_pool::{load_pool, POOL_STATUS_OPEN, POOL_STATUS_REFUNDED};
+
+/// Refund everyone in a pool its organizer did not settle in time
+///
+/// Once the settlement deadline has passed the organizer can no longer
+/// name winners, and the pool is marked refunded as if settled with none.
+/// Each participant then claims their deposit back with `ClaimPool`.
+/// Anyone can refund, since each deposit goes back to its participant.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` pool: Pool to refund
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If the fee payer is not a signer
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `PoolNotOpen` - If the pool has already been settled
+/// * `SettlementWindowOpen` - If the settlement deadline has not passed yet
+pub fn refund_pool(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    organizer: Pubkey,
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let pool_info = next_account_info(account_info_iter)?;
+
+    let pool = &mut load_pool(program_id, pool_info, &organizer, counter)?;
+
+    if !fee_payer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if pool.data.status != POOL_STATUS_OPEN {
+        return Err(NescrowError::PoolNotOpen.into());
+    }
+
+    // The organizer may settle up to and including the deadline
+    if Clock::get()?.unix_timestamp <= pool.data.settle_deadline {
+        return Err(NescrowError::SettlementWindowOpen.into());
+    }
+
+    pool.data.status = POOL_STATUS_REFUNDED;
+
+    pool.data.serialize(&mut &mut pool_info.data.borrow_mut()[..])?;
+
+    msg!("Pool {} refunded after its settlement deadline", pool_info.key);
+    Ok(())
+}
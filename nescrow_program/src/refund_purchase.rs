@@ -0,0 +1,74 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::create_purchase::{
+    load_purchase, PURCHASE_STATUS_DISPUTED, PURCHASE_STATUS_PAID, PURCHASE_STATUS_REFUNDED,
+    PURCHASE_STATUS_SHIPPED,
+};
+
+/// Return the payment of a purchase to the buyer
+///
+/// The seller may refund at any point before the payment is released,
+/// which is how a dispute is settled in the buyer's favour. The buyer may
+/// only cancel while the order has not shipped.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` purchase: Purchase holding the payment
+/// * `[signer]` party: Seller, or the buyer before shipping
+/// * `[writable]` buyer: Buyer of the goods
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `InvalidAuthority` - If the signer is neither the seller nor the buyer
+/// * `InvalidPurchaseStatus` - If the payment was already released or
+///   refunded, or the buyer cancels after shipping
+pub fn refund_purchase(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let purchase_info = next_account_info(account_info_iter)?;
+    let party_info = next_account_info(account_info_iter)?;
+    let buyer_info = next_account_info(account_info_iter)?;
+
+    let purchase = &mut load_purchase(program_id, purchase_info, buyer_info.key, counter)?;
+
+    if !fee_payer_info.is_signer || !party_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let refundable = if *party_info.key == purchase.data.seller {
+        vec![PURCHASE_STATUS_PAID, PURCHASE_STATUS_SHIPPED, PURCHASE_STATUS_DISPUTED]
+    } else if *party_info.key == purchase.data.buyer {
+        vec![PURCHASE_STATUS_PAID]
+    } else {
+        return Err(NescrowError::InvalidAuthority.into());
+    };
+
+    if !refundable.contains(&purchase.data.status) {
+        return Err(NescrowError::InvalidPurchaseStatus.into());
+    }
+
+    **purchase_info.try_borrow_mut_lamports()? -= purchase.data.amount;
+    **buyer_info.try_borrow_mut_lamports()? += purchase.data.amount;
+
+    purchase.data.status = PURCHASE_STATUS_REFUNDED;
+
+    purchase.data.serialize(&mut &mut purchase_info.data.borrow_mut()[..])?;
+
+    msg!("Purchase refunded, {} lamports returned to {}", purchase.data.amount, buyer_info.key);
+    Ok(())
+}
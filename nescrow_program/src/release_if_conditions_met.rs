@@ -0,0 +1,111 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::{ConditionNode, ReleaseConditions};
+use crate::src::complete_escrow::{load_escrow, pay_out};
+use crate::src::conditions::{self, ConditionContext, MAX_PREIMAGE_LEN};
+use crate::src::create_escrow::ESCROW_STATUS_ACCEPTED;
+use crate::src::set_release_conditions::load_release_conditions;
+
+/// Release the pot to a party whose condition tree is met
+///
+/// Anyone can release. Signers, event and escrow accounts referenced by the
+/// tree are passed after the fixed accounts, and preimages for hash
+/// conditions in the instruction data. If both trees are met, the first
+/// release wins. A parlay with a void leg in either tree is never released,
+/// only refunded through `RefundParlay`.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to settle
+/// * `[]` conditions: Release conditions of the escrow
+/// * `[writable]` winner: The party whose tree is evaluated
+/// * `[]` remaining: Signers, event and escrow accounts referenced by the tree
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If the fee payer is not a signer
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the escrow is not in Accepted status
+/// * `InvalidWinner` - If the winner is not a party to the escrow
+/// * `InvalidConditions` - If too many or too long preimages are given, or
+///   the escrow is a stream or recurring payment
+/// * `ConditionsNotMet` - If the winner's tree is not met
+/// * `LegNotSettled` - If an escrow referenced by either tree is missing or unsettled
+/// * `LegVoid` - If an escrow referenced by either tree is void
+pub fn release_if_conditions_met(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+    preimages: Vec<Vec<u8>>,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let conditions_info = next_account_info(account_info_iter)?;
+    let winner_info = next_account_info(account_info_iter)?;
+    let remaining_accounts = account_info_iter.as_slice();
+
+    let escrow = &mut load_escrow(program_id, escrow_info, &creator, counter)?;
+    let release_conditions =
+        load_release_conditions(program_id, conditions_info, escrow_info.key, escrow.data.created_slot)?;
+
+    if !fee_payer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    // Their pot is paid out period by period, never released at once
+    if escrow.data.stream.is_some() || escrow.data.schedule.is_some() {
+        return Err(NescrowError::InvalidConditions.into());
+    }
+
+    let tree = if *winner_info.key == escrow.data.creator {
+        &release_conditions.data.creator_wins
+    } else if Some(*winner_info.key) == escrow.data.taker {
+        &release_conditions.data.taker_wins
+    } else {
+        return Err(NescrowError::InvalidWinner.into());
+    };
+
+    if preimages.len() > ReleaseConditions::MAX_NODES
+        || preimages.iter().any(|preimage| preimage.len() > MAX_PREIMAGE_LEN)
+    {
+        return Err(NescrowError::InvalidConditions.into());
+    }
+
+    let context = ConditionContext {
+        program_id,
+        now: Clock::get()?.unix_timestamp,
+        revealed_hashes: preimages.iter().map(|preimage| conditions::preimage_hash(preimage)).collect(),
+        accounts: remaining_accounts,
+    };
+
+    let trees: [&[ConditionNode]; 2] = [&release_conditions.data.creator_wins, &release_conditions.data.taker_wins];
+    if conditions::has_void_leg(&trees, &context)? {
+        return Err(NescrowError::LegVoid.into());
+    }
+
+    if !conditions::evaluate(tree, &context)? {
+        return Err(NescrowError::ConditionsNotMet.into());
+    }
+
+    pay_out(&mut escrow.data, escrow_info, winner_info)?;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
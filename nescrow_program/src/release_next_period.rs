@@ -0,0 +1,80 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED};
+use crate::src::create_recurring_payment::elapsed_periods;
+
+/// Release the next tranche of a recurring payment to its payee
+///
+/// Anyone can release, one tranche per call, once the boundary of the period
+/// it pays for has passed. Releasing the last tranche completes the escrow.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account of the recurring payment
+/// * `[writable]` payee: Payee of the recurring payment
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If the fee payer is not a signer
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the payment was cancelled or fully released
+/// * `NotRecurring` - If the escrow is not a recurring payment
+/// * `PeriodNotElapsed` - If the next period boundary has not passed
+pub fn release_next_period(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let payee_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, &creator, counter)?;
+
+    if !fee_payer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    if Some(*payee_info.key) != escrow.data.taker {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    let schedule = escrow.data.schedule.as_mut().ok_or(NescrowError::NotRecurring)?;
+
+    if elapsed_periods(schedule, Clock::get()?.unix_timestamp) <= schedule.released {
+        return Err(NescrowError::PeriodNotElapsed.into());
+    }
+
+    **escrow_info.try_borrow_mut_lamports()? -= schedule.tranche;
+    **payee_info.try_borrow_mut_lamports()? += schedule.tranche;
+
+    schedule.released += 1;
+    msg!("Released tranche {} of {}", schedule.released, schedule.periods);
+
+    if schedule.released == schedule.periods {
+        escrow.data.status = ESCROW_STATUS_COMPLETED;
+        escrow.data.winner = Some(*payee_info.key);
+    }
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
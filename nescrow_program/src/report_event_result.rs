@@ -0,0 +1,77 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::create_event::load_event;
+
+/// Post the final result of an event
+///
+/// Only the event's reporter can post, only once, and not before the event
+/// has started. Escrows backed by the event can then be resolved by anyone.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` event: Event account to report on
+/// * `[signer]` reporter: Reporter named by the event
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `InvalidAuthority` - If the signer is not the event's reporter
+/// * `EventNotStarted` - If the event start time has not been reached
+/// * `EventAlreadyReported` - If a result was already posted
+/// * `InvalidEventOutcome` - If the outcome index is out of range
+pub fn report_event_result(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    event_id: u64,
+    outcome: u8,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let event_info = next_account_info(account_info_iter)?;
+    let reporter_info = next_account_info(account_info_iter)?;
+
+    let event = &mut load_event(program_id, event_info)?;
+
+    if !fee_payer_info.is_signer || !reporter_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if event.data.event_id != event_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if *reporter_info.key != event.data.reporter {
+        return Err(NescrowError::InvalidAuthority.into());
+    }
+
+    if Clock::get()?.unix_timestamp < event.data.start_time {
+        return Err(NescrowError::EventNotStarted.into());
+    }
+
+    if event.data.result.is_some() {
+        return Err(NescrowError::EventAlreadyReported.into());
+    }
+
+    if outcome as usize >= event.data.outcomes.len() {
+        return Err(NescrowError::InvalidEventOutcome.into());
+    }
+
+    event.data.result = Some(outcome);
+
+    event.data.serialize(&mut &mut event_info.data.borrow_mut()[..])?;
+
+    msg!("Event {} result: {}", event_id, event.data.outcomes[outcome as usize]);
+    Ok(())
+}
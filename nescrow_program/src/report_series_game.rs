@@ -0,0 +1,64 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::Outcome;
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::ESCROW_STATUS_ACCEPTED;
+use crate::src::series;
+
+/// Record the result of the next game of a series, confirmed by both parties
+///
+/// The escrow is paid out as soon as one side has won a majority of the
+/// series' games.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account played as a series
+/// * `[writable, signer]` creator: Creator of the escrow
+/// * `[writable, signer]` taker: Taker of the escrow
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the escrow is not in Accepted status
+/// * `InvalidSeries` - If the escrow is not a series
+pub fn report_series_game(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+    outcome: Outcome,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let taker_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, creator_info.key, counter)?;
+
+    if !fee_payer_info.is_signer || !creator_info.is_signer || !taker_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if Some(*taker_info.key) != escrow.data.taker {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    series::record_game(&mut escrow.data, escrow_info, creator_info, taker_info, outcome)?;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
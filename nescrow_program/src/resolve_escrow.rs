@@ -0,0 +1,82 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::{Escrow, Outcome};
+use crate::src::complete_escrow::{load_escrow, pay_out};
+use crate::src::create_escrow::ESCROW_STATUS_ACCEPTED;
+use crate::src::oracle::read_outcome;
+
+/// Settle the escrow from the outcome reported by its oracle
+///
+/// The oracle account is interpreted according to the escrow's oracle kind,
+/// see [`crate::src::oracle`]. Neither party needs to sign.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to settle
+/// * `[]` oracle: Instructions sysvar for attestations, the feed for prices
+/// * `[writable]` winner: The party named by the outcome
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If the fee payer is not a signer
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `WrongAccountOwner` - If a price feed is not owned by the Pyth oracle program
+/// * `EscrowNotAccepted` - If the escrow is not in Accepted status
+/// * `NoOracle` - If the escrow was created without a supported oracle
+/// * `InvalidSeries` - If the escrow is a series, settled game by game
+/// * `InvalidWinner` - If the winner does not match the outcome
+/// * Any error of the oracle kind, if it cannot report an outcome yet
+pub fn resolve_escrow(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let oracle_info = next_account_info(account_info_iter)?;
+    let winner_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, &creator, counter)?;
+
+    if !fee_payer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    if escrow.data.series.is_some() {
+        return Err(NescrowError::InvalidSeries.into());
+    }
+
+    let outcome = read_outcome(program_id, oracle_info, escrow_info.key, &escrow.data)?;
+
+    if Some(*winner_info.key) != outcome_winner(&escrow.data, outcome) {
+        return Err(NescrowError::InvalidWinner.into());
+    }
+
+    pay_out(&mut escrow.data, escrow_info, winner_info)?;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+/// The party paid out for `outcome`
+pub fn outcome_winner(escrow: &Escrow, outcome: Outcome) -> Option<Pubkey> {
+    match outcome {
+        Outcome::CreatorWins => Some(escrow.creator),
+        Outcome::TakerWins => escrow.taker,
+    }
+}
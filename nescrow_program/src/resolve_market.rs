@@ -0,0 +1,86 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::create_event::load_event;
+use crate::src::create_market::{
+    load_market, MARKET_STATUS_OPEN, MARKET_STATUS_REFUNDED, MARKET_STATUS_RESOLVED,
+};
+
+/// Resolve a market from its event's reported result
+///
+/// The creator's fee is taken from the whole pool and the rest is left for
+/// winning positions to claim. If nobody staked on the winning outcome the
+/// market is refunded instead: no fee is charged and every position gets
+/// its stake back. Anyone may resolve a market.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` market: Market to resolve
+/// * `[]` event: Event the market is on
+/// * `[writable]` creator: Creator of the market, receiving the fee
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `MarketClosed` - If the market was already resolved
+/// * `EventNotReported` - If the event has no result yet
+pub fn resolve_market(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let market_info = next_account_info(account_info_iter)?;
+    let event_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+
+    let market = &mut load_market(program_id, market_info, creator_info.key, counter)?;
+    let event = load_event(program_id, event_info)?;
+
+    if !fee_payer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if *event_info.key != market.data.event {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if market.data.status != MARKET_STATUS_OPEN {
+        return Err(NescrowError::MarketClosed.into());
+    }
+
+    let result = event.data.result.ok_or(NescrowError::EventNotReported)?;
+    let total: u64 = market.data.outcome_totals.iter().sum();
+    let winning_stake = market.data.outcome_totals[result as usize];
+
+    market.data.winning_outcome = Some(result);
+
+    if winning_stake == 0 {
+        market.data.status = MARKET_STATUS_REFUNDED;
+        msg!("No stake on outcome {}, market refunded", result);
+    } else {
+        let fee = (total as u128 * market.data.fee_bps as u128 / 10_000) as u64;
+
+        **market_info.try_borrow_mut_lamports()? -= fee;
+        **creator_info.try_borrow_mut_lamports()? += fee;
+
+        market.data.status = MARKET_STATUS_RESOLVED;
+        market.data.payout_pool = total - fee;
+        market.data.unclaimed_winning_stake = winning_stake;
+        msg!("Market resolved on outcome {}, fee of {} lamports", result, fee);
+    }
+
+    market.data.serialize(&mut &mut market_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::ESCROW_STATUS_ACCEPTED;
+use crate::src::oracle::{self, ORACLE_KIND_ATTESTATION};
+use crate::src::series;
+
+/// Record the result of the next game of a series from an oracle attestation
+///
+/// The oracle signs the message built by
+/// [`oracle::series_attestation_message`], which names the game so an
+/// attestation cannot be replayed for a later one, and the escrow's creation
+/// slot so it cannot be replayed for a later escrow. Neither party needs to
+/// sign, and the escrow is paid out as soon as one side has won a majority.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account played as a series
+/// * `[]` oracle: Instructions sysvar holding the attestation
+/// * `[writable]` creator: Creator of the escrow
+/// * `[writable]` taker: Taker of the escrow
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If the fee payer is not a signer
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the escrow is not in Accepted status
+/// * `InvalidSeries` - If the escrow is not a series
+/// * `NoOracle` - If the escrow has no attestation oracle
+/// * `MissingAttestation` / `InvalidAttestation` - If the game is not attested
+pub fn resolve_series_game(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let oracle_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let taker_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, &creator, counter)?;
+
+    if !fee_payer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if *creator_info.key != escrow.data.creator || Some(*taker_info.key) != escrow.data.taker {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    let game = escrow
+        .data
+        .series
+        .as_ref()
+        .ok_or(NescrowError::InvalidSeries)?
+        .results
+        .len() as u8;
+
+    if escrow.data.oracle_kind != ORACLE_KIND_ATTESTATION {
+        return Err(NescrowError::NoOracle.into());
+    }
+
+    let mut subject = oracle::attestation_subject(escrow_info.key, escrow.data.created_slot);
+    subject.push(game);
+    let outcome = oracle::attested_outcome(oracle_info, &escrow.data.oracle_account, &subject)?;
+
+    series::record_game(&mut escrow.data, escrow_info, creator_info, taker_info, outcome)?;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
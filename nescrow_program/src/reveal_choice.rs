@@ -0,0 +1,96 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::ESCROW_STATUS_ACCEPTED;
+use crate::src::game;
+
+/// Reveal the choice a party committed to in a game
+///
+/// The choice and salt must hash to the party's commitment, see
+/// [`game::commitment`]. Reveals are only accepted until the reveal deadline.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account of the game
+/// * `[signer]` party: Creator or taker revealing their choice
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the escrow is not in Accepted status
+/// * `NotAGame` - If the escrow is not a game
+/// * `InvalidAuthority` - If the signer is not a party to the escrow
+/// * `RevealDeadlinePassed` - If the reveal deadline has passed
+/// * `AlreadyRevealed` - If the party already revealed
+/// * `InvalidChoice` - If the choice is not a valid move
+/// * `InvalidCommitment` - If the choice and salt don't match the commitment
+pub fn reveal_choice(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+    choice: u8,
+    salt: [u8; 32],
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let party_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, &creator, counter)?;
+
+    if !fee_payer_info.is_signer || !party_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    let is_creator = *party_info.key == escrow.data.creator;
+    let is_taker = Some(*party_info.key) == escrow.data.taker;
+    let game = escrow.data.game.as_mut().ok_or(NescrowError::NotAGame)?;
+
+    let (commitment, revealed) = if is_creator {
+        (game.creator_commitment, &mut game.creator_choice)
+    } else if is_taker {
+        (game.taker_commitment, &mut game.taker_choice)
+    } else {
+        return Err(NescrowError::InvalidAuthority.into());
+    };
+
+    if Clock::get()?.unix_timestamp > game.reveal_deadline {
+        return Err(NescrowError::RevealDeadlinePassed.into());
+    }
+
+    if revealed.is_some() {
+        return Err(NescrowError::AlreadyRevealed.into());
+    }
+
+    if !game::is_valid_choice(game.game_kind, choice) {
+        return Err(NescrowError::InvalidChoice.into());
+    }
+
+    if game::commitment(choice, &salt, party_info.key) != commitment {
+        return Err(NescrowError::InvalidCommitment.into());
+    }
+
+    *revealed = Some(choice);
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("{} revealed choice {}", party_info.key, choice);
+    Ok(())
+}
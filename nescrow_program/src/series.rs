@@ -0,0 +1,48 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::{Escrow, Outcome, Series};
+use crate::src::complete_escrow::pay_out;
+
+/// Whether a series can be played over `length` games
+///
+/// Lengths are odd so that every series ends with a majority.
+pub fn is_valid_length(length: u8) -> bool {
+    length % 2 == 1 && length <= Series::MAX_LENGTH
+}
+
+/// Side that has won a majority of the series' games, if any
+pub fn winner(series: &Series) -> Option<Outcome> {
+    let majority = series.length as usize / 2 + 1;
+
+    [Outcome::CreatorWins, Outcome::TakerWins]
+        .into_iter()
+        .find(|side| series.results.iter().filter(|result| *result == side).count() >= majority)
+}
+
+/// Record the next game of the escrow's series, paying out once one side
+/// has won a majority
+///
+/// `creator_info` and `taker_info` must already be checked against the escrow.
+pub fn record_game(
+    escrow: &mut Escrow,
+    escrow_info: &AccountInfo,
+    creator_info: &AccountInfo,
+    taker_info: &AccountInfo,
+    outcome: Outcome,
+) -> ProgramResult {
+    let series = escrow.series.as_mut().ok_or(NescrowError::InvalidSeries)?;
+    series.results.push(outcome);
+
+    msg!("Game {} of {} won by {:?}", series.results.len(), series.length, outcome);
+
+    match winner(series) {
+        Some(Outcome::CreatorWins) => pay_out(escrow, escrow_info, creator_info),
+        Some(Outcome::TakerWins) => pay_out(escrow, escrow_info, taker_info),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,166 @@
+use std::str::FromStr;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::create_account,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::{AccountPDA, ConditionNode, ReleaseConditions};
+use crate::src::complete_escrow::load_escrow;
+use crate::src::conditions;
+use crate::src::create_escrow::ESCROW_STATUS_ACCEPTED;
+
+/// Attach release condition trees to an accepted escrow
+///
+/// Both parties sign, so neither can impose conditions the other has not
+/// seen. The conditions can only be set once per escrow, after which the
+/// trees cannot change; conditions left behind by a closed escrow at the
+/// same address are replaced. Either tree may be empty, in which case that
+/// party can only be paid through the escrow's other settlement paths.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[]` escrow: Escrow account the conditions apply to
+/// * `[writable]` conditions: Release conditions account to be created
+/// * `[signer]` creator: Creator of the escrow
+/// * `[signer]` taker: Taker of the escrow
+/// * `[]` system_program: System program for account creation
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the escrow is not in Accepted status
+/// * `InvalidAuthority` - If the taker is not the escrow's taker
+/// * `InvalidConditions` - If either tree is malformed or too large, the
+///   escrow already has conditions, or it is a stream or recurring payment
+pub fn set_release_conditions(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+    creator_wins: Vec<ConditionNode>,
+    taker_wins: Vec<ConditionNode>,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let conditions_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let taker_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let escrow = load_escrow(program_id, escrow_info, creator_info.key, counter)?;
+
+    // Derive PDA for the conditions account
+    let (conditions_pubkey, conditions_bump) = Pubkey::find_program_address(
+        &[b"conditions", escrow_info.key.as_ref()],
+        program_id,
+    );
+
+    // Validate accounts
+    if !fee_payer_info.is_signer || !creator_info.is_signer || !taker_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    if *conditions_info.key != conditions_pubkey || *system_program_info.key != system_program_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    if Some(*taker_info.key) != escrow.data.taker {
+        return Err(NescrowError::InvalidAuthority.into());
+    }
+
+    // Streams and recurring payments pay out over time, so releasing the
+    // whole pot would undo payments already owed to the taker
+    if escrow.data.stream.is_some() || escrow.data.schedule.is_some() {
+        return Err(NescrowError::InvalidConditions.into());
+    }
+
+    if !conditions::is_valid(&creator_wins) || !conditions::is_valid(&taker_wins) {
+        return Err(NescrowError::InvalidConditions.into());
+    }
+
+    if conditions_info.owner == program_id {
+        // Only conditions of an earlier escrow at this address may be replaced
+        let previous = ReleaseConditions::deserialize(&mut &conditions_info.data.borrow()[..])?;
+        if previous.escrow_created_slot == escrow.data.created_slot {
+            return Err(NescrowError::InvalidConditions.into());
+        }
+    } else {
+        invoke_signed(
+            &create_account(
+                fee_payer_info.key,
+                conditions_info.key,
+                Rent::get()?.minimum_balance(ReleaseConditions::LEN),
+                ReleaseConditions::LEN as u64,
+                program_id,
+            ),
+            &[fee_payer_info.clone(), conditions_info.clone()],
+            &[&[b"conditions", escrow_info.key.as_ref(), &[conditions_bump]]],
+        )?;
+    }
+
+    let release_conditions = ReleaseConditions {
+        escrow: *escrow_info.key,
+        escrow_created_slot: escrow.data.created_slot,
+        creator_wins,
+        taker_wins,
+        conditions_bump,
+    };
+
+    release_conditions.serialize(&mut &mut conditions_info.data.borrow_mut()[..])?;
+
+    msg!("Release conditions set: {}", conditions_pubkey);
+    Ok(())
+}
+
+/// Load the release conditions of `escrow_key` after checking their address, owner and size
+///
+/// The conditions must have been set for the escrow created at
+/// `escrow_created_slot`, not an earlier one closed at the same address.
+pub fn load_release_conditions<'a, 'b>(
+    program_id: &Pubkey,
+    conditions_info: &'a AccountInfo<'b>,
+    escrow_key: &Pubkey,
+    escrow_created_slot: u64,
+) -> Result<AccountPDA<'a, 'b, ReleaseConditions>, ProgramError> {
+    if conditions_info.owner != program_id {
+        return Err(NescrowError::WrongAccountOwner.into());
+    }
+
+    if conditions_info.data_len() != ReleaseConditions::LEN {
+        return Err(NescrowError::InvalidAccountLen.into());
+    }
+
+    let release_conditions = ReleaseConditions::deserialize(&mut &conditions_info.data.borrow()[..])?;
+
+    let conditions_pubkey = Pubkey::create_program_address(
+        &[b"conditions", escrow_key.as_ref(), &[release_conditions.conditions_bump]],
+        program_id,
+    )?;
+
+    if *conditions_info.key != conditions_pubkey
+        || release_conditions.escrow != *escrow_key
+        || release_conditions.escrow_created_slot != escrow_created_slot
+    {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    let conditions_bump = release_conditions.conditions_bump;
+    Ok(AccountPDA::new(conditions_info, release_conditions, conditions_bump))
+}
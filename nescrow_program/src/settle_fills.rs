@@ -0,0 +1,77 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::Outcome;
+use crate::src::complete_escrow::{load_escrow, pay_out};
+use crate::src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED};
+use crate::src::oracle::read_outcome;
+
+/// Settle an escrow taken through fills from the outcome reported by its oracle
+///
+/// If the creator wins the whole pot is paid out at once. Otherwise the
+/// escrow is completed without a winner and each taker collects their
+/// share with `ClaimFill`. Neither party needs to sign.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to settle
+/// * `[]` oracle: Instructions sysvar for attestations, the feed for prices
+/// * `[writable]` creator: Creator of the escrow
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If the fee payer is not a signer
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the escrow is not in Accepted status
+/// * `InvalidFill` - If the escrow is not taken through fills
+/// * Any error of the oracle kind, if it cannot report an outcome yet
+pub fn settle_fills(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let oracle_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, creator_info.key, counter)?;
+
+    if !fee_payer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    if escrow.data.fills.is_none() {
+        return Err(NescrowError::InvalidFill.into());
+    }
+
+    match read_outcome(program_id, oracle_info, escrow_info.key, &escrow.data)? {
+        Outcome::CreatorWins => pay_out(&mut escrow.data, escrow_info, creator_info)?,
+        Outcome::TakerWins => {
+            if let Some(fills) = escrow.data.fills.as_mut() {
+                fills.unclaimed = fills.filled;
+            }
+            escrow.data.status = ESCROW_STATUS_COMPLETED;
+            escrow.data.winner = None;
+
+            msg!("Takers won, fills can be claimed");
+        }
+    }
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::Outcome;
+use crate::src::complete_escrow::{load_escrow, pay_out, refund_stakes};
+use crate::src::create_escrow::ESCROW_STATUS_ACCEPTED;
+use crate::src::game;
+
+/// Settle a game once both choices are revealed or the reveal window closes
+///
+/// With both choices revealed the game decides the winner, and a tie refunds
+/// both stakes. After the reveal deadline a party who revealed wins against
+/// one who did not, and if neither revealed both stakes are refunded. Anyone
+/// can settle.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account of the game
+/// * `[writable]` creator: Creator of the escrow
+/// * `[writable]` taker: Taker of the escrow
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If the fee payer is not a signer
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the escrow is not in Accepted status
+/// * `NotAGame` - If the escrow is not a game
+/// * `RevealPending` - If a choice is unrevealed and the deadline has not passed
+pub fn settle_game(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let taker_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, creator_info.key, counter)?;
+
+    if !fee_payer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    if Some(*taker_info.key) != escrow.data.taker {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    let game = escrow.data.game.as_ref().ok_or(NescrowError::NotAGame)?;
+    let deadline_passed = Clock::get()?.unix_timestamp > game.reveal_deadline;
+
+    let outcome = match (game.creator_choice, game.taker_choice) {
+        (Some(creator_choice), Some(taker_choice)) => game::play(game, creator_choice, taker_choice),
+        _ if !deadline_passed => return Err(NescrowError::RevealPending.into()),
+        (Some(_), None) => Some(Outcome::CreatorWins),
+        (None, Some(_)) => Some(Outcome::TakerWins),
+        (None, None) => None,
+    };
+
+    match outcome {
+        Some(Outcome::CreatorWins) => pay_out(&mut escrow.data, escrow_info, creator_info)?,
+        Some(Outcome::TakerWins) => pay_out(&mut escrow.data, escrow_info, taker_info)?,
+        None => refund_stakes(&mut escrow.data, escrow_info, creator_info, taker_info)?,
+    }
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::Outcome;
+use crate::src::complete_escrow::{load_escrow, pay_out, refund_stakes};
+use crate::src::create_escrow::ESCROW_STATUS_ACCEPTED;
+use crate::src::create_event::load_event;
+use crate::src::line;
+
+/// Settle an escrow from its line and the score of its event
+///
+/// The winner follows from the score alone, so neither party needs to sign.
+/// A score landing exactly on the line is a push and refunds both stakes.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account to settle
+/// * `[]` event: Event named by the escrow's oracle
+/// * `[writable]` creator: Creator of the escrow
+/// * `[writable]` taker: Taker of the escrow
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If the fee payer is not a signer
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the escrow is not in Accepted status
+/// * `NoLine` - If the escrow has no line
+/// * `EventNotReported` - If the event's score has not been posted yet
+pub fn settle_line(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let event_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let taker_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, &creator, counter)?;
+
+    if !fee_payer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if *creator_info.key != escrow.data.creator
+        || Some(*taker_info.key) != escrow.data.taker
+        || *event_info.key != escrow.data.oracle_account
+    {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    let line = escrow.data.line.ok_or(NescrowError::NoLine)?;
+    let event = load_event(program_id, event_info)?;
+    let score = event.data.score.ok_or(NescrowError::EventNotReported)?;
+
+    match line::settle(&line, &score) {
+        Some(Outcome::CreatorWins) => pay_out(&mut escrow.data, escrow_info, creator_info)?,
+        Some(Outcome::TakerWins) => pay_out(&mut escrow.data, escrow_info, taker_info)?,
+        None => refund_stakes(&mut escrow.data, escrow_info, creator_info, taker_info)?,
+    }
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
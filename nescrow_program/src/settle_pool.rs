@@ -0,0 +1,101 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::Pool;
+use crate::src::create_pool::{
+    load_participant, load_pool, POOL_STATUS_OPEN, POOL_STATUS_REFUNDED, POOL_STATUS_SETTLED,
+};
+
+/// Settle a pool by naming its winners, or refund everyone with no winners
+///
+/// The organizer acts as the pool's arbiter: winners are whoever the
+/// organizer names, with no oracle or participant vote behind them, so
+/// participants join trusting the organizer to report the result honestly.
+/// The program only guarantees that winners are participants, that the
+/// pool is settled once, and that it is settled after the join deadline,
+/// so nobody can join a pool whose winners are already known. An organizer
+/// who never settles cannot hold the deposits either: past the settlement
+/// deadline the pool can only be refunded, with `RefundPool`.
+///
+/// Funds are not moved here: each participant claims their share or refund
+/// afterwards, so the number of participants is not limited by transaction
+/// size.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` pool: Pool to settle
+/// * `[signer]` organizer: Organizer of the pool
+/// * `[]` remaining: Participant record of each winner, in the same order
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `PoolNotOpen` - If the pool has already been settled
+/// * `JoinWindowOpen` - If the join deadline has not passed yet
+/// * `SettlementWindowClosed` - If the settlement deadline has passed
+/// * `InvalidWinners` - If there are too many or repeated winners, or a
+///   winner's participant record is missing
+pub fn settle_pool(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    counter: u64,
+    winners: Vec<Pubkey>,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let pool_info = next_account_info(account_info_iter)?;
+    let organizer_info = next_account_info(account_info_iter)?;
+    let remaining_accounts = account_info_iter.as_slice();
+
+    let pool = &mut load_pool(program_id, pool_info, organizer_info.key, counter)?;
+
+    if !fee_payer_info.is_signer || !organizer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if pool.data.status != POOL_STATUS_OPEN {
+        return Err(NescrowError::PoolNotOpen.into());
+    }
+
+    // Participants may join up to and including the deadline
+    let now = Clock::get()?.unix_timestamp;
+    if now <= pool.data.join_deadline {
+        return Err(NescrowError::JoinWindowOpen.into());
+    }
+
+    if now > pool.data.settle_deadline {
+        return Err(NescrowError::SettlementWindowClosed.into());
+    }
+
+    if winners.len() > Pool::MAX_WINNERS
+        || remaining_accounts.len() != winners.len()
+        || winners.iter().enumerate().any(|(i, winner)| winners[..i].contains(winner))
+    {
+        return Err(NescrowError::InvalidWinners.into());
+    }
+
+    for (winner, record_info) in winners.iter().zip(remaining_accounts) {
+        load_participant(program_id, record_info, pool_info.key, winner)
+            .map_err(|_| NescrowError::InvalidWinners)?;
+    }
+
+    pool.data.status = if winners.is_empty() { POOL_STATUS_REFUNDED } else { POOL_STATUS_SETTLED };
+    pool.data.unclaimed_winners = winners.len() as u32;
+    pool.data.winners = winners;
+
+    pool.data.serialize(&mut &mut pool_info.data.borrow_mut()[..])?;
+
+    msg!("Pool settled with {} winners", pool.data.winners.len());
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use solana_program::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey,
+    pubkey::Pubkey,
+};
+
+use crate::generated::errors::NescrowError;
+
+/// SPL Token program
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+/// SPL Associated Token Account program
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+/// System program, a required account of associated token account creation
+const SYSTEM_PROGRAM_ID: Pubkey = pubkey!("11111111111111111111111111111111");
+
+/// Length of an SPL Token mint account
+pub const MINT_LEN: usize = 82;
+/// Length of an SPL Token account
+pub const TOKEN_ACCOUNT_LEN: usize = 165;
+
+// SPL Token instruction tags
+const MINT_TO: u8 = 7;
+const BURN: u8 = 8;
+const INITIALIZE_MINT_2: u8 = 20;
+// Associated Token Account instruction tags
+const CREATE_IDEMPOTENT: u8 = 1;
+
+// Offsets into the SPL Token account layout
+const MINT_OFFSET: usize = 0;
+const OWNER_OFFSET: usize = 32;
+const AMOUNT_OFFSET: usize = 64;
+const STATE_OFFSET: usize = 108;
+/// Account state of an initialized, not frozen, token account
+const STATE_INITIALIZED: u8 = 1;
+
+/// Balance of a token account, read from its raw data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenAccount {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+impl TokenAccount {
+    /// Parse an initialized token account owned by the SPL Token program
+    pub fn load(token_account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        if *token_account_info.owner != TOKEN_PROGRAM_ID {
+            return Err(NescrowError::WrongAccountOwner.into());
+        }
+
+        Self::unpack(&token_account_info.data.borrow())
+    }
+
+    /// Parse raw token account data
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != TOKEN_ACCOUNT_LEN || data[STATE_OFFSET] != STATE_INITIALIZED {
+            return Err(NescrowError::InvalidAccountLen.into());
+        }
+
+        Ok(Self {
+            mint: read_pubkey(data, MINT_OFFSET),
+            owner: read_pubkey(data, OWNER_OFFSET),
+            amount: u64::from_le_bytes(data[AMOUNT_OFFSET..AMOUNT_OFFSET + 8].try_into().unwrap()),
+        })
+    }
+}
+
+/// Associated token account of `wallet` for `mint`
+pub fn associated_token_address(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), TOKEN_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Initialize `mint` with no decimals, no freeze authority and `authority`
+/// as its mint authority
+pub fn initialize_mint(mint: &Pubkey, authority: &Pubkey) -> Instruction {
+    let mut data = vec![INITIALIZE_MINT_2, 0];
+    data.extend_from_slice(authority.as_ref());
+    data.push(0);
+
+    Instruction {
+        program_id: TOKEN_PROGRAM_ID,
+        accounts: vec![AccountMeta::new(*mint, false)],
+        data,
+    }
+}
+
+/// Create the associated token account of `wallet` for `mint` unless it exists
+pub fn create_associated_token_account(payer: &Pubkey, wallet: &Pubkey, mint: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(associated_token_address(wallet, mint), false),
+            AccountMeta::new_readonly(*wallet, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: vec![CREATE_IDEMPOTENT],
+    }
+}
+
+/// Mint `amount` of `mint` into `account`
+pub fn mint_to(mint: &Pubkey, account: &Pubkey, authority: &Pubkey, amount: u64) -> Instruction {
+    let mut data = vec![MINT_TO];
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id: TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*mint, false),
+            AccountMeta::new(*account, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data,
+    }
+}
+
+/// Burn `amount` of `mint` from `account`
+pub fn burn(account: &Pubkey, mint: &Pubkey, owner: &Pubkey, amount: u64) -> Instruction {
+    let mut data = vec![BURN];
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id: TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*account, false),
+            AccountMeta::new(*mint, false),
+            AccountMeta::new_readonly(*owner, true),
+        ],
+        data,
+    }
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Pubkey {
+    Pubkey::new_from_array(data[offset..offset + 32].try_into().unwrap())
+}
@@ -0,0 +1,183 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::create_account,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::generated::state::Escrow;
+use crate::src::complete_escrow::{escrow_seed_creator, load_escrow};
+use crate::src::create_escrow::ESCROW_STATUS_ACCEPTED;
+use crate::src::spl_token::{self, ASSOCIATED_TOKEN_PROGRAM_ID, MINT_LEN, TOKEN_PROGRAM_ID};
+
+/// Position side constants
+pub const POSITION_SIDE_CREATOR: u8 = 0;
+pub const POSITION_SIDE_TAKER: u8 = 1;
+
+/// Mint a supply-1 token standing for one side of an accepted escrow
+///
+/// The token is minted into the party's associated token account, with the
+/// escrow as mint authority, and from then on the side is paid to whoever
+/// redeems its token rather than to the party. Sides can only be tokenized
+/// on plain wagers, and instructions that would let the party act for a
+/// tokenized side without its token are refused.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the mint and token account
+/// * `[writable]` escrow: Accepted escrow
+/// * `[signer]` party: Creator or taker holding the side
+/// * `[writable]` mint: Mint of the side's token, derived from the escrow and side
+/// * `[writable]` token_account: Associated token account of the party for the mint
+/// * `[]` system_program: System program for account creation
+/// * `[]` token_program: SPL Token program
+/// * `[]` associated_token_program: SPL Associated Token Account program
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the escrow is not in Accepted status
+/// * `InvalidAuthority` - If the party does not hold the side
+/// * `InvalidPositionToken` - If the side is unknown or already tokenized, or
+///   the escrow is a game, stream, recurring payment, taken through fills or
+///   settled by a line, whose push refunds the parties directly
+pub fn tokenize_position(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+    side: u8,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let party_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let token_account_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let associated_token_program_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, &creator, counter)?;
+
+    // Derive PDA for the mint account
+    let (mint_pubkey, mint_bump) = Pubkey::find_program_address(
+        &[b"position_mint", escrow_info.key.as_ref(), &[side]],
+        program_id,
+    );
+
+    // Validate accounts
+    if !fee_payer_info.is_signer || !party_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if *mint_info.key != mint_pubkey
+        || *token_account_info.key != spl_token::associated_token_address(party_info.key, mint_info.key)
+        || *token_program_info.key != TOKEN_PROGRAM_ID
+        || *associated_token_program_info.key != ASSOCIATED_TOKEN_PROGRAM_ID
+    {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    if escrow.data.game.is_some()
+        || escrow.data.fills.is_some()
+        || escrow.data.stream.is_some()
+        || escrow.data.schedule.is_some()
+        || escrow.data.line.is_some()
+    {
+        return Err(NescrowError::InvalidPositionToken.into());
+    }
+
+    let (holder, position_mint) = match side {
+        POSITION_SIDE_CREATOR => (Some(escrow.data.creator), &mut escrow.data.position_tokens.creator_mint),
+        POSITION_SIDE_TAKER => (escrow.data.taker, &mut escrow.data.position_tokens.taker_mint),
+        _ => return Err(NescrowError::InvalidPositionToken.into()),
+    };
+
+    if holder != Some(*party_info.key) {
+        return Err(NescrowError::InvalidAuthority.into());
+    }
+
+    if position_mint.is_some() {
+        return Err(NescrowError::InvalidPositionToken.into());
+    }
+
+    *position_mint = Some(mint_pubkey);
+
+    // Create the mint, with the escrow as its mint authority
+    invoke_signed(
+        &create_account(
+            fee_payer_info.key,
+            mint_info.key,
+            Rent::get()?.minimum_balance(MINT_LEN),
+            MINT_LEN as u64,
+            &TOKEN_PROGRAM_ID,
+        ),
+        &[fee_payer_info.clone(), mint_info.clone()],
+        &[&[b"position_mint", escrow_info.key.as_ref(), &[side], &[mint_bump]]],
+    )?;
+
+    invoke(
+        &spl_token::initialize_mint(mint_info.key, escrow_info.key),
+        &[mint_info.clone(), token_program_info.clone()],
+    )?;
+
+    // Mint the single token into the party's wallet
+    invoke(
+        &spl_token::create_associated_token_account(fee_payer_info.key, party_info.key, mint_info.key),
+        &[
+            fee_payer_info.clone(),
+            token_account_info.clone(),
+            party_info.clone(),
+            mint_info.clone(),
+            system_program_info.clone(),
+            token_program_info.clone(),
+            associated_token_program_info.clone(),
+        ],
+    )?;
+
+    let seed_creator = escrow_seed_creator(&escrow.data);
+    invoke_signed(
+        &spl_token::mint_to(mint_info.key, token_account_info.key, escrow_info.key, 1),
+        &[mint_info.clone(), token_account_info.clone(), escrow_info.clone(), token_program_info.clone()],
+        &[&[
+            b"escrow",
+            seed_creator.as_ref(),
+            counter.to_le_bytes().as_ref(),
+            &[escrow.bump],
+        ]],
+    )?;
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Side {} of escrow {} tokenized as {}", side, escrow_info.key, mint_pubkey);
+    Ok(())
+}
+
+/// Whether either side of the escrow has been tokenized
+pub fn is_tokenized(escrow: &Escrow) -> bool {
+    escrow.position_tokens.creator_mint.is_some() || escrow.position_tokens.taker_mint.is_some()
+}
+
+/// Mint of the token standing for the side held by `key`, if tokenized
+pub fn position_mint(escrow: &Escrow, key: &Pubkey) -> Option<Pubkey> {
+    if *key == escrow.creator {
+        escrow.position_tokens.creator_mint
+    } else if Some(*key) == escrow.taker {
+        escrow.position_tokens.taker_mint
+    } else {
+        None
+    }
+}
@@ -0,0 +1,104 @@
+use std::str::FromStr;
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    pubkey::Pubkey,
+    system_instruction::transfer,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::{is_party, load_escrow};
+use crate::src::create_escrow::ESCROW_STATUS_ACCEPTED;
+use crate::src::tokenize_position::position_mint;
+
+/// Transfer a party's side of an accepted escrow to another account
+///
+/// The buyer takes over the creator or taker role, with everything it
+/// entitles to, and pays the optional price to the party in the same
+/// transaction. The escrow keeps its address: when the creator's side is
+/// sold, the original creator is kept on record to derive it from.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account whose side is transferred
+/// * `[writable, signer]` party: Creator or taker selling their side
+/// * `[writable, signer]` buyer: New holder of the side
+/// * `[]` system_program: System program for the price transfer
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the escrow is not in Accepted status
+/// * `InvalidAuthority` - If the party is neither the creator nor the taker
+/// * `InvalidTransfer` - If the buyer is already a party to the escrow, the
+///   side is tokenized and trades as its token instead, or the escrow is a
+///   game, stream or recurring payment
+pub fn transfer_position(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+    price: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let party_info = next_account_info(account_info_iter)?;
+    let buyer_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, &creator, counter)?;
+
+    if !fee_payer_info.is_signer || !party_info.is_signer || !buyer_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    let system_program_id = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+    if *system_program_info.key != system_program_id {
+        return Err(NescrowError::NotExpectedAddress.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    if !is_party(&escrow.data, party_info.key) {
+        return Err(NescrowError::InvalidAuthority.into());
+    }
+
+    // A game commitment binds the party's key, and the payee of a stream or
+    // recurring payment holds no side of a wager
+    if is_party(&escrow.data, buyer_info.key)
+        || position_mint(&escrow.data, party_info.key).is_some()
+        || escrow.data.game.is_some()
+        || escrow.data.stream.is_some()
+        || escrow.data.schedule.is_some()
+    {
+        return Err(NescrowError::InvalidTransfer.into());
+    }
+
+    if price > 0 {
+        invoke(
+            &transfer(buyer_info.key, party_info.key, price),
+            &[buyer_info.clone(), party_info.clone(), system_program_info.clone()],
+        )?;
+    }
+
+    if *party_info.key == escrow.data.creator {
+        escrow.data.original_creator.get_or_insert(escrow.data.creator);
+        escrow.data.creator = *buyer_info.key;
+    } else {
+        escrow.data.taker = Some(*buyer_info.key);
+    }
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Position of {} transferred to {} for {} lamports", party_info.key, buyer_info.key, price);
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::generated::errors::NescrowError;
+use crate::src::complete_escrow::load_escrow;
+use crate::src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED};
+use crate::src::create_stream::vested_amount;
+
+/// Pull the vested portion of a stream that has not been withdrawn yet
+///
+/// The escrow is completed once the full amount has been withdrawn.
+///
+/// # Accounts
+///
+/// * `[writable, signer]` fee_payer: Account paying for the transaction
+/// * `[writable]` escrow: Escrow account of the stream
+/// * `[writable, signer]` beneficiary: Beneficiary of the stream
+///
+/// # Errors
+///
+/// * `InvalidSignerPermission` - If required signers are not present
+/// * `NotExpectedAddress` - If account addresses don't match expected values
+/// * `EscrowNotAccepted` - If the stream was cancelled or fully withdrawn
+/// * `NotAStream` - If the escrow is not a stream
+/// * `InvalidAuthority` - If the signer is not the beneficiary
+/// * `NothingToWithdraw` - If nothing new has vested
+pub fn withdraw(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creator: Pubkey,
+    counter: u64,
+) -> ProgramResult {
+    // Parse accounts
+    let account_info_iter = &mut accounts.iter();
+    let fee_payer_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let beneficiary_info = next_account_info(account_info_iter)?;
+
+    let escrow = &mut load_escrow(program_id, escrow_info, &creator, counter)?;
+
+    if !fee_payer_info.is_signer || !beneficiary_info.is_signer {
+        return Err(NescrowError::InvalidSignerPermission.into());
+    }
+
+    if escrow.data.status != ESCROW_STATUS_ACCEPTED {
+        return Err(NescrowError::EscrowNotAccepted.into());
+    }
+
+    if Some(*beneficiary_info.key) != escrow.data.taker {
+        return Err(NescrowError::InvalidAuthority.into());
+    }
+
+    let amount = escrow.data.amount;
+    let stream = escrow.data.stream.as_mut().ok_or(NescrowError::NotAStream)?;
+
+    let vested = vested_amount(amount, stream, Clock::get()?.unix_timestamp);
+    let withdrawable = vested - stream.withdrawn;
+    if withdrawable == 0 {
+        return Err(NescrowError::NothingToWithdraw.into());
+    }
+
+    **escrow_info.try_borrow_mut_lamports()? -= withdrawable;
+    **beneficiary_info.try_borrow_mut_lamports()? += withdrawable;
+
+    stream.withdrawn = vested;
+
+    if vested == amount {
+        escrow.data.status = ESCROW_STATUS_COMPLETED;
+        escrow.data.winner = Some(*beneficiary_info.key);
+    }
+
+    escrow.data.serialize(&mut &mut escrow_info.data.borrow_mut()[..])?;
+
+    msg!("Withdrew {} lamports", withdrawable);
+    Ok(())
+}
@@ -0,0 +1,167 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		counter_offer_account,
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{CounterOffer, Escrow},
+        src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_OPEN},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+const AMOUNT: u64 = 5_000_000;
+const CREATOR_LAMPORTS: u64 = 1_000_000_000;
+const NOW: i64 = 1_700_000_000;
+
+/// Accept an offer of `amount` until `expiry_time` on an open 5 million
+/// lamports escrow. Returns the escrow, its lamports above rent and the
+/// creator's lamports if acceptance succeeded.
+async fn accept_with(amount: u64, expiry_time: i64) -> Option<(Escrow, u64, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+	let taker_pubkey = Pubkey::new_unique();
+	let system_program_pubkey = Pubkey::default();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (counter_offer_pda, counter_offer_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"counter_offer",
+			escrow_pda.as_ref(),
+			taker_pubkey.as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for (pubkey, lamports) in [(fee_payer_pubkey, 1_000_000_000_000), (creator_pubkey, CREATOR_LAMPORTS)] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports,
+				data: vec![],
+				owner: Pubkey::default(),
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator: creator_pubkey,
+				amount: AMOUNT,
+				status: ESCROW_STATUS_OPEN,
+				expiry_time: NOW + 3600,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				..Default::default()
+			},
+			AMOUNT,
+		),
+	);
+
+	program_test.add_account(
+		counter_offer_pda,
+		counter_offer_account(&CounterOffer {
+			escrow: escrow_pda,
+			taker: taker_pubkey,
+			amount,
+			expiry_time,
+			offer_bump: counter_offer_pda_bump,
+		}),
+	);
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: NOW,
+		..Default::default()
+	});
+
+	let ix = nescrow_ix_interface::accept_counter_offer_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		counter_offer_pda,
+		&creator_keypair,
+		taker_pubkey,
+		system_program_pubkey,
+		counter,
+		context.last_blockhash,
+	);
+
+	context.banks_client.process_transaction(ix).await.ok()?;
+
+	assert!(context.banks_client.get_account(counter_offer_pda).await.unwrap().is_none());
+
+	let taker_account = context.banks_client.get_account(taker_pubkey).await.unwrap().unwrap();
+	assert_eq!(taker_account.lamports, Rent::default().minimum_balance(CounterOffer::LEN));
+
+	let escrow_account = context.banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	assert_eq!(escrow.status, ESCROW_STATUS_ACCEPTED);
+	assert_eq!(escrow.taker, Some(taker_pubkey));
+	assert_eq!(escrow.expiry_time, expiry_time);
+
+	let creator_account = context.banks_client.get_account(creator_pubkey).await.unwrap().unwrap();
+	Some((
+		escrow,
+		escrow_account.lamports - Rent::default().minimum_balance(Escrow::LEN),
+		creator_account.lamports,
+	))
+}
+
+#[tokio::test]
+async fn accept_counter_offer_ix_success() {
+	let (escrow, pot, creator_lamports) = accept_with(3_000_000, NOW + 7200).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.amount, 3_000_000);
+	assert_eq!(pot, 6_000_000);
+	assert_eq!(creator_lamports, CREATOR_LAMPORTS + 2_000_000);
+}
+
+#[tokio::test]
+async fn accept_counter_offer_ix_tops_up_creator_stake() {
+	let (escrow, pot, creator_lamports) = accept_with(8_000_000, NOW + 7200).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.amount, 8_000_000);
+	assert_eq!(pot, 16_000_000);
+	assert_eq!(creator_lamports, CREATOR_LAMPORTS - 3_000_000);
+}
+
+#[tokio::test]
+async fn accept_counter_offer_ix_rejects_expired_offer() {
+	// ASSERTIONS
+	assert!(accept_with(3_000_000, NOW).await.is_none());
+}
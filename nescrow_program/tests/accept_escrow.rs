@@ -35,6 +35,7 @@ async fn accept_escrow_ix_success() {
 	// DATA
 	let creator: Pubkey = Pubkey::default();
 	let counter: u64 = Default::default();
+	let commitment: Option<[u8; 32]> = Default::default();
 
 	// KEYPAIR
 	let fee_payer_keypair = Keypair::new();
@@ -44,6 +45,9 @@ async fn accept_escrow_ix_success() {
 	let fee_payer_pubkey = fee_payer_keypair.pubkey();
 	let taker_pubkey = taker_keypair.pubkey();
 
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
 	// PDA
 	let (escrow_pda, _escrow_pda_bump) = Pubkey::find_program_address(
 		&[
@@ -84,8 +88,10 @@ async fn accept_escrow_ix_success() {
 		&fee_payer_keypair,
 		escrow_pda,
 		&taker_keypair,
+		system_program_pubkey,
 		creator,
 		counter,
+		commitment,
 		recent_blockhash,
 	);
 
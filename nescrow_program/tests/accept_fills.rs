@@ -0,0 +1,125 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Fills},
+        src::{
+            create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_OPEN},
+            oracle::ORACLE_KIND_EVENT,
+        },
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const AMOUNT: u64 = 10_000_000;
+const MIN_FILL: u64 = 4_000_000;
+const CREATOR_LAMPORTS: u64 = 1_000_000_000;
+
+/// Accept a 10 million lamports escrow with a minimum fill of 4 million
+/// after `filled` lamports were filled, returning the escrow and the
+/// creator's lamports if acceptance succeeded
+async fn accept_with(filled: u64) -> Option<(Escrow, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for (pubkey, lamports) in [(fee_payer_pubkey, 1_000_000_000_000), (creator_pubkey, CREATOR_LAMPORTS)] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports,
+				data: vec![],
+				owner: Pubkey::default(),
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator: creator_pubkey,
+				amount: AMOUNT,
+				status: ESCROW_STATUS_OPEN,
+				expiry_time: i64::MAX,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				oracle_kind: ORACLE_KIND_EVENT,
+				fills: Some(Fills {
+					min_fill: MIN_FILL,
+					filled,
+					..Default::default()
+				}),
+				..Default::default()
+			},
+			AMOUNT + filled,
+		),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::accept_fills_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&creator_keypair,
+		counter,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let creator_account = banks_client.get_account(creator_pubkey).await.unwrap().unwrap();
+	Some((
+		Escrow::deserialize(&mut &escrow_account.data[..]).unwrap(),
+		creator_account.lamports,
+	))
+}
+
+#[tokio::test]
+async fn accept_fills_ix_success() {
+	let (escrow, creator_lamports) = accept_with(6_000_000).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.status, ESCROW_STATUS_ACCEPTED);
+	assert_eq!(escrow.amount, 6_000_000);
+	assert_eq!(creator_lamports, CREATOR_LAMPORTS + 4_000_000);
+}
+
+#[tokio::test]
+async fn accept_fills_ix_rejects_below_min_fill() {
+	// ASSERTIONS
+	assert!(accept_with(MIN_FILL - 1).await.is_none());
+}
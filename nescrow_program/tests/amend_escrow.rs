@@ -0,0 +1,144 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::Escrow,
+        src::create_escrow::ESCROW_STATUS_ACCEPTED,
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+const BALANCE: u64 = 1_000_000_000;
+
+/// Amend an accepted 2 million lamport escrow, returning the escrow, its
+/// lamports above rent and the creator's and taker's balances
+async fn amend_with(
+	description: Option<String>,
+	amount: Option<u64>,
+	expiry_time: Option<i64>,
+) -> Option<(Escrow, u64, u64, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+	let stake: u64 = 2_000_000;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+	let taker_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+	let taker_pubkey = taker_keypair.pubkey();
+	let system_program_pubkey = Pubkey::default();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for (pubkey, lamports) in [(fee_payer_pubkey, 1_000_000_000_000), (creator_pubkey, BALANCE), (taker_pubkey, BALANCE)] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports,
+				data: vec![],
+				owner: Pubkey::default(),
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator: creator_pubkey,
+				taker: Some(taker_pubkey),
+				amount: stake,
+				status: ESCROW_STATUS_ACCEPTED,
+				description: "Rain on Friday".to_string(),
+				expiry_time: 1_700_000_000,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				..Default::default()
+			},
+			2 * stake,
+		),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::amend_escrow_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&creator_keypair,
+		&taker_keypair,
+		system_program_pubkey,
+		counter,
+		description,
+		amount,
+		expiry_time,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	let pot = escrow_account.lamports - Rent::default().minimum_balance(Escrow::LEN);
+	let creator_account = banks_client.get_account(creator_pubkey).await.unwrap().unwrap();
+	let taker_account = banks_client.get_account(taker_pubkey).await.unwrap().unwrap();
+	Some((escrow, pot, creator_account.lamports, taker_account.lamports))
+}
+
+#[tokio::test]
+async fn amend_escrow_ix_success() {
+	let (escrow, pot, creator_lamports, taker_lamports) =
+		amend_with(Some("Rain on Saturday".to_string()), Some(3_000_000), None).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.description, "Rain on Saturday");
+	assert_eq!(escrow.amount, 3_000_000);
+	assert_eq!(escrow.expiry_time, 1_700_000_000);
+	assert_eq!(escrow.amendments, 1);
+	assert_eq!(pot, 6_000_000);
+	assert_eq!((creator_lamports, taker_lamports), (BALANCE - 1_000_000, BALANCE - 1_000_000));
+}
+
+#[tokio::test]
+async fn amend_escrow_ix_refunds_lowered_amount() {
+	let (escrow, pot, creator_lamports, taker_lamports) =
+		amend_with(None, Some(500_000), Some(1_800_000_000)).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.amount, 500_000);
+	assert_eq!(escrow.expiry_time, 1_800_000_000);
+	assert_eq!(pot, 1_000_000);
+	assert_eq!((creator_lamports, taker_lamports), (BALANCE + 1_500_000, BALANCE + 1_500_000));
+}
+
+#[tokio::test]
+async fn amend_escrow_ix_rejects_empty_amendment() {
+	// ASSERTIONS
+	assert!(amend_with(None, None, None).await.is_none());
+}
@@ -0,0 +1,125 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		get_program_test,
+		milestone_escrow_account,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Milestone, MilestoneEscrow},
+        src::create_milestone_escrow::{
+            MILESTONE_STATUS_DISPUTED, MILESTONE_STATUS_PENDING, MILESTONE_STATUS_RELEASED,
+        },
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+/// Approve milestone `index` of an escrow with milestones of 3 and 7 million
+/// lamports in the given statuses, returning the escrow and the worker's lamports
+async fn approve_with(statuses: [u8; 2], index: u8) -> Option<(MilestoneEscrow, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let client_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let client_pubkey = client_keypair.pubkey();
+	let worker_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestones",
+			client_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		milestone_escrow_account(
+			&MilestoneEscrow {
+				client: client_pubkey,
+				worker: worker_pubkey,
+				counter,
+				milestones: vec![
+					Milestone { amount: 3_000_000, deadline: 100, status: statuses[0], ..Default::default() },
+					Milestone { amount: 7_000_000, deadline: 200, status: statuses[1], ..Default::default() },
+				],
+				escrow_bump: escrow_pda_bump,
+			},
+			10_000_000,
+		),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::approve_milestone_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&client_keypair,
+		worker_pubkey,
+		counter,
+		index,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = MilestoneEscrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	let worker_account = banks_client.get_account(worker_pubkey).await.unwrap().unwrap();
+	Some((escrow, worker_account.lamports))
+}
+
+#[tokio::test]
+async fn approve_milestone_ix_success() {
+	let (escrow, worker_lamports) =
+		approve_with([MILESTONE_STATUS_PENDING, MILESTONE_STATUS_PENDING], 1).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(worker_lamports, 7_000_000);
+	assert_eq!(escrow.milestones[0].status, MILESTONE_STATUS_PENDING);
+	assert_eq!(escrow.milestones[1].status, MILESTONE_STATUS_RELEASED);
+}
+
+#[tokio::test]
+async fn approve_milestone_ix_settles_dispute() {
+	let (escrow, worker_lamports) =
+		approve_with([MILESTONE_STATUS_DISPUTED, MILESTONE_STATUS_PENDING], 0).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(worker_lamports, 3_000_000);
+	assert_eq!(escrow.milestones[0].status, MILESTONE_STATUS_RELEASED);
+}
+
+#[tokio::test]
+async fn approve_milestone_ix_rejects_released_milestone() {
+	// ASSERTIONS
+	assert!(approve_with([MILESTONE_STATUS_RELEASED, MILESTONE_STATUS_PENDING], 0).await.is_none());
+	assert!(approve_with([MILESTONE_STATUS_PENDING, MILESTONE_STATUS_PENDING], 2).await.is_none());
+}
@@ -0,0 +1,118 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		get_program_test,
+		nescrow_ix_interface,
+		purchase_account,
+	},
+    nescrow::{
+        generated::state::Purchase,
+        src::create_purchase::{PURCHASE_STATUS_DISPUTED, PURCHASE_STATUS_RELEASED, PURCHASE_STATUS_SHIPPED},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const INSPECTION_DEADLINE: i64 = 1_700_000_000;
+const AMOUNT: u64 = 5_000_000;
+
+/// Release at `now`, signed by an unrelated fee payer, a purchase in
+/// `status` whose inspection window ends at `INSPECTION_DEADLINE`. Returns
+/// the purchase and the seller's lamports if the release succeeded.
+async fn release_with(status: u8, now: i64) -> Option<(Purchase, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let buyer: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let seller_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (purchase_pda, purchase_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"purchase",
+			buyer.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		purchase_pda,
+		purchase_account(&Purchase {
+			buyer,
+			seller: seller_pubkey,
+			counter,
+			amount: AMOUNT,
+			inspection_window: 86_400,
+			inspection_deadline: INSPECTION_DEADLINE,
+			status,
+			purchase_bump: purchase_pda_bump,
+		}),
+	);
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: now,
+		..Default::default()
+	});
+
+	let ix = nescrow_ix_interface::auto_release_purchase_ix_setup(
+		&fee_payer_keypair,
+		purchase_pda,
+		seller_pubkey,
+		buyer,
+		counter,
+		context.last_blockhash,
+	);
+
+	context.banks_client.process_transaction(ix).await.ok()?;
+
+	let purchase_account = context.banks_client.get_account(purchase_pda).await.unwrap().unwrap();
+	let seller_account = context.banks_client.get_account(seller_pubkey).await.unwrap().unwrap();
+	Some((Purchase::deserialize(&mut &purchase_account.data[..]).unwrap(), seller_account.lamports))
+}
+
+#[tokio::test]
+async fn auto_release_purchase_ix_success() {
+	let (purchase, seller_lamports) = release_with(PURCHASE_STATUS_SHIPPED, INSPECTION_DEADLINE + 1).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(purchase.status, PURCHASE_STATUS_RELEASED);
+	assert_eq!(seller_lamports, AMOUNT);
+}
+
+#[tokio::test]
+async fn auto_release_purchase_ix_waits_for_inspection_window() {
+	// ASSERTIONS
+	assert!(release_with(PURCHASE_STATUS_SHIPPED, INSPECTION_DEADLINE).await.is_none());
+}
+
+#[tokio::test]
+async fn auto_release_purchase_ix_rejects_disputed_purchase() {
+	// ASSERTIONS
+	assert!(release_with(PURCHASE_STATUS_DISPUTED, INSPECTION_DEADLINE + 1).await.is_none());
+}
@@ -1,50 +1,48 @@
 pub mod common;
 
-use std::str::FromStr;
 use {
+    borsh::BorshDeserialize,
     common::{
+		escrow_account,
 		get_program_test,
 		nescrow_ix_interface,
 	},
+    nescrow::{
+        generated::state::{Escrow, Fills},
+        src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_CANCELLED, ESCROW_STATUS_OPEN},
+    },
     solana_program_test::tokio,
     solana_sdk::{
         account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
     },
 };
 
+const AMOUNT: u64 = 2_000_000;
+const CREATOR_LAMPORTS: u64 = 1_000_000_000;
 
-#[tokio::test]
-async fn cancel_escrow_ix_success() {
+/// Cancel an escrow built by `configure`, signed by its creator or by a
+/// stranger. Returns the escrow's status and lamports and the creator's
+/// lamports if the cancellation succeeded.
+async fn cancel_with(
+	configure: impl FnOnce(&mut Escrow),
+	by_creator: bool,
+) -> Option<(u8, u64, u64)> {
 	let mut program_test = get_program_test();
 
-	// PROGRAMS
-	program_test.prefer_bpf(true);
-
-	program_test.add_program(
-		"account_compression",
-		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
-		None,
-	);
-
-	program_test.add_program(
-		"noop",
-		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
-		None,
-	);
-
 	// DATA
 	let counter: u64 = Default::default();
 
 	// KEYPAIR
 	let fee_payer_keypair = Keypair::new();
 	let creator_keypair = Keypair::new();
+	let stranger_keypair = Keypair::new();
 
 	// PUBKEY
 	let fee_payer_pubkey = fee_payer_keypair.pubkey();
 	let creator_pubkey = creator_keypair.pubkey();
 
 	// PDA
-	let (escrow_pda, _escrow_pda_bump) = Pubkey::find_program_address(
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
 		&[
 			b"escrow",
 			creator_pubkey.as_ref(),
@@ -68,7 +66,7 @@ async fn cancel_escrow_ix_success() {
 	program_test.add_account(
 		creator_pubkey,
 		Account {
-			lamports: 1_000_000_000_000,
+			lamports: CREATOR_LAMPORTS,
 			data: vec![],
 			owner: system_program::ID,
 			executable: false,
@@ -76,20 +74,68 @@ async fn cancel_escrow_ix_success() {
 		},
 	);
 
+	let mut escrow = Escrow {
+		creator: creator_pubkey,
+		amount: AMOUNT,
+		status: ESCROW_STATUS_OPEN,
+		escrow_bump: escrow_pda_bump,
+		counter,
+		..Default::default()
+	};
+	configure(&mut escrow);
+
+	program_test.add_account(escrow_pda, escrow_account(&escrow, AMOUNT));
+
 	// INSTRUCTIONS
 	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
 
+	let signer = if by_creator { &creator_keypair } else { &stranger_keypair };
 	let ix = nescrow_ix_interface::cancel_escrow_ix_setup(
 		&fee_payer_keypair,
 		escrow_pda,
-		&creator_keypair,
+		signer,
 		counter,
 		recent_blockhash,
 	);
 
-	let result = banks_client.process_transaction(ix).await;
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	let creator_account = banks_client.get_account(creator_pubkey).await.unwrap().unwrap();
+
+	Some((escrow.status, escrow_account.lamports, creator_account.lamports))
+}
 
+#[tokio::test]
+async fn cancel_escrow_ix_success() {
+	let (status, escrow_lamports, creator_lamports) = cancel_with(|_| {}, true).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(status, ESCROW_STATUS_CANCELLED);
+	assert_eq!(escrow_lamports, Rent::default().minimum_balance(Escrow::LEN));
+	assert_eq!(creator_lamports, CREATOR_LAMPORTS + AMOUNT);
+}
+
+#[tokio::test]
+async fn cancel_escrow_ix_requires_creator() {
 	// ASSERTIONS
-	assert!(result.is_ok());
+	assert!(cancel_with(|_| {}, false).await.is_none());
+}
 
+#[tokio::test]
+async fn cancel_escrow_ix_rejects_accepted_escrow() {
+	// ASSERTIONS
+	assert!(cancel_with(|escrow| {
+		escrow.status = ESCROW_STATUS_ACCEPTED;
+		escrow.taker = Some(Pubkey::new_unique());
+	}, true).await.is_none());
+}
+
+#[tokio::test]
+async fn cancel_escrow_ix_rejects_filled_escrow() {
+	// ASSERTIONS
+	assert!(cancel_with(|escrow| escrow.fills = Some(Fills { min_fill: 1, filled: 1, unclaimed: 0 }), true)
+		.await
+		.is_none());
 }
@@ -0,0 +1,119 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Schedule},
+        src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_CANCELLED},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const START_TIME: i64 = 1_700_000_000;
+const PERIOD_LENGTH: i64 = 86_400;
+const TRANCHE: u64 = 2_000_000;
+
+#[tokio::test]
+async fn cancel_recurring_payment_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+	let periods: u32 = 4;
+	let released: u32 = 1;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+	let payee_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator: creator_pubkey,
+				taker: Some(payee_pubkey),
+				amount: TRANCHE * periods as u64,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				schedule: Some(Schedule {
+					tranche: TRANCHE,
+					start_time: START_TIME,
+					period_length: PERIOD_LENGTH,
+					periods,
+					released,
+				}),
+				..Default::default()
+			},
+			TRANCHE * (periods - released) as u64,
+		),
+	);
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: START_TIME + 2 * PERIOD_LENGTH + 1,
+		..Default::default()
+	});
+
+	let ix = nescrow_ix_interface::cancel_recurring_payment_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&creator_keypair,
+		payee_pubkey,
+		counter,
+		context.last_blockhash,
+	);
+
+	let result = context.banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let escrow_account = context.banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	assert_eq!(escrow.status, ESCROW_STATUS_CANCELLED);
+	assert_eq!(escrow.schedule.unwrap().released, 2);
+
+	// The second period had ended, the last two had not
+	let payee_account = context.banks_client.get_account(payee_pubkey).await.unwrap().unwrap();
+	assert_eq!(payee_account.lamports, TRANCHE);
+
+	let creator_account = context.banks_client.get_account(creator_pubkey).await.unwrap().unwrap();
+	assert_eq!(creator_account.lamports, 2 * TRANCHE);
+}
@@ -0,0 +1,115 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Stream},
+        src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_CANCELLED},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const START_TIME: i64 = 1_700_000_000;
+const END_TIME: i64 = START_TIME + 1_000;
+
+#[tokio::test]
+async fn cancel_stream_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+	let amount: u64 = 10_000_000;
+	let withdrawn: u64 = 1_000_000;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+	let beneficiary_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator: creator_pubkey,
+				taker: Some(beneficiary_pubkey),
+				amount,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				stream: Some(Stream {
+					start_time: START_TIME,
+					end_time: END_TIME,
+					withdrawn,
+				}),
+				..Default::default()
+			},
+			amount - withdrawn,
+		),
+	);
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: START_TIME + 400,
+		..Default::default()
+	});
+
+	let ix = nescrow_ix_interface::cancel_stream_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&creator_keypair,
+		beneficiary_pubkey,
+		counter,
+		context.last_blockhash,
+	);
+
+	let result = context.banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let escrow_account = context.banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	assert_eq!(escrow.status, ESCROW_STATUS_CANCELLED);
+	assert_eq!(escrow.stream.unwrap().withdrawn, 4_000_000);
+
+	let beneficiary_account = context.banks_client.get_account(beneficiary_pubkey).await.unwrap().unwrap();
+	assert_eq!(beneficiary_account.lamports, 3_000_000);
+
+	let creator_account = context.banks_client.get_account(creator_pubkey).await.unwrap().unwrap();
+	assert_eq!(creator_account.lamports, 6_000_000);
+}
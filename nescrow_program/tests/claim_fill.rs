@@ -0,0 +1,183 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		fill_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Fill, Fills},
+        src::{
+            create_escrow::{ESCROW_STATUS_CANCELLED, ESCROW_STATUS_COMPLETED, ESCROW_STATUS_OPEN},
+            oracle::ORACLE_KIND_EVENT,
+        },
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+const FILLS: [u64; 2] = [3_000_000, 2_000_000];
+const EXPIRY: i64 = 1_700_000_000;
+
+/// Claim fills of 3 and 2 million lamports from an escrow in `status`
+/// holding `pot` lamports at `now`. Returns the escrow and the lamports
+/// received by each taker, net of their fill record's rent, if every claim
+/// succeeded.
+async fn claim_with(status: u8, creator_won: bool, pot: u64, now: i64) -> Option<(Escrow, Vec<u64>)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let creator: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+	let filled: u64 = FILLS.iter().sum();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let taker_pubkeys = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let fill_pdas = taker_pubkeys.map(|taker_pubkey| Pubkey::find_program_address(
+		&[
+			b"fill",
+			escrow_pda.as_ref(),
+			taker_pubkey.as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	));
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator,
+				amount: filled,
+				status,
+				winner: creator_won.then_some(creator),
+				expiry_time: EXPIRY,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				oracle_kind: ORACLE_KIND_EVENT,
+				fills: Some(Fills {
+					min_fill: filled,
+					filled,
+					unclaimed: if status == ESCROW_STATUS_COMPLETED { filled } else { 0 },
+				}),
+				..Default::default()
+			},
+			pot,
+		),
+	);
+
+	for ((taker_pubkey, (fill_pda, fill_pda_bump)), amount) in taker_pubkeys.iter().zip(fill_pdas).zip(FILLS) {
+		program_test.add_account(
+			fill_pda,
+			fill_account(&Fill {
+				escrow: escrow_pda,
+				taker: *taker_pubkey,
+				amount,
+				fill_bump: fill_pda_bump,
+			}),
+		);
+	}
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: now,
+		..Default::default()
+	});
+
+	let mut received = vec![];
+	for (taker_pubkey, (fill_pda, _)) in taker_pubkeys.iter().zip(fill_pdas) {
+		let ix = nescrow_ix_interface::claim_fill_ix_setup(
+			&fee_payer_keypair,
+			escrow_pda,
+			fill_pda,
+			*taker_pubkey,
+			creator,
+			counter,
+			context.banks_client.get_latest_blockhash().await.unwrap(),
+		);
+
+		context.banks_client.process_transaction(ix).await.ok()?;
+
+		let taker_account = context.banks_client.get_account(*taker_pubkey).await.unwrap().unwrap();
+		received.push(taker_account.lamports - Rent::default().minimum_balance(Fill::LEN));
+
+		assert!(context.banks_client.get_account(fill_pda).await.unwrap().is_none());
+	}
+
+	let escrow_account = context.banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	assert_eq!(escrow_account.lamports, Rent::default().minimum_balance(Escrow::LEN));
+	Some((Escrow::deserialize(&mut &escrow_account.data[..]).unwrap(), received))
+}
+
+#[tokio::test]
+async fn claim_fill_ix_success() {
+	let (escrow, received) = claim_with(ESCROW_STATUS_COMPLETED, false, 10_000_000, EXPIRY).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(received, vec![6_000_000, 4_000_000]);
+	assert_eq!(escrow.fills.unwrap().unclaimed, 0);
+}
+
+#[tokio::test]
+async fn claim_fill_ix_pays_nothing_when_creator_won() {
+	let (_, received) = claim_with(ESCROW_STATUS_COMPLETED, true, 0, EXPIRY).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(received, vec![0, 0]);
+}
+
+#[tokio::test]
+async fn claim_fill_ix_refunds_expired_escrow() {
+	let (escrow, received) = claim_with(ESCROW_STATUS_OPEN, false, 5_000_000, EXPIRY).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(received, FILLS.to_vec());
+	assert_eq!(escrow.fills.unwrap().filled, 0);
+}
+
+#[tokio::test]
+async fn claim_fill_ix_refunds_cancelled_escrow() {
+	let (escrow, received) = claim_with(ESCROW_STATUS_CANCELLED, false, 5_000_000, EXPIRY - 1).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(received, FILLS.to_vec());
+	assert_eq!(escrow.fills.unwrap().filled, 0);
+}
+
+#[tokio::test]
+async fn claim_fill_ix_rejects_open_escrow() {
+	// ASSERTIONS
+	assert!(claim_with(ESCROW_STATUS_OPEN, false, 5_000_000, EXPIRY - 1).await.is_none());
+}
@@ -0,0 +1,153 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		nescrow_ix_interface,
+		participant_account,
+		pool_account,
+	},
+    nescrow::{
+        generated::state::{Participant, Pool},
+        src::create_pool::{POOL_STATUS_OPEN, POOL_STATUS_REFUNDED, POOL_STATUS_SETTLED},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+const DEPOSIT: u64 = 2_000_000;
+
+/// Claim from a three participant pool in `status` whose winners are the
+/// first `winners` participants, once for each participant in `claimants`
+/// in order. Returns each claimant's lamports and whether their record is
+/// closed, if every claim succeeded.
+async fn claim_with(status: u8, winners: usize, claimants: &[usize]) -> Option<Vec<(u64, bool)>> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let organizer: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+	let participants: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+
+	// PDA
+	let (pool_pda, pool_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"pool",
+			organizer.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let record_pda = |participant: &Pubkey| Pubkey::find_program_address(
+		&[
+			b"participant",
+			pool_pda.as_ref(),
+			participant.as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		pool_pda,
+		pool_account(&Pool {
+			organizer,
+			counter,
+			deposit: DEPOSIT,
+			max_participants: 3,
+			participants: 3,
+			status,
+			winners: participants[..winners].to_vec(),
+			unclaimed_winners: winners as u32,
+			pool_bump: pool_pda_bump,
+			..Default::default()
+		}),
+	);
+
+	for participant in &participants {
+		let (record, record_bump) = record_pda(participant);
+		program_test.add_account(
+			record,
+			participant_account(&Participant {
+				pool: pool_pda,
+				participant: *participant,
+				participant_bump: record_bump,
+			}),
+		);
+	}
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let mut results = vec![];
+	for &claimant in claimants {
+		let participant = participants[claimant];
+		let record = record_pda(&participant).0;
+
+		let ix = nescrow_ix_interface::claim_pool_ix_setup(
+			&fee_payer_keypair,
+			pool_pda,
+			record,
+			participant,
+			organizer,
+			counter,
+			recent_blockhash,
+		);
+
+		banks_client.process_transaction(ix).await.ok()?;
+
+		let participant_account = banks_client.get_account(participant).await.unwrap();
+		results.push((
+			participant_account.map_or(0, |account| account.lamports),
+			banks_client.get_account(record).await.unwrap().is_none(),
+		));
+	}
+
+	Some(results)
+}
+
+#[tokio::test]
+async fn claim_pool_ix_splits_pot_between_winners() {
+	let results = claim_with(POOL_STATUS_SETTLED, 2, &[0, 1, 2]).await.unwrap();
+	let record_rent = Rent::default().minimum_balance(Participant::LEN);
+
+	// ASSERTIONS
+	assert_eq!(results[0], (3 * DEPOSIT / 2 + record_rent, true));
+	assert_eq!(results[1], (3 * DEPOSIT / 2 + record_rent, true));
+	assert_eq!(results[2], (record_rent, true));
+}
+
+#[tokio::test]
+async fn claim_pool_ix_refunds_deposit() {
+	let results = claim_with(POOL_STATUS_REFUNDED, 0, &[1]).await.unwrap();
+	let record_rent = Rent::default().minimum_balance(Participant::LEN);
+
+	// ASSERTIONS
+	assert_eq!(results[0], (DEPOSIT + record_rent, true));
+}
+
+#[tokio::test]
+async fn claim_pool_ix_rejects_open_pool() {
+	// ASSERTIONS
+	assert!(claim_with(POOL_STATUS_OPEN, 0, &[0]).await.is_none());
+}
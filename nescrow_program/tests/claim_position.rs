@@ -0,0 +1,163 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		market_account,
+		nescrow_ix_interface,
+		position_account,
+	},
+    nescrow::{
+        generated::state::{Market, Position},
+        src::create_market::{MARKET_STATUS_OPEN, MARKET_STATUS_REFUNDED, MARKET_STATUS_RESOLVED},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+/// Positions of the market: 1 and 2 million lamports on the winning
+/// outcome 0, and 7 million on outcome 1
+const POSITIONS: [(u8, u64); 3] = [(0, 1_000_000), (0, 2_000_000), (1, 7_000_000)];
+
+/// Claim every position of a 10 million lamport market in `status`, won by
+/// outcome 0 with a fee of 1 million already taken. Returns each owner's
+/// lamports and whether their position is closed, if every claim succeeded.
+async fn claim_with(status: u8) -> Option<Vec<(u64, bool)>> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let creator: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+	let owners: Vec<Pubkey> = POSITIONS.iter().map(|_| Pubkey::new_unique()).collect();
+	let pot: u64 = if status == MARKET_STATUS_RESOLVED { 9_000_000 } else { 10_000_000 };
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+
+	// PDA
+	let (market_pda, market_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"market",
+			creator.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let position_pda = |owner: &Pubkey, outcome: u8| Pubkey::find_program_address(
+		&[
+			b"position",
+			market_pda.as_ref(),
+			owner.as_ref(),
+			&[outcome],
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		market_pda,
+		market_account(
+			&Market {
+				creator,
+				counter,
+				fee_bps: 1_000,
+				outcome_totals: vec![3_000_000, 7_000_000],
+				status,
+				winning_outcome: Some(0),
+				payout_pool: if status == MARKET_STATUS_RESOLVED { pot } else { 0 },
+				unclaimed_winning_stake: if status == MARKET_STATUS_RESOLVED { 3_000_000 } else { 0 },
+				market_bump: market_pda_bump,
+				..Default::default()
+			},
+			pot,
+		),
+	);
+
+	for (owner, &(outcome, stake)) in owners.iter().zip(POSITIONS.iter()) {
+		let (position, position_bump) = position_pda(owner, outcome);
+		program_test.add_account(
+			position,
+			position_account(&Position {
+				market: market_pda,
+				owner: *owner,
+				outcome,
+				stake,
+				position_bump,
+			}),
+		);
+	}
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let mut results = vec![];
+	for (owner, &(outcome, _)) in owners.iter().zip(POSITIONS.iter()) {
+		let position = position_pda(owner, outcome).0;
+
+		let ix = nescrow_ix_interface::claim_position_ix_setup(
+			&fee_payer_keypair,
+			market_pda,
+			position,
+			*owner,
+			creator,
+			counter,
+			outcome,
+			recent_blockhash,
+		);
+
+		banks_client.process_transaction(ix).await.ok()?;
+
+		let owner_account = banks_client.get_account(*owner).await.unwrap();
+		results.push((
+			owner_account.map_or(0, |account| account.lamports),
+			banks_client.get_account(position).await.unwrap().is_none(),
+		));
+	}
+
+	Some(results)
+}
+
+#[tokio::test]
+async fn claim_position_ix_pays_winners_pro_rata() {
+	let results = claim_with(MARKET_STATUS_RESOLVED).await.unwrap();
+	let position_rent = Rent::default().minimum_balance(Position::LEN);
+
+	// ASSERTIONS
+	assert_eq!(results[0], (3_000_000 + position_rent, true));
+	assert_eq!(results[1], (6_000_000 + position_rent, true));
+	assert_eq!(results[2], (position_rent, true));
+}
+
+#[tokio::test]
+async fn claim_position_ix_refunds_stakes() {
+	let results = claim_with(MARKET_STATUS_REFUNDED).await.unwrap();
+	let position_rent = Rent::default().minimum_balance(Position::LEN);
+
+	// ASSERTIONS
+	for (&(_, stake), result) in POSITIONS.iter().zip(results) {
+		assert_eq!(result, (stake + position_rent, true));
+	}
+}
+
+#[tokio::test]
+async fn claim_position_ix_rejects_open_market() {
+	// ASSERTIONS
+	assert!(claim_with(MARKET_STATUS_OPEN).await.is_none());
+}
@@ -0,0 +1,246 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::Escrow,
+        src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED, ESCROW_STATUS_OPEN},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+const AMOUNT: u64 = 1_000_000;
+const BALANCE: u64 = 1_000_000_000;
+const NOW: i64 = 1_700_000_000;
+const DESCRIPTION: &str = "Chess, best of one";
+const COUNTER: u64 = 4;
+
+/// Rematch an escrow in `status`, then have the previous taker accept the
+/// rematch if `by_taker`, or else an outsider. Returns the rematch and the
+/// lamports it holds above rent and left with the creator if both went
+/// through.
+async fn rematch_with(status: u8, by_taker: bool) -> Option<(Escrow, u64, u64)> {
+	rematch_into(status, by_taker, COUNTER + 1, false, false).await
+}
+
+/// Same as `rematch_with`, into `rematch_counter`, with the creator's
+/// counter after the escrow's already used if `next_taken`, and with the
+/// creator's side transferred from an original creator if `transferred`
+async fn rematch_into(
+	status: u8,
+	by_taker: bool,
+	rematch_counter: u64,
+	next_taken: bool,
+	transferred: bool,
+) -> Option<(Escrow, u64, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = COUNTER;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+	let taker_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+	let taker_pubkey = taker_keypair.pubkey();
+	let system_program_pubkey = Pubkey::default();
+	let accepter_keypair = if by_taker { &taker_keypair } else { &outsider_keypair };
+	let original_creator = transferred.then(Pubkey::new_unique);
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			original_creator.unwrap_or(creator_pubkey).as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (next_pda, next_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			(counter + 1).to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (rematch_pda, rematch_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			rematch_counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [fee_payer_pubkey, creator_pubkey, taker_pubkey, outsider_keypair.pubkey()] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: BALANCE,
+				data: vec![],
+				owner: Pubkey::default(),
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator: creator_pubkey,
+				taker: Some(taker_pubkey),
+				amount: AMOUNT,
+				status,
+				winner: Some(taker_pubkey),
+				description: DESCRIPTION.to_string(),
+				expiry_time: NOW - 3600,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				rent_payer: fee_payer_pubkey,
+				original_creator,
+				..Default::default()
+			},
+			0,
+		),
+	);
+
+	if next_taken {
+		program_test.add_account(
+			next_pda,
+			escrow_account(
+				&Escrow {
+					creator: creator_pubkey,
+					amount: AMOUNT,
+					status: ESCROW_STATUS_OPEN,
+					expiry_time: NOW + 3600,
+					escrow_bump: next_pda_bump,
+					counter: counter + 1,
+					..Default::default()
+				},
+				AMOUNT,
+			),
+		);
+	}
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		slot: 1,
+		unix_timestamp: NOW,
+		..Default::default()
+	});
+
+	let ix = nescrow_ix_interface::clone_escrow_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		rematch_pda,
+		&creator_keypair,
+		system_program_pubkey,
+		counter,
+		rematch_counter,
+		NOW + 3600,
+		context.last_blockhash,
+	);
+
+	context.banks_client.process_transaction(ix).await.ok()?;
+
+	// The rematch took the completed escrow's place
+	assert!(context.banks_client.get_account(escrow_pda).await.unwrap().is_none());
+
+	let rematch_account = context.banks_client.get_account(rematch_pda).await.unwrap().unwrap();
+	let rematch = Escrow::deserialize(&mut &rematch_account.data[..]).unwrap();
+	assert_eq!(rematch.status, ESCROW_STATUS_OPEN);
+	assert_eq!(rematch.creator, creator_pubkey);
+	assert_eq!(rematch.amount, AMOUNT);
+	assert_eq!(rematch.description, DESCRIPTION);
+	assert_eq!(rematch.counter, rematch_counter);
+	assert_eq!(rematch.escrow_bump, rematch_pda_bump);
+	assert_eq!(rematch.original_creator, None);
+	assert_eq!(rematch.designated_taker, Some(taker_pubkey));
+
+	let ix = nescrow_ix_interface::accept_escrow_ix_setup(
+		&fee_payer_keypair,
+		rematch_pda,
+		accepter_keypair,
+		system_program_pubkey,
+		creator_pubkey,
+		rematch_counter,
+		None,
+		context.last_blockhash,
+	);
+
+	context.banks_client.process_transaction(ix).await.ok()?;
+
+	let rematch_account = context.banks_client.get_account(rematch_pda).await.unwrap().unwrap();
+	let rematch = Escrow::deserialize(&mut &rematch_account.data[..]).unwrap();
+	let creator_account = context.banks_client.get_account(creator_pubkey).await.unwrap().unwrap();
+
+	Some((
+		rematch,
+		rematch_account.lamports - Rent::default().minimum_balance(Escrow::LEN),
+		creator_account.lamports,
+	))
+}
+
+#[tokio::test]
+async fn clone_escrow_ix_success() {
+	let (rematch, pot, creator_lamports) = rematch_with(ESCROW_STATUS_COMPLETED, true).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(rematch.status, ESCROW_STATUS_ACCEPTED);
+	assert_eq!(pot, 2 * AMOUNT);
+	assert_eq!(creator_lamports, BALANCE - AMOUNT);
+}
+
+#[tokio::test]
+async fn clone_escrow_ix_rejects_other_takers() {
+	// ASSERTIONS
+	assert!(rematch_with(ESCROW_STATUS_COMPLETED, false).await.is_none());
+}
+
+#[tokio::test]
+async fn clone_escrow_ix_rejects_unsettled_escrow() {
+	// ASSERTIONS
+	assert!(rematch_with(ESCROW_STATUS_ACCEPTED, true).await.is_none());
+}
+
+#[tokio::test]
+async fn clone_escrow_ix_skips_used_counter() {
+	let (rematch, _, _) = rematch_into(ESCROW_STATUS_COMPLETED, true, COUNTER + 2, true, false).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(rematch.counter, COUNTER + 2);
+	assert!(rematch_into(ESCROW_STATUS_COMPLETED, true, COUNTER + 1, true, false).await.is_none());
+}
+
+#[tokio::test]
+async fn clone_escrow_ix_rematches_transferred_position_under_current_creator() {
+	let (rematch, pot, creator_lamports) = rematch_into(ESCROW_STATUS_COMPLETED, true, COUNTER, false, true)
+		.await
+		.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(rematch.status, ESCROW_STATUS_ACCEPTED);
+	assert_eq!(rematch.counter, COUNTER);
+	assert_eq!(pot, 2 * AMOUNT);
+	assert_eq!(creator_lamports, BALANCE - AMOUNT);
+}
@@ -0,0 +1,155 @@
+pub mod common;
+
+use {
+    common::{
+		counter_offer_account,
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{CounterOffer, Escrow},
+        src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_OPEN},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+const AMOUNT: u64 = 3_000_000;
+
+/// Who closes the counter-offer
+enum Closer {
+	Creator,
+	Taker,
+	Stranger,
+}
+
+/// Close a 3 million lamports counter-offer on an escrow in `status`,
+/// returning the lamports refunded to its taker if closing succeeded
+async fn close_with(closer: Closer, status: u8) -> Option<u64> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+	let taker_keypair = Keypair::new();
+	let stranger_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+	let taker_pubkey = taker_keypair.pubkey();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (counter_offer_pda, counter_offer_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"counter_offer",
+			escrow_pda.as_ref(),
+			taker_pubkey.as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator: creator_pubkey,
+				amount: 5_000_000,
+				status,
+				expiry_time: i64::MAX,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				..Default::default()
+			},
+			5_000_000,
+		),
+	);
+
+	program_test.add_account(
+		counter_offer_pda,
+		counter_offer_account(&CounterOffer {
+			escrow: escrow_pda,
+			taker: taker_pubkey,
+			amount: AMOUNT,
+			expiry_time: i64::MAX,
+			offer_bump: counter_offer_pda_bump,
+		}),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let authority_keypair = match closer {
+		Closer::Creator => &creator_keypair,
+		Closer::Taker => &taker_keypair,
+		Closer::Stranger => &stranger_keypair,
+	};
+
+	let ix = nescrow_ix_interface::close_counter_offer_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		counter_offer_pda,
+		taker_pubkey,
+		authority_keypair,
+		creator_pubkey,
+		counter,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	assert!(banks_client.get_account(counter_offer_pda).await.unwrap().is_none());
+
+	let taker_account = banks_client.get_account(taker_pubkey).await.unwrap().unwrap();
+	Some(taker_account.lamports)
+}
+
+#[tokio::test]
+async fn close_counter_offer_ix_success() {
+	let refund = Rent::default().minimum_balance(CounterOffer::LEN) + AMOUNT;
+
+	// ASSERTIONS
+	assert_eq!(close_with(Closer::Taker, ESCROW_STATUS_OPEN).await, Some(refund));
+	assert_eq!(close_with(Closer::Creator, ESCROW_STATUS_OPEN).await, Some(refund));
+}
+
+#[tokio::test]
+async fn close_counter_offer_ix_anyone_closes_stale_offer() {
+	let refund = Rent::default().minimum_balance(CounterOffer::LEN) + AMOUNT;
+
+	// ASSERTIONS
+	assert_eq!(close_with(Closer::Stranger, ESCROW_STATUS_ACCEPTED).await, Some(refund));
+}
+
+#[tokio::test]
+async fn close_counter_offer_ix_rejects_stranger_on_live_offer() {
+	// ASSERTIONS
+	assert!(close_with(Closer::Stranger, ESCROW_STATUS_OPEN).await.is_none());
+}
@@ -1,6 +1,8 @@
 use {
+	borsh::BorshSerialize,
 	solana_program_test::{processor, ProgramTest},
-	nescrow::generated::entrypoint::process_instruction,
+	solana_sdk::{account::Account, rent::Rent},
+	nescrow::generated::{entrypoint::process_instruction, state::{CounterOffer, Escrow, Event, Fill, Market, MilestoneEscrow, Participant, Pool, Position, Purchase, ReleaseConditions}},
 };
 
 pub fn get_program_test() -> ProgramTest {
@@ -12,6 +14,160 @@ pub fn get_program_test() -> ProgramTest {
 
 	return program_test;
 }
+
+/// Build a program-owned escrow account holding `pot` lamports above rent
+pub fn escrow_account(escrow: &Escrow, pot: u64) -> Account {
+	let mut data = escrow.try_to_vec().unwrap();
+	data.resize(Escrow::LEN, 0);
+
+	Account {
+		lamports: Rent::default().minimum_balance(Escrow::LEN) + pot,
+		data,
+		owner: nescrow_ix_interface::ID,
+		executable: false,
+		rent_epoch: 0,
+	}
+}
+
+/// Build a program-owned event account
+pub fn event_account(event: &Event) -> Account {
+	let mut data = event.try_to_vec().unwrap();
+	data.resize(Event::LEN, 0);
+
+	Account {
+		lamports: Rent::default().minimum_balance(Event::LEN),
+		data,
+		owner: nescrow_ix_interface::ID,
+		executable: false,
+		rent_epoch: 0,
+	}
+}
+
+/// Build a program-owned release conditions account
+pub fn release_conditions_account(release_conditions: &ReleaseConditions) -> Account {
+	let mut data = release_conditions.try_to_vec().unwrap();
+	data.resize(ReleaseConditions::LEN, 0);
+
+	Account {
+		lamports: Rent::default().minimum_balance(ReleaseConditions::LEN),
+		data,
+		owner: nescrow_ix_interface::ID,
+		executable: false,
+		rent_epoch: 0,
+	}
+}
+
+/// Build a program-owned milestone escrow account holding `funds` lamports above rent
+pub fn milestone_escrow_account(escrow: &MilestoneEscrow, funds: u64) -> Account {
+	let mut data = escrow.try_to_vec().unwrap();
+	data.resize(MilestoneEscrow::LEN, 0);
+
+	Account {
+		lamports: Rent::default().minimum_balance(MilestoneEscrow::LEN) + funds,
+		data,
+		owner: nescrow_ix_interface::ID,
+		executable: false,
+		rent_epoch: 0,
+	}
+}
+
+/// Build a program-owned purchase account holding its payment above rent
+pub fn purchase_account(purchase: &Purchase) -> Account {
+	let mut data = purchase.try_to_vec().unwrap();
+	data.resize(Purchase::LEN, 0);
+
+	Account {
+		lamports: Rent::default().minimum_balance(Purchase::LEN) + purchase.amount,
+		data,
+		owner: nescrow_ix_interface::ID,
+		executable: false,
+		rent_epoch: 0,
+	}
+}
+
+/// Build a program-owned pool account holding every participant's deposit above rent
+pub fn pool_account(pool: &Pool) -> Account {
+	let mut data = pool.try_to_vec().unwrap();
+	data.resize(Pool::LEN, 0);
+
+	Account {
+		lamports: Rent::default().minimum_balance(Pool::LEN) + pool.deposit * pool.participants as u64,
+		data,
+		owner: nescrow_ix_interface::ID,
+		executable: false,
+		rent_epoch: 0,
+	}
+}
+
+/// Build a program-owned participant record
+pub fn participant_account(record: &Participant) -> Account {
+	let mut data = record.try_to_vec().unwrap();
+	data.resize(Participant::LEN, 0);
+
+	Account {
+		lamports: Rent::default().minimum_balance(Participant::LEN),
+		data,
+		owner: nescrow_ix_interface::ID,
+		executable: false,
+		rent_epoch: 0,
+	}
+}
+
+/// Build a program-owned market account holding `pot` lamports above rent
+pub fn market_account(market: &Market, pot: u64) -> Account {
+	let mut data = market.try_to_vec().unwrap();
+	data.resize(Market::LEN, 0);
+
+	Account {
+		lamports: Rent::default().minimum_balance(Market::LEN) + pot,
+		data,
+		owner: nescrow_ix_interface::ID,
+		executable: false,
+		rent_epoch: 0,
+	}
+}
+
+/// Build a program-owned market position
+pub fn position_account(position: &Position) -> Account {
+	let mut data = position.try_to_vec().unwrap();
+	data.resize(Position::LEN, 0);
+
+	Account {
+		lamports: Rent::default().minimum_balance(Position::LEN),
+		data,
+		owner: nescrow_ix_interface::ID,
+		executable: false,
+		rent_epoch: 0,
+	}
+}
+
+/// Build a program-owned fill record
+pub fn fill_account(fill: &Fill) -> Account {
+	let mut data = fill.try_to_vec().unwrap();
+	data.resize(Fill::LEN, 0);
+
+	Account {
+		lamports: Rent::default().minimum_balance(Fill::LEN),
+		data,
+		owner: nescrow_ix_interface::ID,
+		executable: false,
+		rent_epoch: 0,
+	}
+}
+
+/// Build a program-owned counter-offer holding its offered stake above rent
+pub fn counter_offer_account(counter_offer: &CounterOffer) -> Account {
+	let mut data = counter_offer.try_to_vec().unwrap();
+	data.resize(CounterOffer::LEN, 0);
+
+	Account {
+		lamports: Rent::default().minimum_balance(CounterOffer::LEN) + counter_offer.amount,
+		data,
+		owner: nescrow_ix_interface::ID,
+		executable: false,
+		rent_epoch: 0,
+	}
+}
 	
 pub mod nescrow_ix_interface {
 
@@ -25,7 +181,7 @@ pub mod nescrow_ix_interface {
 			signature::{Keypair, Signer},
 			transaction::Transaction,
 		},
-		nescrow::generated::instructions::*,
+		nescrow::generated::{instructions::*, state::{ConditionNode, EventCondition, Line, Outcome, PriceCondition}},
 	};
 
 	declare_id!("FG4TENpdyGmjxqJYwheeKHyRfcXTwPxFT6nCs8aP1HdL");
@@ -39,6 +195,16 @@ pub mod nescrow_ix_interface {
 		amount: u64,
 		description: &String,
 		expiry_time: i64,
+		oracle_kind: u8,
+		oracle_account: Pubkey,
+		price_condition: Option<PriceCondition>,
+		event_condition: Option<EventCondition>,
+		game: Option<GameTerms>,
+		max_extension: i64,
+		min_fill: Option<u64>,
+		line: Option<Line>,
+		series_length: Option<u8>,
+		event: Option<Pubkey>,
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let data = NescrowInstruction::CreateEscrow(
@@ -47,17 +213,29 @@ pub mod nescrow_ix_interface {
 				amount,
 				description: description.clone(),
 				expiry_time,
+				oracle_kind,
+				oracle_account,
+				price_condition,
+				event_condition,
+				game,
+				max_extension,
+				min_fill,
+				line,
+				series_length,
 			},
 		);
 
+		let mut accounts = vec![
+			AccountMeta::new(fee_payer.pubkey(), true),
+			AccountMeta::new(escrow, false),
+			AccountMeta::new(creator.pubkey(), true),
+			AccountMeta::new_readonly(system_program, false),
+		];
+		accounts.extend(event.map(|event| AccountMeta::new_readonly(event, false)));
+
 		let instruction = Instruction {
 			program_id: id(),
-			accounts: vec![
-				AccountMeta::new(fee_payer.pubkey(), true),
-				AccountMeta::new(escrow, false),
-				AccountMeta::new(creator.pubkey(), true),
-				AccountMeta::new_readonly(system_program, false),
-			],
+			accounts,
 			data: data.try_to_vec().unwrap(),
 		};
 
@@ -78,14 +256,17 @@ pub mod nescrow_ix_interface {
 		fee_payer: &Keypair,
 		escrow: Pubkey,
 		taker: &Keypair,
+		system_program: Pubkey,
 		creator: Pubkey,
 		counter: u64,
+		commitment: Option<[u8; 32]>,
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let data = NescrowInstruction::AcceptEscrow(
 			AcceptEscrowArgs{
 				creator,
 				counter,
+				commitment,
 			},
 		);
 
@@ -95,6 +276,7 @@ pub mod nescrow_ix_interface {
 				AccountMeta::new(fee_payer.pubkey(), true),
 				AccountMeta::new(escrow, false),
 				AccountMeta::new(taker.pubkey(), true),
+				AccountMeta::new_readonly(system_program, false),
 			],
 			data: data.try_to_vec().unwrap(),
 		};
@@ -192,6 +374,7 @@ pub mod nescrow_ix_interface {
 		fee_payer: &Keypair,
 		escrow: Pubkey,
 		creator: &Keypair,
+		taker: Option<&Keypair>,
 		counter: u64,
 		new_expiry_time: i64,
 		recent_blockhash: Hash,
@@ -203,12 +386,259 @@ pub mod nescrow_ix_interface {
 			},
 		);
 
+		let mut accounts = vec![
+			AccountMeta::new(fee_payer.pubkey(), true),
+			AccountMeta::new(escrow, false),
+			AccountMeta::new_readonly(creator.pubkey(), true),
+		];
+		accounts.extend(taker.map(|taker| AccountMeta::new_readonly(taker.pubkey(), true)));
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts,
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		let mut keypairs = vec![fee_payer, creator];
+		keypairs.extend(taker);
+		transaction.sign(&keypairs, recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn resolve_escrow_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		oracle: Pubkey,
+		winner: Pubkey,
+		attestation: Option<Instruction>,
+		creator: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::ResolveEscrow(
+			ResolveEscrowArgs{
+				creator,
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new_readonly(oracle, false),
+				AccountMeta::new(winner, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&attestation.into_iter().chain([instruction]).collect::<Vec<_>>(), 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_event_ix_setup(
+		fee_payer: &Keypair,
+		event: Pubkey,
+		reporter: &Keypair,
+		system_program: Pubkey,
+		event_id: u64,
+		start_time: i64,
+		outcomes: &[String],
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::CreateEvent(
+			CreateEventArgs{
+				event_id,
+				start_time,
+				outcomes: outcomes.to_vec(),
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(event, false),
+				AccountMeta::new_readonly(reporter.pubkey(), true),
+				AccountMeta::new_readonly(system_program, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&reporter,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn report_event_result_ix_setup(
+		fee_payer: &Keypair,
+		event: Pubkey,
+		reporter: &Keypair,
+		event_id: u64,
+		outcome: u8,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::ReportEventResult(
+			ReportEventResultArgs{
+				event_id,
+				outcome,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(event, false),
+				AccountMeta::new_readonly(reporter.pubkey(), true),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&reporter,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn reveal_choice_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		party: &Keypair,
+		creator: Pubkey,
+		counter: u64,
+		choice: u8,
+		salt: [u8; 32],
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::RevealChoice(
+			RevealChoiceArgs{
+				creator,
+				counter,
+				choice,
+				salt,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new_readonly(party.pubkey(), true),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&party,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn settle_game_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		creator: Pubkey,
+		taker: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::SettleGame(
+			SettleGameArgs{
+				counter,
+			},
+		);
+
 		let instruction = Instruction {
 			program_id: id(),
 			accounts: vec![
 				AccountMeta::new(fee_payer.pubkey(), true),
 				AccountMeta::new(escrow, false),
+				AccountMeta::new(creator, false),
+				AccountMeta::new(taker, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_release_conditions_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		conditions: Pubkey,
+		creator: &Keypair,
+		taker: &Keypair,
+		system_program: Pubkey,
+		counter: u64,
+		creator_wins: Vec<ConditionNode>,
+		taker_wins: Vec<ConditionNode>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::SetReleaseConditions(
+			SetReleaseConditionsArgs{
+				counter,
+				creator_wins,
+				taker_wins,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new_readonly(escrow, false),
+				AccountMeta::new(conditions, false),
 				AccountMeta::new_readonly(creator.pubkey(), true),
+				AccountMeta::new_readonly(taker.pubkey(), true),
+				AccountMeta::new_readonly(system_program, false),
 			],
 			data: data.try_to_vec().unwrap(),
 		};
@@ -221,9 +651,1987 @@ pub mod nescrow_ix_interface {
 		transaction.sign(&[
 			&fee_payer,
 			&creator,
+			&taker,
 		], recent_blockhash);
 
 		return transaction;
 	}
 
+	pub fn release_if_conditions_met_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		conditions: Pubkey,
+		winner: Pubkey,
+		signers: &[&Keypair],
+		references: &[Pubkey],
+		creator: Pubkey,
+		counter: u64,
+		preimages: Vec<Vec<u8>>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::ReleaseIfConditionsMet(
+			ReleaseIfConditionsMetArgs{
+				creator,
+				counter,
+				preimages,
+			},
+		);
+
+		let mut accounts = vec![
+			AccountMeta::new(fee_payer.pubkey(), true),
+			AccountMeta::new(escrow, false),
+			AccountMeta::new_readonly(conditions, false),
+			AccountMeta::new(winner, false),
+		];
+		accounts.extend(signers.iter().map(|signer| AccountMeta::new_readonly(signer.pubkey(), true)));
+		accounts.extend(references.iter().map(|reference| AccountMeta::new_readonly(*reference, false)));
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts,
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		let mut keypairs = vec![fee_payer];
+		keypairs.extend_from_slice(signers);
+		transaction.sign(&keypairs, recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_milestone_escrow_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		client: &Keypair,
+		worker: Pubkey,
+		system_program: Pubkey,
+		counter: u64,
+		milestones: Vec<MilestoneTerms>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::CreateMilestoneEscrow(
+			CreateMilestoneEscrowArgs{
+				counter,
+				milestones,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(client.pubkey(), true),
+				AccountMeta::new_readonly(worker, false),
+				AccountMeta::new_readonly(system_program, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&client,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn approve_milestone_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		client: &Keypair,
+		worker: Pubkey,
+		counter: u64,
+		index: u8,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::ApproveMilestone(
+			ApproveMilestoneArgs{
+				counter,
+				index,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new_readonly(client.pubkey(), true),
+				AccountMeta::new(worker, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&client,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn dispute_milestone_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		party: &Keypair,
+		client: Pubkey,
+		counter: u64,
+		index: u8,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::DisputeMilestone(
+			DisputeMilestoneArgs{
+				client,
+				counter,
+				index,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new_readonly(party.pubkey(), true),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&party,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn reclaim_milestones_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		client: &Keypair,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::ReclaimMilestones(
+			ReclaimMilestonesArgs{
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(client.pubkey(), true),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&client,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_stream_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		creator: &Keypair,
+		beneficiary: Pubkey,
+		system_program: Pubkey,
+		counter: u64,
+		amount: u64,
+		start_time: i64,
+		end_time: i64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::CreateStream(
+			CreateStreamArgs{
+				counter,
+				amount,
+				start_time,
+				end_time,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(creator.pubkey(), true),
+				AccountMeta::new_readonly(beneficiary, false),
+				AccountMeta::new_readonly(system_program, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn withdraw_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		beneficiary: &Keypair,
+		creator: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::Withdraw(
+			WithdrawArgs{
+				creator,
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(beneficiary.pubkey(), true),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&beneficiary,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn cancel_stream_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		creator: &Keypair,
+		beneficiary: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::CancelStream(
+			CancelStreamArgs{
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(creator.pubkey(), true),
+				AccountMeta::new(beneficiary, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_recurring_payment_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		creator: &Keypair,
+		payee: Pubkey,
+		system_program: Pubkey,
+		counter: u64,
+		tranche: u64,
+		start_time: i64,
+		period_length: i64,
+		periods: u32,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::CreateRecurringPayment(
+			CreateRecurringPaymentArgs{
+				counter,
+				tranche,
+				start_time,
+				period_length,
+				periods,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(creator.pubkey(), true),
+				AccountMeta::new_readonly(payee, false),
+				AccountMeta::new_readonly(system_program, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn release_next_period_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		payee: Pubkey,
+		creator: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::ReleaseNextPeriod(
+			ReleaseNextPeriodArgs{
+				creator,
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(payee, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn cancel_recurring_payment_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		creator: &Keypair,
+		payee: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::CancelRecurringPayment(
+			CancelRecurringPaymentArgs{
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(creator.pubkey(), true),
+				AccountMeta::new(payee, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn increase_stake_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		creator: &Keypair,
+		system_program: Pubkey,
+		taker: Option<&Keypair>,
+		counter: u64,
+		increase: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::IncreaseStake(
+			IncreaseStakeArgs{
+				counter,
+				increase,
+			},
+		);
+
+		let mut accounts = vec![
+			AccountMeta::new(fee_payer.pubkey(), true),
+			AccountMeta::new(escrow, false),
+			AccountMeta::new(creator.pubkey(), true),
+			AccountMeta::new_readonly(system_program, false),
+		];
+		accounts.extend(taker.map(|taker| AccountMeta::new(taker.pubkey(), true)));
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts,
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		let mut keypairs = vec![fee_payer, creator];
+		keypairs.extend(taker);
+		transaction.sign(&keypairs, recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn decrease_stake_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		creator: &Keypair,
+		counter: u64,
+		decrease: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::DecreaseStake(
+			DecreaseStakeArgs{
+				counter,
+				decrease,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(creator.pubkey(), true),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn amend_escrow_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		creator: &Keypair,
+		taker: &Keypair,
+		system_program: Pubkey,
+		counter: u64,
+		description: Option<String>,
+		amount: Option<u64>,
+		expiry_time: Option<i64>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::AmendEscrow(
+			AmendEscrowArgs{
+				counter,
+				description,
+				amount,
+				expiry_time,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(creator.pubkey(), true),
+				AccountMeta::new(taker.pubkey(), true),
+				AccountMeta::new_readonly(system_program, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+			&taker,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_purchase_ix_setup(
+		fee_payer: &Keypair,
+		purchase: Pubkey,
+		buyer: &Keypair,
+		seller: Pubkey,
+		system_program: Pubkey,
+		counter: u64,
+		amount: u64,
+		inspection_window: i64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::CreatePurchase(
+			CreatePurchaseArgs{
+				counter,
+				amount,
+				inspection_window,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(purchase, false),
+				AccountMeta::new(buyer.pubkey(), true),
+				AccountMeta::new_readonly(seller, false),
+				AccountMeta::new_readonly(system_program, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&buyer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn mark_shipped_ix_setup(
+		fee_payer: &Keypair,
+		purchase: Pubkey,
+		seller: &Keypair,
+		buyer: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::MarkShipped(
+			MarkShippedArgs{
+				buyer,
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(purchase, false),
+				AccountMeta::new_readonly(seller.pubkey(), true),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&seller,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn confirm_purchase_ix_setup(
+		fee_payer: &Keypair,
+		purchase: Pubkey,
+		buyer: &Keypair,
+		seller: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::ConfirmPurchase(
+			ConfirmPurchaseArgs{
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(purchase, false),
+				AccountMeta::new_readonly(buyer.pubkey(), true),
+				AccountMeta::new(seller, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&buyer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn dispute_purchase_ix_setup(
+		fee_payer: &Keypair,
+		purchase: Pubkey,
+		buyer: &Keypair,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::DisputePurchase(
+			DisputePurchaseArgs{
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(purchase, false),
+				AccountMeta::new_readonly(buyer.pubkey(), true),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&buyer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn auto_release_purchase_ix_setup(
+		fee_payer: &Keypair,
+		purchase: Pubkey,
+		seller: Pubkey,
+		buyer: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::AutoReleasePurchase(
+			AutoReleasePurchaseArgs{
+				buyer,
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(purchase, false),
+				AccountMeta::new(seller, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn refund_purchase_ix_setup(
+		fee_payer: &Keypair,
+		purchase: Pubkey,
+		party: &Keypair,
+		buyer: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::RefundPurchase(
+			RefundPurchaseArgs{
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(purchase, false),
+				AccountMeta::new_readonly(party.pubkey(), true),
+				AccountMeta::new(buyer, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&party,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn mutual_cancel_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		creator: &Keypair,
+		taker: &Keypair,
+		rent_payer: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::MutualCancel(
+			MutualCancelArgs{
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(creator.pubkey(), true),
+				AccountMeta::new(taker.pubkey(), true),
+				AccountMeta::new(rent_payer, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+			&taker,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn concede_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		party: &Keypair,
+		counterparty: Pubkey,
+		creator: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::Concede(
+			ConcedeArgs{
+				creator,
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new_readonly(party.pubkey(), true),
+				AccountMeta::new(counterparty, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&party,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_pool_ix_setup(
+		fee_payer: &Keypair,
+		pool: Pubkey,
+		organizer: &Keypair,
+		system_program: Pubkey,
+		counter: u64,
+		deposit: u64,
+		max_participants: u32,
+		join_deadline: i64,
+		settle_deadline: i64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::CreatePool(
+			CreatePoolArgs{
+				counter,
+				deposit,
+				max_participants,
+				join_deadline,
+				settle_deadline,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(pool, false),
+				AccountMeta::new_readonly(organizer.pubkey(), true),
+				AccountMeta::new_readonly(system_program, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&organizer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn join_pool_ix_setup(
+		fee_payer: &Keypair,
+		pool: Pubkey,
+		participant_record: Pubkey,
+		participant: &Keypair,
+		system_program: Pubkey,
+		organizer: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::JoinPool(
+			JoinPoolArgs{
+				organizer,
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(pool, false),
+				AccountMeta::new(participant_record, false),
+				AccountMeta::new(participant.pubkey(), true),
+				AccountMeta::new_readonly(system_program, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&participant,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn settle_pool_ix_setup(
+		fee_payer: &Keypair,
+		pool: Pubkey,
+		organizer: &Keypair,
+		winner_records: &[Pubkey],
+		counter: u64,
+		winners: Vec<Pubkey>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::SettlePool(
+			SettlePoolArgs{
+				counter,
+				winners,
+			},
+		);
+
+		let mut accounts = vec![
+			AccountMeta::new(fee_payer.pubkey(), true),
+			AccountMeta::new(pool, false),
+			AccountMeta::new_readonly(organizer.pubkey(), true),
+		];
+		accounts.extend(winner_records.iter().map(|record| AccountMeta::new_readonly(*record, false)));
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts,
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&organizer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn claim_pool_ix_setup(
+		fee_payer: &Keypair,
+		pool: Pubkey,
+		participant_record: Pubkey,
+		participant: Pubkey,
+		organizer: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::ClaimPool(
+			ClaimPoolArgs{
+				organizer,
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(pool, false),
+				AccountMeta::new(participant_record, false),
+				AccountMeta::new(participant, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_market_ix_setup(
+		fee_payer: &Keypair,
+		market: Pubkey,
+		creator: &Keypair,
+		event: Pubkey,
+		system_program: Pubkey,
+		counter: u64,
+		cutoff: i64,
+		fee_bps: u16,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::CreateMarket(
+			CreateMarketArgs{
+				counter,
+				cutoff,
+				fee_bps,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(market, false),
+				AccountMeta::new_readonly(creator.pubkey(), true),
+				AccountMeta::new_readonly(event, false),
+				AccountMeta::new_readonly(system_program, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn place_stake_ix_setup(
+		fee_payer: &Keypair,
+		market: Pubkey,
+		position: Pubkey,
+		owner: &Keypair,
+		system_program: Pubkey,
+		creator: Pubkey,
+		counter: u64,
+		outcome: u8,
+		stake: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::PlaceStake(
+			PlaceStakeArgs{
+				creator,
+				counter,
+				outcome,
+				stake,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(market, false),
+				AccountMeta::new(position, false),
+				AccountMeta::new(owner.pubkey(), true),
+				AccountMeta::new_readonly(system_program, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&owner,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn resolve_market_ix_setup(
+		fee_payer: &Keypair,
+		market: Pubkey,
+		event: Pubkey,
+		creator: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::ResolveMarket(
+			ResolveMarketArgs{
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(market, false),
+				AccountMeta::new_readonly(event, false),
+				AccountMeta::new(creator, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn claim_position_ix_setup(
+		fee_payer: &Keypair,
+		market: Pubkey,
+		position: Pubkey,
+		owner: Pubkey,
+		creator: Pubkey,
+		counter: u64,
+		outcome: u8,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::ClaimPosition(
+			ClaimPositionArgs{
+				creator,
+				counter,
+				outcome,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(market, false),
+				AccountMeta::new(position, false),
+				AccountMeta::new(owner, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn fill_escrow_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		fill: Pubkey,
+		taker: &Keypair,
+		system_program: Pubkey,
+		creator: Pubkey,
+		counter: u64,
+		amount: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::FillEscrow(
+			FillEscrowArgs{
+				creator,
+				counter,
+				amount,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(fill, false),
+				AccountMeta::new(taker.pubkey(), true),
+				AccountMeta::new_readonly(system_program, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&taker,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn accept_fills_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		creator: &Keypair,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::AcceptFills(
+			AcceptFillsArgs{
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(creator.pubkey(), true),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn settle_fills_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		oracle: Pubkey,
+		creator: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::SettleFills(
+			SettleFillsArgs{
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new_readonly(oracle, false),
+				AccountMeta::new(creator, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn claim_fill_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		fill: Pubkey,
+		taker: Pubkey,
+		creator: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::ClaimFill(
+			ClaimFillArgs{
+				creator,
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(fill, false),
+				AccountMeta::new(taker, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn post_counter_offer_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		counter_offer: Pubkey,
+		taker: &Keypair,
+		system_program: Pubkey,
+		creator: Pubkey,
+		counter: u64,
+		amount: u64,
+		expiry_time: i64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::PostCounterOffer(
+			PostCounterOfferArgs{
+				creator,
+				counter,
+				amount,
+				expiry_time,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new_readonly(escrow, false),
+				AccountMeta::new(counter_offer, false),
+				AccountMeta::new(taker.pubkey(), true),
+				AccountMeta::new_readonly(system_program, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&taker,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn accept_counter_offer_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		counter_offer: Pubkey,
+		creator: &Keypair,
+		taker: Pubkey,
+		system_program: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::AcceptCounterOffer(
+			AcceptCounterOfferArgs{
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(counter_offer, false),
+				AccountMeta::new(creator.pubkey(), true),
+				AccountMeta::new(taker, false),
+				AccountMeta::new_readonly(system_program, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn close_counter_offer_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		counter_offer: Pubkey,
+		taker: Pubkey,
+		authority: &Keypair,
+		creator: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::CloseCounterOffer(
+			CloseCounterOfferArgs{
+				creator,
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new_readonly(escrow, false),
+				AccountMeta::new(counter_offer, false),
+				AccountMeta::new(taker, false),
+				AccountMeta::new_readonly(authority.pubkey(), true),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&authority,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn transfer_position_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		party: &Keypair,
+		buyer: &Keypair,
+		system_program: Pubkey,
+		creator: Pubkey,
+		counter: u64,
+		price: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::TransferPosition(
+			TransferPositionArgs{
+				creator,
+				counter,
+				price,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(party.pubkey(), true),
+				AccountMeta::new(buyer.pubkey(), true),
+				AccountMeta::new_readonly(system_program, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&party,
+			&buyer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn tokenize_position_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		party: &Keypair,
+		mint: Pubkey,
+		token_account: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		associated_token_program: Pubkey,
+		creator: Pubkey,
+		counter: u64,
+		side: u8,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::TokenizePosition(
+			TokenizePositionArgs{
+				creator,
+				counter,
+				side,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new_readonly(party.pubkey(), true),
+				AccountMeta::new(mint, false),
+				AccountMeta::new(token_account, false),
+				AccountMeta::new_readonly(system_program, false),
+				AccountMeta::new_readonly(token_program, false),
+				AccountMeta::new_readonly(associated_token_program, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&party,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn redeem_position_token_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		mint: Pubkey,
+		token_account: Pubkey,
+		holder: &Keypair,
+		token_program: Pubkey,
+		creator: Pubkey,
+		counter: u64,
+		side: u8,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::RedeemPositionToken(
+			RedeemPositionTokenArgs{
+				creator,
+				counter,
+				side,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(mint, false),
+				AccountMeta::new(token_account, false),
+				AccountMeta::new(holder.pubkey(), true),
+				AccountMeta::new_readonly(token_program, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&holder,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn report_event_score_ix_setup(
+		fee_payer: &Keypair,
+		event: Pubkey,
+		reporter: &Keypair,
+		event_id: u64,
+		home: u32,
+		away: u32,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::ReportEventScore(
+			ReportEventScoreArgs{
+				event_id,
+				home,
+				away,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(event, false),
+				AccountMeta::new_readonly(reporter.pubkey(), true),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&reporter,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn settle_line_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		event: Pubkey,
+		creator: Pubkey,
+		taker: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::SettleLine(
+			SettleLineArgs{
+				creator,
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new_readonly(event, false),
+				AccountMeta::new(creator, false),
+				AccountMeta::new(taker, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn report_series_game_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		creator: &Keypair,
+		taker: &Keypair,
+		counter: u64,
+		outcome: Outcome,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::ReportSeriesGame(
+			ReportSeriesGameArgs{
+				counter,
+				outcome,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(creator.pubkey(), true),
+				AccountMeta::new(taker.pubkey(), true),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+			&taker,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn resolve_series_game_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		oracle: Pubkey,
+		creator: Pubkey,
+		taker: Pubkey,
+		attestation: Option<Instruction>,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::ResolveSeriesGame(
+			ResolveSeriesGameArgs{
+				creator,
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new_readonly(oracle, false),
+				AccountMeta::new(creator, false),
+				AccountMeta::new(taker, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&attestation.into_iter().chain([instruction]).collect::<Vec<_>>(), 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn clone_escrow_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		rematch: Pubkey,
+		creator: &Keypair,
+		system_program: Pubkey,
+		counter: u64,
+		rematch_counter: u64,
+		expiry_time: i64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::CloneEscrow(
+			CloneEscrowArgs{
+				counter,
+				rematch_counter,
+				expiry_time,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new(rematch, false),
+				AccountMeta::new(creator.pubkey(), true),
+				AccountMeta::new_readonly(system_program, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn refund_parlay_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		conditions: Pubkey,
+		creator: Pubkey,
+		taker: Pubkey,
+		legs: &[Pubkey],
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::RefundParlay(
+			RefundParlayArgs{
+				creator,
+				counter,
+			},
+		);
+
+		let mut accounts = vec![
+			AccountMeta::new(fee_payer.pubkey(), true),
+			AccountMeta::new(escrow, false),
+			AccountMeta::new_readonly(conditions, false),
+			AccountMeta::new(creator, false),
+			AccountMeta::new(taker, false),
+		];
+		accounts.extend(legs.iter().map(|leg| AccountMeta::new_readonly(*leg, false)));
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts,
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn refund_event_escrow_ix_setup(
+		fee_payer: &Keypair,
+		escrow: Pubkey,
+		event: Pubkey,
+		creator: Pubkey,
+		taker: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::RefundEventEscrow(
+			RefundEventEscrowArgs{
+				creator,
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(escrow, false),
+				AccountMeta::new_readonly(event, false),
+				AccountMeta::new(creator, false),
+				AccountMeta::new(taker, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+	pub fn refund_pool_ix_setup(
+		fee_payer: &Keypair,
+		pool: Pubkey,
+		organizer: Pubkey,
+		counter: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let data = NescrowInstruction::RefundPool(
+			RefundPoolArgs{
+				organizer,
+				counter,
+			},
+		);
+
+		let instruction = Instruction {
+			program_id: id(),
+			accounts: vec![
+				AccountMeta::new(fee_payer.pubkey(), true),
+				AccountMeta::new(pool, false),
+			],
+			data: data.try_to_vec().unwrap(),
+		};
+
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
 }
@@ -3,9 +3,14 @@ pub mod common;
 use std::str::FromStr;
 use {
     common::{
+		escrow_account,
 		get_program_test,
 		nescrow_ix_interface,
 	},
+    nescrow::{
+        generated::state::Escrow,
+        src::{create_escrow::ESCROW_STATUS_ACCEPTED, oracle::ORACLE_KIND_ATTESTATION},
+    },
     solana_program_test::tokio,
     solana_sdk::{
         account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
@@ -97,3 +102,89 @@ async fn complete_escrow_ix_success() {
 	assert!(result.is_ok());
 
 }
+
+const AMOUNT: u64 = 2_000_000;
+
+/// Complete an accepted escrow built by `configure` as its creator, naming
+/// the creator as winner. Returns the creator's lamports if it succeeded.
+async fn complete_with(configure: impl FnOnce(&mut Escrow)) -> Option<u64> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	let mut escrow = Escrow {
+		creator: creator_pubkey,
+		taker: Some(Pubkey::new_unique()),
+		amount: AMOUNT,
+		status: ESCROW_STATUS_ACCEPTED,
+		escrow_bump: escrow_pda_bump,
+		counter,
+		..Default::default()
+	};
+	configure(&mut escrow);
+
+	program_test.add_account(escrow_pda, escrow_account(&escrow, 2 * AMOUNT));
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::complete_escrow_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&creator_keypair,
+		creator_pubkey,
+		creator_pubkey,
+		counter,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	Some(banks_client.get_account(creator_pubkey).await.unwrap()?.lamports)
+}
+
+#[tokio::test]
+async fn complete_escrow_ix_pays_winner() {
+	// ASSERTIONS
+	assert_eq!(complete_with(|_| {}).await, Some(2 * AMOUNT));
+}
+
+#[tokio::test]
+async fn complete_escrow_ix_rejects_oracle_escrow() {
+	// ASSERTIONS
+	assert!(complete_with(|escrow| {
+		escrow.oracle_kind = ORACLE_KIND_ATTESTATION;
+		escrow.oracle_account = Pubkey::new_unique();
+	}).await.is_none());
+}
@@ -0,0 +1,119 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::Escrow,
+        src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED, ESCROW_STATUS_OPEN},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const AMOUNT: u64 = 2_000_000;
+
+/// Concede an escrow in `status`, signed by the taker, awarding the pot to
+/// the creator or to the taker themselves. Returns the escrow and the
+/// creator's lamports if the concession succeeded.
+async fn concede_with(status: u8, award_creator: bool) -> Option<(Escrow, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let taker_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = Pubkey::new_unique();
+	let taker_pubkey = taker_keypair.pubkey();
+	let counterparty_pubkey = if award_creator { creator_pubkey } else { taker_pubkey };
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator: creator_pubkey,
+				taker: Some(taker_pubkey),
+				amount: AMOUNT,
+				status,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				..Default::default()
+			},
+			2 * AMOUNT,
+		),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::concede_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&taker_keypair,
+		counterparty_pubkey,
+		creator_pubkey,
+		counter,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let creator_account = banks_client.get_account(creator_pubkey).await.unwrap().unwrap();
+	Some((Escrow::deserialize(&mut &escrow_account.data[..]).unwrap(), creator_account.lamports))
+}
+
+#[tokio::test]
+async fn concede_ix_success() {
+	let (escrow, creator_lamports) = concede_with(ESCROW_STATUS_ACCEPTED, true).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.status, ESCROW_STATUS_COMPLETED);
+	assert_eq!(escrow.winner, Some(escrow.creator));
+	assert_eq!(creator_lamports, 2 * AMOUNT);
+}
+
+#[tokio::test]
+async fn concede_ix_rejects_awarding_self() {
+	// ASSERTIONS
+	assert!(concede_with(ESCROW_STATUS_ACCEPTED, false).await.is_none());
+}
+
+#[tokio::test]
+async fn concede_ix_rejects_open_escrow() {
+	// ASSERTIONS
+	assert!(concede_with(ESCROW_STATUS_OPEN, true).await.is_none());
+}
@@ -0,0 +1,121 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		get_program_test,
+		nescrow_ix_interface,
+		purchase_account,
+	},
+    nescrow::{
+        generated::state::Purchase,
+        src::create_purchase::{PURCHASE_STATUS_DISPUTED, PURCHASE_STATUS_RELEASED, PURCHASE_STATUS_SHIPPED},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+const AMOUNT: u64 = 5_000_000;
+
+/// Confirm a purchase in `status`, returning the purchase, its lamports and
+/// the seller's lamports if the confirmation succeeded
+async fn confirm_with(status: u8) -> Option<(Purchase, u64, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let buyer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let buyer_pubkey = buyer_keypair.pubkey();
+	let seller_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (purchase_pda, purchase_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"purchase",
+			buyer_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		purchase_pda,
+		purchase_account(&Purchase {
+			buyer: buyer_pubkey,
+			seller: seller_pubkey,
+			counter,
+			amount: AMOUNT,
+			inspection_window: 86_400,
+			status,
+			purchase_bump: purchase_pda_bump,
+			..Default::default()
+		}),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::confirm_purchase_ix_setup(
+		&fee_payer_keypair,
+		purchase_pda,
+		&buyer_keypair,
+		seller_pubkey,
+		counter,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let purchase_account = banks_client.get_account(purchase_pda).await.unwrap().unwrap();
+	let seller_account = banks_client.get_account(seller_pubkey).await.unwrap().unwrap();
+	Some((
+		Purchase::deserialize(&mut &purchase_account.data[..]).unwrap(),
+		purchase_account.lamports,
+		seller_account.lamports,
+	))
+}
+
+#[tokio::test]
+async fn confirm_purchase_ix_success() {
+	let (purchase, purchase_lamports, seller_lamports) = confirm_with(PURCHASE_STATUS_SHIPPED).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(purchase.status, PURCHASE_STATUS_RELEASED);
+	assert_eq!(purchase_lamports, Rent::default().minimum_balance(Purchase::LEN));
+	assert_eq!(seller_lamports, AMOUNT);
+}
+
+#[tokio::test]
+async fn confirm_purchase_ix_settles_dispute() {
+	let (purchase, _, seller_lamports) = confirm_with(PURCHASE_STATUS_DISPUTED).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(purchase.status, PURCHASE_STATUS_RELEASED);
+	assert_eq!(seller_lamports, AMOUNT);
+}
+
+#[tokio::test]
+async fn confirm_purchase_ix_rejects_released_purchase() {
+	// ASSERTIONS
+	assert!(confirm_with(PURCHASE_STATUS_RELEASED).await.is_none());
+}
@@ -3,9 +3,14 @@ pub mod common;
 use std::str::FromStr;
 use {
     common::{
+		event_account,
 		get_program_test,
 		nescrow_ix_interface,
 	},
+    nescrow::{
+        generated::{instructions::GameTerms, state::{Event, EventCondition, Line, PriceCondition}},
+        src::oracle::ORACLE_KIND_EVENT,
+    },
     solana_program_test::tokio,
     solana_sdk::{
         account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
@@ -37,6 +42,15 @@ async fn create_escrow_ix_success() {
 	let amount: u64 = Default::default();
 	let description: String = Default::default();
 	let expiry_time: i64 = Default::default();
+	let oracle_kind: u8 = Default::default();
+	let oracle_account: Pubkey = Default::default();
+	let price_condition: Option<PriceCondition> = Default::default();
+	let event_condition: Option<EventCondition> = Default::default();
+	let game: Option<GameTerms> = Default::default();
+	let max_extension: i64 = Default::default();
+	let min_fill: Option<u64> = Default::default();
+	let line: Option<Line> = Default::default();
+	let series_length: Option<u8> = Default::default();
 
 	// KEYPAIR
 	let fee_payer_keypair = Keypair::new();
@@ -94,6 +108,16 @@ async fn create_escrow_ix_success() {
 		amount,
 		&description,
 		expiry_time,
+		oracle_kind,
+		oracle_account,
+		price_condition,
+		event_condition,
+		game,
+		max_extension,
+		min_fill,
+		line,
+		series_length,
+		None,
 		recent_blockhash,
 	);
 
@@ -103,3 +127,143 @@ async fn create_escrow_ix_success() {
 	assert!(result.is_ok());
 
 }
+
+const EVENT_START: i64 = 1_000;
+
+/// Event account passed to an event escrow's creation
+enum EventAccount {
+	Named,
+	Other,
+	Missing,
+}
+
+/// Create a Home (creator) vs `taker_outcome` wager on a Home, Draw, Away
+/// event starting at `EVENT_START`. Returns whether the creation succeeded.
+async fn create_with(expiry_time: i64, taker_outcome: u8, event: EventAccount) -> bool {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+	let event_id: u64 = 1;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+	let reporter_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (escrow_pda, _escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let event_pdas: Vec<(Pubkey, u8)> = [event_id, event_id + 1]
+		.iter()
+		.map(|event_id| Pubkey::find_program_address(
+			&[
+				b"event",
+				reporter_pubkey.as_ref(),
+				event_id.to_le_bytes().as_ref(),
+			],
+			&nescrow_ix_interface::ID,
+		))
+		.collect();
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [fee_payer_pubkey, creator_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	for (offset, (event_pda, event_pda_bump)) in event_pdas.iter().enumerate() {
+		program_test.add_account(
+			*event_pda,
+			event_account(&Event {
+				reporter: reporter_pubkey,
+				event_id: event_id + offset as u64,
+				start_time: EVENT_START,
+				outcomes: vec!["Home".to_string(), "Draw".to_string(), "Away".to_string()],
+				result: None,
+				score: None,
+				event_bump: *event_pda_bump,
+			}),
+		);
+	}
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let event = match event {
+		EventAccount::Named => Some(event_pdas[0].0),
+		EventAccount::Other => Some(event_pdas[1].0),
+		EventAccount::Missing => None,
+	};
+
+	let ix = nescrow_ix_interface::create_escrow_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&creator_keypair,
+		system_program::ID,
+		counter,
+		1_000_000,
+		&"Home vs Away".to_string(),
+		expiry_time,
+		ORACLE_KIND_EVENT,
+		event_pdas[0].0,
+		None,
+		Some(EventCondition {
+			creator_outcome: 0,
+			taker_outcome,
+		}),
+		None,
+		0,
+		None,
+		None,
+		None,
+		event,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.is_ok()
+}
+
+#[tokio::test]
+async fn create_escrow_ix_accepts_event_escrow() {
+	// ASSERTIONS
+	assert!(create_with(EVENT_START, 2, EventAccount::Named).await);
+}
+
+#[tokio::test]
+async fn create_escrow_ix_rejects_expiry_after_event_start() {
+	// ASSERTIONS
+	assert!(!create_with(EVENT_START + 1, 2, EventAccount::Named).await);
+}
+
+#[tokio::test]
+async fn create_escrow_ix_rejects_unknown_event_outcome() {
+	// ASSERTIONS
+	assert!(!create_with(EVENT_START, 3, EventAccount::Named).await);
+}
+
+#[tokio::test]
+async fn create_escrow_ix_requires_named_event() {
+	// ASSERTIONS
+	assert!(!create_with(EVENT_START, 2, EventAccount::Other).await);
+	assert!(!create_with(EVENT_START, 2, EventAccount::Missing).await);
+}
@@ -0,0 +1,85 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    borsh::BorshDeserialize,
+    common::{
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::generated::state::Event,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+
+#[tokio::test]
+async fn create_event_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let event_id: u64 = 42;
+	let start_time: i64 = 1_700_000_000;
+	let outcomes: Vec<String> = vec!["Home".to_string(), "Draw".to_string(), "Away".to_string()];
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let reporter_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let reporter_pubkey = reporter_keypair.pubkey();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (event_pda, _event_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"event",
+			reporter_pubkey.as_ref(),
+			event_id.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program_pubkey,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::create_event_ix_setup(
+		&fee_payer_keypair,
+		event_pda,
+		&reporter_keypair,
+		system_program_pubkey,
+		event_id,
+		start_time,
+		&outcomes,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let event_account = banks_client.get_account(event_pda).await.unwrap().unwrap();
+	let event = Event::deserialize(&mut &event_account.data[..]).unwrap();
+	assert_eq!(event.reporter, reporter_pubkey);
+	assert_eq!(event.start_time, start_time);
+	assert_eq!(event.outcomes, outcomes);
+	assert_eq!(event.result, None);
+}
@@ -0,0 +1,123 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		event_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Event, Market},
+        src::create_market::MARKET_STATUS_OPEN,
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const START_TIME: i64 = 1_700_000_000;
+
+/// Create a market on a three outcome event starting at `START_TIME`,
+/// returning it if creation succeeded
+async fn create_with(cutoff: i64, fee_bps: u16) -> Option<Market> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+	let event_id: u64 = 1;
+	let reporter: Pubkey = Pubkey::new_unique();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+	let system_program_pubkey = Pubkey::default();
+
+	// PDA
+	let (market_pda, _market_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"market",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (event_pda, event_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"event",
+			reporter.as_ref(),
+			event_id.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		event_pda,
+		event_account(&Event {
+			reporter,
+			event_id,
+			start_time: START_TIME,
+			outcomes: vec!["Home".to_string(), "Draw".to_string(), "Away".to_string()],
+			result: None,
+			score: None,
+			event_bump: event_pda_bump,
+		}),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::create_market_ix_setup(
+		&fee_payer_keypair,
+		market_pda,
+		&creator_keypair,
+		event_pda,
+		system_program_pubkey,
+		counter,
+		cutoff,
+		fee_bps,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let market_account = banks_client.get_account(market_pda).await.unwrap().unwrap();
+	let market = Market::deserialize(&mut &market_account.data[..]).unwrap();
+	assert_eq!(market.event, event_pda);
+	Some(market)
+}
+
+#[tokio::test]
+async fn create_market_ix_success() {
+	let market = create_with(START_TIME, 200).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(market.status, MARKET_STATUS_OPEN);
+	assert_eq!(market.outcome_totals, vec![0, 0, 0]);
+	assert_eq!(market.fee_bps, 200);
+}
+
+#[tokio::test]
+async fn create_market_ix_rejects_invalid_terms() {
+	// ASSERTIONS
+	assert!(create_with(START_TIME + 1, 200).await.is_none());
+	assert!(create_with(START_TIME, 5_000).await.is_none());
+}
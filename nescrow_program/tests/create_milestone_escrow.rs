@@ -0,0 +1,114 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::{instructions::MilestoneTerms, state::MilestoneEscrow},
+        src::create_milestone_escrow::MILESTONE_STATUS_PENDING,
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+/// Create a milestone escrow, returning it and its lamports if creation succeeded
+async fn create_with(milestones: Vec<MilestoneTerms>) -> Option<(MilestoneEscrow, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let client_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let client_pubkey = client_keypair.pubkey();
+	let worker_pubkey = Pubkey::new_unique();
+	let system_program_pubkey = Pubkey::default();
+
+	// PDA
+	let (escrow_pda, _escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestones",
+			client_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		client_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::create_milestone_escrow_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&client_keypair,
+		worker_pubkey,
+		system_program_pubkey,
+		counter,
+		milestones,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = MilestoneEscrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	Some((escrow, escrow_account.lamports))
+}
+
+fn milestone(amount: u64, deadline: i64) -> MilestoneTerms {
+	MilestoneTerms {
+		amount,
+		description_hash: [1; 32],
+		deadline,
+	}
+}
+
+#[tokio::test]
+async fn create_milestone_escrow_ix_success() {
+	let (escrow, lamports) = create_with(vec![milestone(300, 100), milestone(700, 200)]).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.milestones.len(), 2);
+	assert!(escrow.milestones.iter().all(|milestone| milestone.status == MILESTONE_STATUS_PENDING));
+	assert_eq!(lamports, Rent::default().minimum_balance(MilestoneEscrow::LEN) + 1_000);
+}
+
+#[tokio::test]
+async fn create_milestone_escrow_ix_rejects_invalid_milestones() {
+	// ASSERTIONS
+	assert!(create_with(vec![]).await.is_none());
+	assert!(create_with(vec![milestone(300, 200), milestone(700, 100)]).await.is_none());
+	assert!(create_with(vec![milestone(0, 100)]).await.is_none());
+}
@@ -0,0 +1,101 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::Pool,
+        src::create_pool::POOL_STATUS_OPEN,
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const JOIN_DEADLINE: i64 = 1_700_000_000;
+
+/// Create a pool that can be joined until `JOIN_DEADLINE` and settled
+/// until `settle_deadline`, returning it if creation succeeded
+async fn create_with(deposit: u64, max_participants: u32, settle_deadline: i64) -> Option<Pool> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+	let join_deadline: i64 = JOIN_DEADLINE;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let organizer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let organizer_pubkey = organizer_keypair.pubkey();
+	let system_program_pubkey = Pubkey::default();
+
+	// PDA
+	let (pool_pda, _pool_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"pool",
+			organizer_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::create_pool_ix_setup(
+		&fee_payer_keypair,
+		pool_pda,
+		&organizer_keypair,
+		system_program_pubkey,
+		counter,
+		deposit,
+		max_participants,
+		join_deadline,
+		settle_deadline,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let pool_account = banks_client.get_account(pool_pda).await.unwrap().unwrap();
+	Some(Pool::deserialize(&mut &pool_account.data[..]).unwrap())
+}
+
+#[tokio::test]
+async fn create_pool_ix_success() {
+	let pool = create_with(2_000_000, 5, JOIN_DEADLINE + 1).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(pool.status, POOL_STATUS_OPEN);
+	assert_eq!(pool.max_participants, 5);
+	assert_eq!(pool.participants, 0);
+	assert_eq!(pool.join_deadline, JOIN_DEADLINE);
+	assert_eq!(pool.settle_deadline, JOIN_DEADLINE + 1);
+}
+
+#[tokio::test]
+async fn create_pool_ix_rejects_invalid_terms() {
+	// ASSERTIONS
+	assert!(create_with(0, 5, JOIN_DEADLINE + 1).await.is_none());
+	assert!(create_with(2_000_000, 0, JOIN_DEADLINE + 1).await.is_none());
+	assert!(create_with(2_000_000, 5, JOIN_DEADLINE).await.is_none());
+}
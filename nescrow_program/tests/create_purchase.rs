@@ -0,0 +1,100 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::Purchase,
+        src::create_purchase::{MAX_INSPECTION_WINDOW, PURCHASE_STATUS_PAID},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+/// Pay for a purchase, returning it and its lamports if creation succeeded
+async fn create_with(amount: u64, inspection_window: i64) -> Option<(Purchase, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let buyer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let buyer_pubkey = buyer_keypair.pubkey();
+	let seller_pubkey = Pubkey::new_unique();
+	let system_program_pubkey = Pubkey::default();
+
+	// PDA
+	let (purchase_pda, _purchase_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"purchase",
+			buyer_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [fee_payer_pubkey, buyer_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: Pubkey::default(),
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::create_purchase_ix_setup(
+		&fee_payer_keypair,
+		purchase_pda,
+		&buyer_keypair,
+		seller_pubkey,
+		system_program_pubkey,
+		counter,
+		amount,
+		inspection_window,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let purchase_account = banks_client.get_account(purchase_pda).await.unwrap().unwrap();
+	let purchase = Purchase::deserialize(&mut &purchase_account.data[..]).unwrap();
+	assert_eq!(purchase.seller, seller_pubkey);
+	Some((purchase, purchase_account.lamports))
+}
+
+#[tokio::test]
+async fn create_purchase_ix_success() {
+	let (purchase, lamports) = create_with(5_000_000, 86_400).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(purchase.status, PURCHASE_STATUS_PAID);
+	assert_eq!(purchase.inspection_window, 86_400);
+	assert_eq!(lamports, Rent::default().minimum_balance(Purchase::LEN) + 5_000_000);
+}
+
+#[tokio::test]
+async fn create_purchase_ix_rejects_invalid_terms() {
+	// ASSERTIONS
+	assert!(create_with(0, 86_400).await.is_none());
+	assert!(create_with(5_000_000, 0).await.is_none());
+	assert!(create_with(5_000_000, MAX_INSPECTION_WINDOW + 1).await.is_none());
+	assert!(create_with(5_000_000, MAX_INSPECTION_WINDOW).await.is_some());
+}
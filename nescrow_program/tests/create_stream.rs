@@ -0,0 +1,109 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Stream},
+        src::create_escrow::ESCROW_STATUS_ACCEPTED,
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+/// Create a stream of `amount` lamports, returning the escrow and its
+/// lamports if creation succeeded
+async fn create_with(amount: u64, start_time: i64, end_time: i64) -> Option<(Escrow, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+	let beneficiary_pubkey = Pubkey::new_unique();
+	let system_program_pubkey = Pubkey::default();
+
+	// PDA
+	let (escrow_pda, _escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		creator_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::create_stream_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&creator_keypair,
+		beneficiary_pubkey,
+		system_program_pubkey,
+		counter,
+		amount,
+		start_time,
+		end_time,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	assert_eq!(escrow.taker, Some(beneficiary_pubkey));
+	Some((escrow, escrow_account.lamports))
+}
+
+#[tokio::test]
+async fn create_stream_ix_success() {
+	let (escrow, lamports) = create_with(1_000_000, 100, 200).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.status, ESCROW_STATUS_ACCEPTED);
+	assert_eq!(escrow.stream, Some(Stream { start_time: 100, end_time: 200, withdrawn: 0 }));
+	assert_eq!(lamports, Rent::default().minimum_balance(Escrow::LEN) + 1_000_000);
+}
+
+#[tokio::test]
+async fn create_stream_ix_rejects_empty_window() {
+	// ASSERTIONS
+	assert!(create_with(1_000_000, 200, 200).await.is_none());
+	assert!(create_with(0, 100, 200).await.is_none());
+}
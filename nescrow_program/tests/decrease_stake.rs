@@ -0,0 +1,108 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::Escrow,
+        src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_OPEN},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+/// Decrease the stake of a 3 million lamport escrow in `status`, returning
+/// the escrow and its lamports above rent if the decrease succeeded
+async fn decrease_with(status: u8, decrease: u64) -> Option<(Escrow, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+	let amount: u64 = 3_000_000;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator: creator_pubkey,
+				amount,
+				status,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				..Default::default()
+			},
+			amount,
+		),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::decrease_stake_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&creator_keypair,
+		counter,
+		decrease,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	let pot = escrow_account.lamports - Rent::default().minimum_balance(Escrow::LEN);
+	Some((escrow, pot))
+}
+
+#[tokio::test]
+async fn decrease_stake_ix_success() {
+	let (escrow, pot) = decrease_with(ESCROW_STATUS_OPEN, 1_000_000).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.amount, 2_000_000);
+	assert_eq!(pot, 2_000_000);
+}
+
+#[tokio::test]
+async fn decrease_stake_ix_rejects_invalid_decrease() {
+	// ASSERTIONS
+	assert!(decrease_with(ESCROW_STATUS_OPEN, 3_000_001).await.is_none());
+	assert!(decrease_with(ESCROW_STATUS_ACCEPTED, 1_000_000).await.is_none());
+}
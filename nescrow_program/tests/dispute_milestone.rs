@@ -0,0 +1,122 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		get_program_test,
+		milestone_escrow_account,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Milestone, MilestoneEscrow},
+        src::create_milestone_escrow::{MILESTONE_STATUS_DISPUTED, MILESTONE_STATUS_PENDING},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+/// Dispute at `now` the first of two pending milestones, due at 100 and
+/// 200, signed by the worker or by an outsider, returning the escrow if the
+/// dispute was accepted
+async fn dispute_with(worker_signs: bool, now: i64) -> Option<MilestoneEscrow> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let client: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let worker_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let worker_pubkey = worker_keypair.pubkey();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestones",
+			client.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		milestone_escrow_account(
+			&MilestoneEscrow {
+				client,
+				worker: worker_pubkey,
+				counter,
+				milestones: vec![
+					Milestone { amount: 3_000_000, deadline: 100, ..Default::default() },
+					Milestone { amount: 7_000_000, deadline: 200, ..Default::default() },
+				],
+				escrow_bump: escrow_pda_bump,
+			},
+			10_000_000,
+		),
+	);
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: now,
+		..Default::default()
+	});
+
+	let party_keypair = if worker_signs { &worker_keypair } else { &outsider_keypair };
+
+	let ix = nescrow_ix_interface::dispute_milestone_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		party_keypair,
+		client,
+		counter,
+		0,
+		context.last_blockhash,
+	);
+
+	context.banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = context.banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	Some(MilestoneEscrow::deserialize(&mut &escrow_account.data[..]).unwrap())
+}
+
+#[tokio::test]
+async fn dispute_milestone_ix_success() {
+	let escrow = dispute_with(true, 100).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.milestones[0].status, MILESTONE_STATUS_DISPUTED);
+	assert_eq!(escrow.milestones[1].status, MILESTONE_STATUS_PENDING);
+}
+
+#[tokio::test]
+async fn dispute_milestone_ix_rejects_outsider() {
+	// ASSERTIONS
+	assert!(dispute_with(false, 100).await.is_none());
+}
+
+#[tokio::test]
+async fn dispute_milestone_ix_rejects_overdue_milestone() {
+	// ASSERTIONS
+	assert!(dispute_with(true, 101).await.is_none());
+}
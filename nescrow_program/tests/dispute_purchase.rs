@@ -0,0 +1,113 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		get_program_test,
+		nescrow_ix_interface,
+		purchase_account,
+	},
+    nescrow::{
+        generated::state::Purchase,
+        src::create_purchase::{PURCHASE_STATUS_DISPUTED, PURCHASE_STATUS_PAID, PURCHASE_STATUS_SHIPPED},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const INSPECTION_DEADLINE: i64 = 1_700_000_000;
+
+/// Dispute at `now` a purchase in `status` whose inspection window ends at
+/// `INSPECTION_DEADLINE`, returning the purchase if the dispute was accepted
+async fn dispute_with(status: u8, now: i64) -> Option<Purchase> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let buyer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let buyer_pubkey = buyer_keypair.pubkey();
+
+	// PDA
+	let (purchase_pda, purchase_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"purchase",
+			buyer_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		purchase_pda,
+		purchase_account(&Purchase {
+			buyer: buyer_pubkey,
+			seller: Pubkey::new_unique(),
+			counter,
+			amount: 5_000_000,
+			inspection_window: 86_400,
+			inspection_deadline: INSPECTION_DEADLINE,
+			status,
+			purchase_bump: purchase_pda_bump,
+		}),
+	);
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: now,
+		..Default::default()
+	});
+
+	let ix = nescrow_ix_interface::dispute_purchase_ix_setup(
+		&fee_payer_keypair,
+		purchase_pda,
+		&buyer_keypair,
+		counter,
+		context.last_blockhash,
+	);
+
+	context.banks_client.process_transaction(ix).await.ok()?;
+
+	let purchase_account = context.banks_client.get_account(purchase_pda).await.unwrap().unwrap();
+	Some(Purchase::deserialize(&mut &purchase_account.data[..]).unwrap())
+}
+
+#[tokio::test]
+async fn dispute_purchase_ix_success() {
+	let purchase = dispute_with(PURCHASE_STATUS_SHIPPED, INSPECTION_DEADLINE).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(purchase.status, PURCHASE_STATUS_DISPUTED);
+}
+
+#[tokio::test]
+async fn dispute_purchase_ix_rejects_after_inspection_window() {
+	// ASSERTIONS
+	assert!(dispute_with(PURCHASE_STATUS_SHIPPED, INSPECTION_DEADLINE + 1).await.is_none());
+}
+
+#[tokio::test]
+async fn dispute_purchase_ix_rejects_unshipped_purchase() {
+	// ASSERTIONS
+	assert!(dispute_with(PURCHASE_STATUS_PAID, INSPECTION_DEADLINE).await.is_none());
+}
@@ -2,10 +2,19 @@ pub mod common;
 
 use std::str::FromStr;
 use {
+    borsh::BorshDeserialize,
     common::{
+		escrow_account,
 		get_program_test,
 		nescrow_ix_interface,
 	},
+    nescrow::{
+        generated::state::{Escrow, Schedule, Stream},
+        src::{
+            create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_OPEN},
+            oracle::ORACLE_KIND_EVENT,
+        },
+    },
     solana_program_test::tokio,
     solana_sdk::{
         account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
@@ -84,6 +93,7 @@ async fn extend_escrow_ix_success() {
 		&fee_payer_keypair,
 		escrow_pda,
 		&creator_keypair,
+		None,
 		counter,
 		new_expiry_time,
 		recent_blockhash,
@@ -95,3 +105,157 @@ async fn extend_escrow_ix_success() {
 	assert!(result.is_ok());
 
 }
+
+/// Extend by `extension` seconds an escrow in `status` expiring at 1000 and
+/// allowing 100 seconds of extension without the taker. Returns the escrow
+/// if the extension succeeded.
+async fn extend_with(status: u8, extension: i64, taker_signs: bool) -> Option<Escrow> {
+	extend_configured_with(status, extension, taker_signs, |_| {}).await
+}
+
+/// Same as `extend_with`, for an escrow further built by `configure`
+async fn extend_configured_with(
+	status: u8,
+	extension: i64,
+	taker_signs: bool,
+	configure: impl FnOnce(&mut Escrow),
+) -> Option<Escrow> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+	let expiry_time: i64 = 1_000;
+	let new_expiry_time: i64 = expiry_time + extension;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+	let taker_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+	let taker_pubkey = taker_keypair.pubkey();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [fee_payer_pubkey, creator_pubkey, taker_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: Pubkey::default(),
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	let mut escrow = Escrow {
+		creator: creator_pubkey,
+		taker: (status == ESCROW_STATUS_ACCEPTED).then_some(taker_pubkey),
+		amount: 1_000_000,
+		status,
+		expiry_time,
+		escrow_bump: escrow_pda_bump,
+		counter,
+		max_extension: 100,
+		..Default::default()
+	};
+	configure(&mut escrow);
+
+	program_test.add_account(escrow_pda, escrow_account(&escrow, 1_000_000));
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::extend_escrow_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&creator_keypair,
+		taker_signs.then_some(&taker_keypair),
+		counter,
+		new_expiry_time,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	assert_eq!(escrow.expiry_time, new_expiry_time);
+	Some(escrow)
+}
+
+#[tokio::test]
+async fn extend_escrow_ix_open_by_creator_alone() {
+	let escrow = extend_with(ESCROW_STATUS_OPEN, 1_000, false).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.max_extension, 100);
+}
+
+#[tokio::test]
+async fn extend_escrow_ix_accepted_within_agreed_maximum() {
+	let escrow = extend_with(ESCROW_STATUS_ACCEPTED, 60, false).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.max_extension, 40);
+}
+
+#[tokio::test]
+async fn extend_escrow_ix_accepted_beyond_maximum_requires_taker() {
+	// ASSERTIONS
+	assert!(extend_with(ESCROW_STATUS_ACCEPTED, 1_000, false).await.is_none());
+}
+
+#[tokio::test]
+async fn extend_escrow_ix_accepted_with_taker_consent() {
+	let escrow = extend_with(ESCROW_STATUS_ACCEPTED, 1_000, true).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.max_extension, 100);
+}
+
+#[tokio::test]
+async fn extend_escrow_ix_rejects_earlier_expiry() {
+	// ASSERTIONS
+	assert!(extend_with(ESCROW_STATUS_OPEN, -1, false).await.is_none());
+}
+
+#[tokio::test]
+async fn extend_escrow_ix_rejects_open_event_escrow() {
+	let settled_by_event = |escrow: &mut Escrow| escrow.oracle_kind = ORACLE_KIND_EVENT;
+
+	// ASSERTIONS
+	assert!(extend_configured_with(ESCROW_STATUS_OPEN, 1_000, false, settled_by_event).await.is_none());
+	assert!(extend_configured_with(ESCROW_STATUS_ACCEPTED, 1_000, true, settled_by_event).await.is_some());
+}
+
+#[tokio::test]
+async fn extend_escrow_ix_rejects_streams_and_recurring_payments() {
+	let stream = |escrow: &mut Escrow| escrow.stream = Some(Stream::default());
+	let schedule = |escrow: &mut Escrow| escrow.schedule = Some(Schedule::default());
+
+	// ASSERTIONS
+	assert!(extend_configured_with(ESCROW_STATUS_ACCEPTED, 1_000, true, stream).await.is_none());
+	assert!(extend_configured_with(ESCROW_STATUS_ACCEPTED, 1_000, true, schedule).await.is_none());
+}
+
+#[tokio::test]
+async fn extend_escrow_ix_rejects_overflowing_extension() {
+	let distant_expiry = |escrow: &mut Escrow| escrow.expiry_time = i64::MIN;
+
+	// ASSERTIONS
+	assert!(extend_configured_with(ESCROW_STATUS_ACCEPTED, 1_000, false, distant_expiry).await.is_none());
+}
@@ -0,0 +1,159 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Fill, Fills},
+        src::{
+            create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_OPEN},
+            oracle::ORACLE_KIND_EVENT,
+        },
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const AMOUNT: u64 = 10_000_000;
+const EXPIRY: i64 = 1_700_000_000;
+
+/// Fill each of `amounts` of a 10 million lamports escrow at `now`,
+/// returning the escrow and the taker's fill record if every fill succeeded
+async fn fill_with(amounts: &[u64], now: i64) -> Option<(Escrow, Fill)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let creator: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let taker_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let taker_pubkey = taker_keypair.pubkey();
+	let system_program_pubkey = Pubkey::default();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (fill_pda, _fill_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"fill",
+			escrow_pda.as_ref(),
+			taker_pubkey.as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [fee_payer_pubkey, taker_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: Pubkey::default(),
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator,
+				amount: AMOUNT,
+				status: ESCROW_STATUS_OPEN,
+				expiry_time: EXPIRY,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				oracle_kind: ORACLE_KIND_EVENT,
+				fills: Some(Fills {
+					min_fill: AMOUNT,
+					..Default::default()
+				}),
+				..Default::default()
+			},
+			AMOUNT,
+		),
+	);
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: now,
+		..Default::default()
+	});
+
+	for &amount in amounts {
+		let ix = nescrow_ix_interface::fill_escrow_ix_setup(
+			&fee_payer_keypair,
+			escrow_pda,
+			fill_pda,
+			&taker_keypair,
+			system_program_pubkey,
+			creator,
+			counter,
+			amount,
+			context.banks_client.get_latest_blockhash().await.unwrap(),
+		);
+
+		context.banks_client.process_transaction(ix).await.ok()?;
+	}
+
+	let escrow_account = context.banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let fill_account = context.banks_client.get_account(fill_pda).await.unwrap().unwrap();
+	Some((
+		Escrow::deserialize(&mut &escrow_account.data[..]).unwrap(),
+		Fill::deserialize(&mut &fill_account.data[..]).unwrap(),
+	))
+}
+
+#[tokio::test]
+async fn fill_escrow_ix_success() {
+	let (escrow, fill) = fill_with(&[3_000_000, 1_000_000], EXPIRY - 1).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.status, ESCROW_STATUS_OPEN);
+	assert_eq!(escrow.fills.unwrap().filled, 4_000_000);
+	assert_eq!(fill.amount, 4_000_000);
+}
+
+#[tokio::test]
+async fn fill_escrow_ix_accepts_when_fully_filled() {
+	let (escrow, fill) = fill_with(&[4_000_000, 6_000_000], EXPIRY - 1).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.status, ESCROW_STATUS_ACCEPTED);
+	assert_eq!(escrow.fills.unwrap().filled, AMOUNT);
+	assert_eq!(fill.amount, AMOUNT);
+}
+
+#[tokio::test]
+async fn fill_escrow_ix_rejects_overfill() {
+	// ASSERTIONS
+	assert!(fill_with(&[AMOUNT + 1], EXPIRY - 1).await.is_none());
+}
+
+#[tokio::test]
+async fn fill_escrow_ix_rejects_after_expiry() {
+	// ASSERTIONS
+	assert!(fill_with(&[1_000_000], EXPIRY).await.is_none());
+}
@@ -0,0 +1,128 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::Escrow,
+        src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_OPEN},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+/// Increase by 500 thousand lamports the stake of a 1 million lamport escrow
+/// in `status`, with or without the taker signing. Returns the escrow and
+/// its lamports above rent if the increase succeeded.
+async fn increase_with(status: u8, taker_signs: bool) -> Option<(Escrow, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+	let amount: u64 = 1_000_000;
+	let increase: u64 = 500_000;
+	let sides: u64 = if status == ESCROW_STATUS_ACCEPTED { 2 } else { 1 };
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+	let taker_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+	let taker_pubkey = taker_keypair.pubkey();
+	let system_program_pubkey = Pubkey::default();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [fee_payer_pubkey, creator_pubkey, taker_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: Pubkey::default(),
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator: creator_pubkey,
+				taker: (status == ESCROW_STATUS_ACCEPTED).then_some(taker_pubkey),
+				amount,
+				status,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				..Default::default()
+			},
+			sides * amount,
+		),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::increase_stake_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&creator_keypair,
+		system_program_pubkey,
+		taker_signs.then_some(&taker_keypair),
+		counter,
+		increase,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	let pot = escrow_account.lamports - Rent::default().minimum_balance(Escrow::LEN);
+	assert_eq!(pot, sides * escrow.amount);
+	Some((escrow, pot))
+}
+
+#[tokio::test]
+async fn increase_stake_ix_success() {
+	let (escrow, pot) = increase_with(ESCROW_STATUS_OPEN, false).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.amount, 1_500_000);
+	assert_eq!(pot, 1_500_000);
+}
+
+#[tokio::test]
+async fn increase_stake_ix_matched_by_taker() {
+	let (escrow, pot) = increase_with(ESCROW_STATUS_ACCEPTED, true).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.amount, 1_500_000);
+	assert_eq!(pot, 3_000_000);
+}
+
+#[tokio::test]
+async fn increase_stake_ix_requires_taker_once_accepted() {
+	// ASSERTIONS
+	assert!(increase_with(ESCROW_STATUS_ACCEPTED, false).await.is_none());
+}
@@ -0,0 +1,136 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		get_program_test,
+		nescrow_ix_interface,
+		pool_account,
+	},
+    nescrow::{
+        generated::state::{Participant, Pool},
+        src::create_pool::POOL_STATUS_OPEN,
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+const JOIN_DEADLINE: i64 = 1_700_000_000;
+const DEPOSIT: u64 = 2_000_000;
+
+/// Join at `now` a pool capped at two participants that already has
+/// `participants`. Returns the pool and its lamports if joining succeeded.
+async fn join_with(participants: u32, now: i64) -> Option<(Pool, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let organizer: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let participant_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let participant_pubkey = participant_keypair.pubkey();
+	let system_program_pubkey = Pubkey::default();
+
+	// PDA
+	let (pool_pda, pool_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"pool",
+			organizer.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (participant_record_pda, _participant_record_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"participant",
+			pool_pda.as_ref(),
+			participant_pubkey.as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [fee_payer_pubkey, participant_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: Pubkey::default(),
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	program_test.add_account(
+		pool_pda,
+		pool_account(&Pool {
+			organizer,
+			counter,
+			deposit: DEPOSIT,
+			max_participants: 2,
+			participants,
+			join_deadline: JOIN_DEADLINE,
+			status: POOL_STATUS_OPEN,
+			pool_bump: pool_pda_bump,
+			..Default::default()
+		}),
+	);
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: now,
+		..Default::default()
+	});
+
+	let ix = nescrow_ix_interface::join_pool_ix_setup(
+		&fee_payer_keypair,
+		pool_pda,
+		participant_record_pda,
+		&participant_keypair,
+		system_program_pubkey,
+		organizer,
+		counter,
+		context.last_blockhash,
+	);
+
+	context.banks_client.process_transaction(ix).await.ok()?;
+
+	let pool_account = context.banks_client.get_account(pool_pda).await.unwrap().unwrap();
+	let record_account = context.banks_client.get_account(participant_record_pda).await.unwrap().unwrap();
+	let record = Participant::deserialize(&mut &record_account.data[..]).unwrap();
+	assert_eq!(record.pool, pool_pda);
+	assert_eq!(record.participant, participant_pubkey);
+	Some((Pool::deserialize(&mut &pool_account.data[..]).unwrap(), pool_account.lamports))
+}
+
+#[tokio::test]
+async fn join_pool_ix_success() {
+	let (pool, lamports) = join_with(1, JOIN_DEADLINE).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(pool.participants, 2);
+	assert_eq!(lamports, Rent::default().minimum_balance(Pool::LEN) + 2 * DEPOSIT);
+}
+
+#[tokio::test]
+async fn join_pool_ix_rejects_full_pool() {
+	// ASSERTIONS
+	assert!(join_with(2, JOIN_DEADLINE).await.is_none());
+}
+
+#[tokio::test]
+async fn join_pool_ix_rejects_after_deadline() {
+	// ASSERTIONS
+	assert!(join_with(0, JOIN_DEADLINE + 1).await.is_none());
+}
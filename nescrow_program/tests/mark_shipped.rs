@@ -0,0 +1,117 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		get_program_test,
+		nescrow_ix_interface,
+		purchase_account,
+	},
+    nescrow::{
+        generated::state::Purchase,
+        src::create_purchase::{PURCHASE_STATUS_PAID, PURCHASE_STATUS_REFUNDED, PURCHASE_STATUS_SHIPPED},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const NOW: i64 = 1_700_000_000;
+
+/// Mark shipped at `NOW` a purchase in `status` with an inspection window of
+/// `inspection_window` seconds, returning the purchase if it was accepted
+async fn ship_with(status: u8, inspection_window: i64) -> Option<Purchase> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let buyer: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let seller_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let seller_pubkey = seller_keypair.pubkey();
+
+	// PDA
+	let (purchase_pda, purchase_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"purchase",
+			buyer.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		purchase_pda,
+		purchase_account(&Purchase {
+			buyer,
+			seller: seller_pubkey,
+			counter,
+			amount: 5_000_000,
+			inspection_window,
+			status,
+			purchase_bump: purchase_pda_bump,
+			..Default::default()
+		}),
+	);
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: NOW,
+		..Default::default()
+	});
+
+	let ix = nescrow_ix_interface::mark_shipped_ix_setup(
+		&fee_payer_keypair,
+		purchase_pda,
+		&seller_keypair,
+		buyer,
+		counter,
+		context.last_blockhash,
+	);
+
+	context.banks_client.process_transaction(ix).await.ok()?;
+
+	let purchase_account = context.banks_client.get_account(purchase_pda).await.unwrap().unwrap();
+	Some(Purchase::deserialize(&mut &purchase_account.data[..]).unwrap())
+}
+
+#[tokio::test]
+async fn mark_shipped_ix_success() {
+	let purchase = ship_with(PURCHASE_STATUS_PAID, 86_400).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(purchase.status, PURCHASE_STATUS_SHIPPED);
+	assert_eq!(purchase.inspection_deadline, NOW + 86_400);
+}
+
+#[tokio::test]
+async fn mark_shipped_ix_rejects_settled_purchase() {
+	// ASSERTIONS
+	assert!(ship_with(PURCHASE_STATUS_SHIPPED, 86_400).await.is_none());
+	assert!(ship_with(PURCHASE_STATUS_REFUNDED, 86_400).await.is_none());
+}
+
+#[tokio::test]
+async fn mark_shipped_ix_rejects_overflowing_deadline() {
+	// ASSERTIONS
+	assert!(ship_with(PURCHASE_STATUS_PAID, i64::MAX).await.is_none());
+}
@@ -0,0 +1,155 @@
+pub mod common;
+
+use {
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Stream},
+        src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_OPEN},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+const AMOUNT: u64 = 2_000_000;
+const PARTY_LAMPORTS: u64 = 1_000_000_000;
+
+/// Mutually cancel an escrow built by `configure`, refunding rent to the
+/// recorded rent payer or to a stranger. Returns whether the escrow account
+/// is gone and the creator's, taker's and rent payer's lamports if the
+/// cancellation succeeded.
+async fn cancel_with(
+	configure: impl FnOnce(&mut Escrow),
+	refund_recorded_payer: bool,
+) -> Option<(bool, u64, u64, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+	let taker_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+	let taker_pubkey = taker_keypair.pubkey();
+	let rent_payer_pubkey = Pubkey::new_unique();
+	let refund_pubkey = if refund_recorded_payer { rent_payer_pubkey } else { Pubkey::new_unique() };
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	for pubkey in [creator_pubkey, taker_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: PARTY_LAMPORTS,
+				data: vec![],
+				owner: Pubkey::default(),
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	let mut escrow = Escrow {
+		creator: creator_pubkey,
+		taker: Some(taker_pubkey),
+		amount: AMOUNT,
+		status: ESCROW_STATUS_ACCEPTED,
+		escrow_bump: escrow_pda_bump,
+		counter,
+		rent_payer: rent_payer_pubkey,
+		..Default::default()
+	};
+	configure(&mut escrow);
+
+	program_test.add_account(escrow_pda, escrow_account(&escrow, 2 * AMOUNT));
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::mutual_cancel_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&creator_keypair,
+		&taker_keypair,
+		refund_pubkey,
+		counter,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let lamports = |account: Option<Account>| account.map_or(0, |account| account.lamports);
+	Some((
+		banks_client.get_account(escrow_pda).await.unwrap().is_none(),
+		lamports(banks_client.get_account(creator_pubkey).await.unwrap()),
+		lamports(banks_client.get_account(taker_pubkey).await.unwrap()),
+		lamports(banks_client.get_account(rent_payer_pubkey).await.unwrap()),
+	))
+}
+
+#[tokio::test]
+async fn mutual_cancel_ix_success() {
+	let (closed, creator_lamports, taker_lamports, rent_payer_lamports) =
+		cancel_with(|_| {}, true).await.unwrap();
+
+	// ASSERTIONS
+	assert!(closed);
+	assert_eq!(creator_lamports, PARTY_LAMPORTS + AMOUNT);
+	assert_eq!(taker_lamports, PARTY_LAMPORTS + AMOUNT);
+	assert_eq!(rent_payer_lamports, Rent::default().minimum_balance(Escrow::LEN));
+}
+
+#[tokio::test]
+async fn mutual_cancel_ix_rejects_other_rent_recipient() {
+	// ASSERTIONS
+	assert!(cancel_with(|_| {}, false).await.is_none());
+}
+
+#[tokio::test]
+async fn mutual_cancel_ix_rejects_open_escrow() {
+	// ASSERTIONS
+	assert!(cancel_with(|escrow| escrow.status = ESCROW_STATUS_OPEN, true).await.is_none());
+}
+
+#[tokio::test]
+async fn mutual_cancel_ix_rejects_stream() {
+	// ASSERTIONS
+	assert!(cancel_with(|escrow| escrow.stream = Some(Stream::default()), true).await.is_none());
+}
+
+#[tokio::test]
+async fn mutual_cancel_ix_rejects_escrow_from_current_slot() {
+	// ASSERTIONS
+	// Not created before the current slot, so its address cannot be freed yet
+	assert!(cancel_with(|escrow| escrow.created_slot = u64::MAX, true).await.is_none());
+}
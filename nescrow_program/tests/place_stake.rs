@@ -0,0 +1,142 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		get_program_test,
+		market_account,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Market, Position},
+        src::create_market::MARKET_STATUS_OPEN,
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const CUTOFF: i64 = 1_700_000_000;
+
+/// Place each of `stakes` on `outcome` of a three outcome market at `now`,
+/// returning the market and the owner's position if every stake succeeded
+async fn stake_with(outcome: u8, stakes: &[u64], now: i64) -> Option<(Market, Position)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let creator: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let owner_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let owner_pubkey = owner_keypair.pubkey();
+	let system_program_pubkey = Pubkey::default();
+
+	// PDA
+	let (market_pda, market_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"market",
+			creator.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (position_pda, _position_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"position",
+			market_pda.as_ref(),
+			owner_pubkey.as_ref(),
+			&[outcome],
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [fee_payer_pubkey, owner_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: Pubkey::default(),
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	program_test.add_account(
+		market_pda,
+		market_account(
+			&Market {
+				creator,
+				counter,
+				cutoff: CUTOFF,
+				outcome_totals: vec![0, 5_000_000, 0],
+				status: MARKET_STATUS_OPEN,
+				market_bump: market_pda_bump,
+				..Default::default()
+			},
+			5_000_000,
+		),
+	);
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: now,
+		..Default::default()
+	});
+
+	for &stake in stakes {
+		let ix = nescrow_ix_interface::place_stake_ix_setup(
+			&fee_payer_keypair,
+			market_pda,
+			position_pda,
+			&owner_keypair,
+			system_program_pubkey,
+			creator,
+			counter,
+			outcome,
+			stake,
+			context.banks_client.get_latest_blockhash().await.unwrap(),
+		);
+
+		context.banks_client.process_transaction(ix).await.ok()?;
+	}
+
+	let market_account = context.banks_client.get_account(market_pda).await.unwrap().unwrap();
+	let position_account = context.banks_client.get_account(position_pda).await.unwrap().unwrap();
+	Some((
+		Market::deserialize(&mut &market_account.data[..]).unwrap(),
+		Position::deserialize(&mut &position_account.data[..]).unwrap(),
+	))
+}
+
+#[tokio::test]
+async fn place_stake_ix_success() {
+	let (market, position) = stake_with(0, &[3_000_000, 1_000_000], CUTOFF).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(market.outcome_totals, vec![4_000_000, 5_000_000, 0]);
+	assert_eq!(position.outcome, 0);
+	assert_eq!(position.stake, 4_000_000);
+}
+
+#[tokio::test]
+async fn place_stake_ix_rejects_after_cutoff() {
+	// ASSERTIONS
+	assert!(stake_with(0, &[3_000_000], CUTOFF + 1).await.is_none());
+}
+
+#[tokio::test]
+async fn place_stake_ix_rejects_unknown_outcome() {
+	// ASSERTIONS
+	assert!(stake_with(3, &[3_000_000], CUTOFF).await.is_none());
+}
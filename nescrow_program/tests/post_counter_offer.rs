@@ -0,0 +1,138 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{CounterOffer, Escrow},
+        src::create_escrow::ESCROW_STATUS_OPEN,
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+const NOW: i64 = 1_700_000_000;
+
+/// Offer `amount` until `expiry_time` on an open 5 million lamports
+/// escrow, returning the counter-offer and its lamports if posting succeeded
+async fn post_with(amount: u64, expiry_time: i64) -> Option<(CounterOffer, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let creator: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let taker_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let taker_pubkey = taker_keypair.pubkey();
+	let system_program_pubkey = Pubkey::default();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (counter_offer_pda, _counter_offer_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"counter_offer",
+			escrow_pda.as_ref(),
+			taker_pubkey.as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [fee_payer_pubkey, taker_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: Pubkey::default(),
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator,
+				amount: 5_000_000,
+				status: ESCROW_STATUS_OPEN,
+				expiry_time: NOW + 3600,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				..Default::default()
+			},
+			5_000_000,
+		),
+	);
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: NOW,
+		..Default::default()
+	});
+
+	let ix = nescrow_ix_interface::post_counter_offer_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		counter_offer_pda,
+		&taker_keypair,
+		system_program_pubkey,
+		creator,
+		counter,
+		amount,
+		expiry_time,
+		context.last_blockhash,
+	);
+
+	context.banks_client.process_transaction(ix).await.ok()?;
+
+	let counter_offer_account = context.banks_client.get_account(counter_offer_pda).await.unwrap().unwrap();
+	Some((
+		CounterOffer::deserialize(&mut &counter_offer_account.data[..]).unwrap(),
+		counter_offer_account.lamports,
+	))
+}
+
+#[tokio::test]
+async fn post_counter_offer_ix_success() {
+	let (counter_offer, lamports) = post_with(3_000_000, NOW + 7200).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(counter_offer.amount, 3_000_000);
+	assert_eq!(counter_offer.expiry_time, NOW + 7200);
+	assert_eq!(lamports, Rent::default().minimum_balance(CounterOffer::LEN) + 3_000_000);
+}
+
+#[tokio::test]
+async fn post_counter_offer_ix_rejects_zero_stake() {
+	// ASSERTIONS
+	assert!(post_with(0, NOW + 7200).await.is_none());
+}
+
+#[tokio::test]
+async fn post_counter_offer_ix_rejects_past_expiry() {
+	// ASSERTIONS
+	assert!(post_with(3_000_000, NOW).await.is_none());
+}
@@ -0,0 +1,138 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		get_program_test,
+		milestone_escrow_account,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Milestone, MilestoneEscrow},
+        src::create_milestone_escrow::{
+            MILESTONE_STATUS_DISPUTED, MILESTONE_STATUS_PENDING, MILESTONE_STATUS_REFUNDED,
+            MILESTONE_STATUS_RELEASED,
+        },
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const FIRST_DEADLINE: i64 = 1_600_000_000;
+const FINAL_DEADLINE: i64 = 1_700_000_000;
+
+/// Reclaim at `now` from an escrow with a released, a disputed and a pending
+/// milestone due at the first deadline and a pending one due at the final
+/// deadline, returning the escrow and the client's lamports
+async fn reclaim_at(now: i64) -> Option<(MilestoneEscrow, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let client_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let client_pubkey = client_keypair.pubkey();
+	let worker_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestones",
+			client_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		milestone_escrow_account(
+			&MilestoneEscrow {
+				client: client_pubkey,
+				worker: worker_pubkey,
+				counter,
+				milestones: vec![
+					Milestone { amount: 1_000_000, deadline: FIRST_DEADLINE, status: MILESTONE_STATUS_RELEASED, ..Default::default() },
+					Milestone { amount: 2_000_000, deadline: FIRST_DEADLINE, status: MILESTONE_STATUS_DISPUTED, ..Default::default() },
+					Milestone { amount: 3_000_000, deadline: FIRST_DEADLINE, status: MILESTONE_STATUS_PENDING, ..Default::default() },
+					Milestone { amount: 4_000_000, deadline: FINAL_DEADLINE, status: MILESTONE_STATUS_PENDING, ..Default::default() },
+				],
+				escrow_bump: escrow_pda_bump,
+			},
+			9_000_000,
+		),
+	);
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: now,
+		..Default::default()
+	});
+
+	let ix = nescrow_ix_interface::reclaim_milestones_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&client_keypair,
+		counter,
+		context.last_blockhash,
+	);
+
+	context.banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = context.banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = MilestoneEscrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	let client_account = context.banks_client.get_account(client_pubkey).await.unwrap().unwrap();
+	Some((escrow, client_account.lamports))
+}
+
+#[tokio::test]
+async fn reclaim_milestones_ix_success() {
+	let (escrow, client_lamports) = reclaim_at(FINAL_DEADLINE + 1).await.unwrap();
+	let statuses: Vec<u8> = escrow.milestones.iter().map(|milestone| milestone.status).collect();
+
+	// ASSERTIONS
+	assert_eq!(client_lamports, 9_000_000);
+	assert_eq!(
+		statuses,
+		vec![MILESTONE_STATUS_RELEASED, MILESTONE_STATUS_REFUNDED, MILESTONE_STATUS_REFUNDED, MILESTONE_STATUS_REFUNDED],
+	);
+}
+
+#[tokio::test]
+async fn reclaim_milestones_ix_keeps_disputed_until_final_deadline() {
+	let (escrow, client_lamports) = reclaim_at(FINAL_DEADLINE).await.unwrap();
+	let statuses: Vec<u8> = escrow.milestones.iter().map(|milestone| milestone.status).collect();
+
+	// ASSERTIONS
+	assert_eq!(client_lamports, 3_000_000);
+	assert_eq!(
+		statuses,
+		vec![MILESTONE_STATUS_RELEASED, MILESTONE_STATUS_DISPUTED, MILESTONE_STATUS_REFUNDED, MILESTONE_STATUS_PENDING],
+	);
+}
+
+#[tokio::test]
+async fn reclaim_milestones_ix_requires_a_passed_deadline() {
+	// ASSERTIONS
+	assert!(reclaim_at(FIRST_DEADLINE).await.is_none());
+}
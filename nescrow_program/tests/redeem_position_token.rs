@@ -0,0 +1,257 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Outcome},
+        src::{
+            create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED},
+            oracle::{attestation_message, ORACLE_KIND_ATTESTATION},
+            spl_token::{
+                associated_token_address, create_associated_token_account, ASSOCIATED_TOKEN_PROGRAM_ID,
+                TOKEN_PROGRAM_ID,
+            },
+            tokenize_position::POSITION_SIDE_TAKER,
+        },
+    },
+    solana_ed25519_program::new_ed25519_instruction_with_signature,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        sysvar,
+        transaction::Transaction,
+    },
+};
+
+const AMOUNT: u64 = 1_000_000;
+const BALANCE: u64 = 1_000_000_000;
+
+fn attestation(signer: &Keypair, escrow: &Pubkey, created_slot: u64, outcome: Outcome) -> Instruction {
+	let message = attestation_message(escrow, created_slot, outcome);
+	let signature = signer.sign_message(&message);
+
+	new_ed25519_instruction_with_signature(
+		&message,
+		signature.as_ref().try_into().unwrap(),
+		&signer.pubkey().to_bytes(),
+	)
+}
+
+/// SPL Token `Transfer` of `amount` from `source` to `destination`
+fn token_transfer(source: &Pubkey, destination: &Pubkey, owner: &Pubkey, amount: u64) -> Instruction {
+	let mut data = vec![3];
+	data.extend_from_slice(&amount.to_le_bytes());
+
+	Instruction {
+		program_id: TOKEN_PROGRAM_ID,
+		accounts: vec![
+			AccountMeta::new(*source, false),
+			AccountMeta::new(*destination, false),
+			AccountMeta::new_readonly(*owner, true),
+		],
+		data,
+	}
+}
+
+/// Tokenize the taker's side, sell the token to a buyer if `sell_token`,
+/// resolve the escrow with `outcome` and have `redeem_by_buyer` pick who
+/// redeems. Returns the lamports gained by the taker and the buyer if the
+/// token was redeemed.
+async fn redeem_with(outcome: Outcome, sell_token: bool, redeem_by_buyer: bool) -> Option<(u64, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+	let side = POSITION_SIDE_TAKER;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let resolver_keypair = Keypair::new();
+	let taker_keypair = Keypair::new();
+	let buyer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = Pubkey::new_unique();
+	let taker_pubkey = taker_keypair.pubkey();
+	let buyer_pubkey = buyer_keypair.pubkey();
+	let system_program_pubkey = Pubkey::default();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (mint_pda, _) = Pubkey::find_program_address(
+		&[b"position_mint", escrow_pda.as_ref(), &[side]],
+		&nescrow_ix_interface::ID,
+	);
+	let taker_token_pubkey = associated_token_address(&taker_pubkey, &mint_pda);
+	let buyer_token_pubkey = associated_token_address(&buyer_pubkey, &mint_pda);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	for pubkey in [taker_pubkey, buyer_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: BALANCE,
+				data: vec![],
+				owner: Pubkey::default(),
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator: creator_pubkey,
+				taker: Some(taker_pubkey),
+				amount: AMOUNT,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				oracle_kind: ORACLE_KIND_ATTESTATION,
+				oracle_account: resolver_keypair.pubkey(),
+				..Default::default()
+			},
+			2 * AMOUNT,
+		),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::tokenize_position_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&taker_keypair,
+		mint_pda,
+		taker_token_pubkey,
+		system_program_pubkey,
+		TOKEN_PROGRAM_ID,
+		ASSOCIATED_TOKEN_PROGRAM_ID,
+		creator_pubkey,
+		counter,
+		side,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.unwrap();
+
+	if sell_token {
+		let mut transaction = Transaction::new_with_payer(
+			&[
+				create_associated_token_account(&fee_payer_pubkey, &buyer_pubkey, &mint_pda),
+				token_transfer(&taker_token_pubkey, &buyer_token_pubkey, &taker_pubkey, 1),
+			],
+			Some(&fee_payer_pubkey),
+		);
+		transaction.sign(&[&fee_payer_keypair, &taker_keypair], recent_blockhash);
+
+		banks_client.process_transaction(transaction).await.unwrap();
+	}
+
+	let winner_pubkey = match outcome {
+		Outcome::CreatorWins => creator_pubkey,
+		Outcome::TakerWins => taker_pubkey,
+	};
+
+	let ix = nescrow_ix_interface::resolve_escrow_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		sysvar::instructions::ID,
+		winner_pubkey,
+		Some(attestation(&resolver_keypair, &escrow_pda, 0, outcome)),
+		creator_pubkey,
+		counter,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	assert_eq!(escrow.status, ESCROW_STATUS_COMPLETED);
+
+	let (holder_keypair, holder_token_pubkey) = if redeem_by_buyer {
+		(&buyer_keypair, buyer_token_pubkey)
+	} else {
+		(&taker_keypair, taker_token_pubkey)
+	};
+
+	let ix = nescrow_ix_interface::redeem_position_token_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		mint_pda,
+		holder_token_pubkey,
+		holder_keypair,
+		TOKEN_PROGRAM_ID,
+		creator_pubkey,
+		counter,
+		side,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	assert_eq!(escrow.position_tokens.held, 0);
+
+	let taker_account = banks_client.get_account(taker_pubkey).await.unwrap().unwrap();
+	let buyer_account = banks_client.get_account(buyer_pubkey).await.unwrap().unwrap();
+
+	Some((taker_account.lamports - BALANCE, buyer_account.lamports - BALANCE))
+}
+
+#[tokio::test]
+async fn redeem_position_token_ix_success() {
+	// ASSERTIONS
+	assert_eq!(redeem_with(Outcome::TakerWins, false, false).await, Some((2 * AMOUNT, 0)));
+}
+
+#[tokio::test]
+async fn redeem_position_token_ix_pays_holder_of_token() {
+	// ASSERTIONS
+	assert_eq!(redeem_with(Outcome::TakerWins, true, true).await, Some((0, 2 * AMOUNT)));
+}
+
+#[tokio::test]
+async fn redeem_position_token_ix_rejects_party_without_token() {
+	// ASSERTIONS
+	assert!(redeem_with(Outcome::TakerWins, true, false).await.is_none());
+}
+
+#[tokio::test]
+async fn redeem_position_token_ix_rejects_losing_side() {
+	// ASSERTIONS
+	assert!(redeem_with(Outcome::CreatorWins, false, false).await.is_none());
+}
@@ -0,0 +1,174 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		event_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Event, EventCondition, Fills},
+        src::{
+            create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_CANCELLED, ESCROW_STATUS_COMPLETED},
+            oracle::ORACLE_KIND_EVENT,
+        },
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+const AMOUNT: u64 = 1_000_000;
+
+/// Refund a Home (creator) vs Away (taker) wager on an event with the given
+/// result, taken by a single taker or through fills. Returns the escrow
+/// and the lamports of its account, the creator and the taker if the
+/// refund succeeded.
+async fn refund_with(result: Option<u8>, through_fills: bool) -> Option<(Escrow, u64, u64, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let creator: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+	let event_id: u64 = 1;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let taker_pubkey = Pubkey::new_unique();
+	let reporter_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (event_pda, event_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"event",
+			reporter_pubkey.as_ref(),
+			event_id.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		event_pda,
+		event_account(&Event {
+			reporter: reporter_pubkey,
+			event_id,
+			start_time: 0,
+			outcomes: vec!["Home".to_string(), "Draw".to_string(), "Away".to_string()],
+			result,
+			score: None,
+			event_bump: event_pda_bump,
+		}),
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator,
+				taker: (!through_fills).then_some(taker_pubkey),
+				amount: AMOUNT,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				oracle_kind: ORACLE_KIND_EVENT,
+				oracle_account: event_pda,
+				event_condition: Some(EventCondition {
+					creator_outcome: 0,
+					taker_outcome: 2,
+				}),
+				fills: through_fills.then_some(Fills {
+					min_fill: AMOUNT,
+					filled: AMOUNT,
+					unclaimed: 0,
+				}),
+				..Default::default()
+			},
+			2 * AMOUNT,
+		),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::refund_event_escrow_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		event_pda,
+		creator,
+		taker_pubkey,
+		counter,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let lamports = |account: Option<Account>| account.map_or(0, |account| account.lamports);
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let creator_lamports = lamports(banks_client.get_account(creator).await.unwrap());
+	let taker_lamports = lamports(banks_client.get_account(taker_pubkey).await.unwrap());
+
+	Some((
+		Escrow::deserialize(&mut &escrow_account.data[..]).unwrap(),
+		escrow_account.lamports,
+		creator_lamports,
+		taker_lamports,
+	))
+}
+
+#[tokio::test]
+async fn refund_event_escrow_ix_success() {
+	let (escrow, escrow_lamports, creator_lamports, taker_lamports) = refund_with(Some(1), false).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.status, ESCROW_STATUS_COMPLETED);
+	assert_eq!(escrow.winner, None);
+	assert_eq!(escrow_lamports, Rent::default().minimum_balance(Escrow::LEN));
+	assert_eq!(creator_lamports, AMOUNT);
+	assert_eq!(taker_lamports, AMOUNT);
+}
+
+#[tokio::test]
+async fn refund_event_escrow_ix_leaves_fills_to_claim() {
+	let (escrow, escrow_lamports, creator_lamports, taker_lamports) = refund_with(Some(1), true).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.status, ESCROW_STATUS_CANCELLED);
+	assert_eq!(escrow_lamports, Rent::default().minimum_balance(Escrow::LEN) + AMOUNT);
+	assert_eq!(creator_lamports, AMOUNT);
+	assert_eq!(taker_lamports, 0);
+}
+
+#[tokio::test]
+async fn refund_event_escrow_ix_requires_unbacked_result() {
+	// ASSERTIONS
+	assert!(refund_with(None, false).await.is_none());
+	assert!(refund_with(Some(0), false).await.is_none());
+	assert!(refund_with(Some(2), true).await.is_none());
+}
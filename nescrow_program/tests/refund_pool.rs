@@ -0,0 +1,154 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		get_program_test,
+		nescrow_ix_interface,
+		participant_account,
+		pool_account,
+	},
+    nescrow::{
+        generated::state::{Participant, Pool},
+        src::create_pool::{POOL_STATUS_OPEN, POOL_STATUS_REFUNDED, POOL_STATUS_SETTLED},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+    },
+};
+
+const DEPOSIT: u64 = 2_000_000;
+const SETTLE_DEADLINE: i64 = 1_700_000_000;
+
+/// Refund at `now` a two participant pool in `status` that could be
+/// settled until `SETTLE_DEADLINE`, then have its first participant claim.
+/// Returns the pool and the participant's lamports if both succeeded.
+async fn refund_at(status: u8, now: i64) -> Option<(Pool, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let organizer: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+	let participants: Vec<Pubkey> = (0..2).map(|_| Pubkey::new_unique()).collect();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+
+	// PDA
+	let (pool_pda, pool_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"pool",
+			organizer.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let record_pda = |participant: &Pubkey| Pubkey::find_program_address(
+		&[
+			b"participant",
+			pool_pda.as_ref(),
+			participant.as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		pool_pda,
+		pool_account(&Pool {
+			organizer,
+			counter,
+			deposit: DEPOSIT,
+			max_participants: 2,
+			participants: 2,
+			join_deadline: SETTLE_DEADLINE - 1_000,
+			settle_deadline: SETTLE_DEADLINE,
+			status,
+			pool_bump: pool_pda_bump,
+			..Default::default()
+		}),
+	);
+
+	for participant in &participants {
+		let (record, record_bump) = record_pda(participant);
+		program_test.add_account(
+			record,
+			participant_account(&Participant {
+				pool: pool_pda,
+				participant: *participant,
+				participant_bump: record_bump,
+			}),
+		);
+	}
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: now,
+		..Default::default()
+	});
+
+	let ix = nescrow_ix_interface::refund_pool_ix_setup(
+		&fee_payer_keypair,
+		pool_pda,
+		organizer,
+		counter,
+		context.last_blockhash,
+	);
+
+	context.banks_client.process_transaction(ix).await.ok()?;
+
+	let ix = nescrow_ix_interface::claim_pool_ix_setup(
+		&fee_payer_keypair,
+		pool_pda,
+		record_pda(&participants[0]).0,
+		participants[0],
+		organizer,
+		counter,
+		context.last_blockhash,
+	);
+
+	context.banks_client.process_transaction(ix).await.ok()?;
+
+	let pool_account = context.banks_client.get_account(pool_pda).await.unwrap().unwrap();
+	let pool = Pool::deserialize(&mut &pool_account.data[..]).unwrap();
+	let participant_account = context.banks_client.get_account(participants[0]).await.unwrap().unwrap();
+	Some((pool, participant_account.lamports))
+}
+
+#[tokio::test]
+async fn refund_pool_ix_success() {
+	let (pool, participant_lamports) = refund_at(POOL_STATUS_OPEN, SETTLE_DEADLINE + 1).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(pool.status, POOL_STATUS_REFUNDED);
+	assert_eq!(participant_lamports, DEPOSIT + Rent::default().minimum_balance(Participant::LEN));
+}
+
+#[tokio::test]
+async fn refund_pool_ix_rejects_before_settle_deadline() {
+	// ASSERTIONS
+	assert!(refund_at(POOL_STATUS_OPEN, SETTLE_DEADLINE).await.is_none());
+}
+
+#[tokio::test]
+async fn refund_pool_ix_rejects_settled_pool() {
+	// ASSERTIONS
+	assert!(refund_at(POOL_STATUS_SETTLED, SETTLE_DEADLINE + 1).await.is_none());
+}
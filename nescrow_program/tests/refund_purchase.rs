@@ -0,0 +1,133 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		get_program_test,
+		nescrow_ix_interface,
+		purchase_account,
+	},
+    nescrow::{
+        generated::state::Purchase,
+        src::create_purchase::{
+            PURCHASE_STATUS_DISPUTED, PURCHASE_STATUS_PAID, PURCHASE_STATUS_REFUNDED, PURCHASE_STATUS_SHIPPED,
+        },
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const AMOUNT: u64 = 5_000_000;
+const BUYER_LAMPORTS: u64 = 1_000_000_000;
+
+/// Refund a purchase in `status`, signed by the seller or by the buyer.
+/// Returns the purchase and the buyer's lamports if the refund succeeded.
+async fn refund_with(status: u8, seller_signs: bool) -> Option<(Purchase, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let buyer_keypair = Keypair::new();
+	let seller_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let buyer_pubkey = buyer_keypair.pubkey();
+	let seller_pubkey = seller_keypair.pubkey();
+
+	// PDA
+	let (purchase_pda, purchase_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"purchase",
+			buyer_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		buyer_pubkey,
+		Account {
+			lamports: BUYER_LAMPORTS,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		purchase_pda,
+		purchase_account(&Purchase {
+			buyer: buyer_pubkey,
+			seller: seller_pubkey,
+			counter,
+			amount: AMOUNT,
+			inspection_window: 86_400,
+			status,
+			purchase_bump: purchase_pda_bump,
+			..Default::default()
+		}),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let party_keypair = if seller_signs { &seller_keypair } else { &buyer_keypair };
+
+	let ix = nescrow_ix_interface::refund_purchase_ix_setup(
+		&fee_payer_keypair,
+		purchase_pda,
+		party_keypair,
+		buyer_pubkey,
+		counter,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let purchase_account = banks_client.get_account(purchase_pda).await.unwrap().unwrap();
+	let buyer_account = banks_client.get_account(buyer_pubkey).await.unwrap().unwrap();
+	Some((Purchase::deserialize(&mut &purchase_account.data[..]).unwrap(), buyer_account.lamports))
+}
+
+#[tokio::test]
+async fn refund_purchase_ix_by_seller_after_dispute() {
+	let (purchase, buyer_lamports) = refund_with(PURCHASE_STATUS_DISPUTED, true).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(purchase.status, PURCHASE_STATUS_REFUNDED);
+	assert_eq!(buyer_lamports, BUYER_LAMPORTS + AMOUNT);
+}
+
+#[tokio::test]
+async fn refund_purchase_ix_by_buyer_before_shipping() {
+	let (purchase, buyer_lamports) = refund_with(PURCHASE_STATUS_PAID, false).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(purchase.status, PURCHASE_STATUS_REFUNDED);
+	assert_eq!(buyer_lamports, BUYER_LAMPORTS + AMOUNT);
+}
+
+#[tokio::test]
+async fn refund_purchase_ix_rejects_buyer_after_shipping() {
+	// ASSERTIONS
+	assert!(refund_with(PURCHASE_STATUS_SHIPPED, false).await.is_none());
+}
@@ -0,0 +1,460 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		event_account,
+		get_program_test,
+		nescrow_ix_interface,
+		release_conditions_account,
+	},
+    nescrow::{
+        generated::state::{ConditionNode, Escrow, Event, Fills, Outcome, ReleaseConditions, Schedule, Stream},
+        src::{
+            conditions::preimage_hash,
+            create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED},
+        },
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const SECRET: &[u8] = b"delivered";
+const AMOUNT: u64 = 1_000_000;
+
+/// Release to the taker, whose tree needs two of: the secret revealed, the
+/// arbiter's signature and the event reporting outcome 1. The conditions
+/// were set for an earlier escrow at the same address if `stale`, and the
+/// escrow is further built by `configure`. Returns the lamports received by
+/// the taker.
+async fn release_with(
+	preimages: Vec<Vec<u8>>,
+	arbiter_signs: bool,
+	event_result: Option<u8>,
+	stale: bool,
+	configure: impl FnOnce(&mut Escrow),
+) -> Option<u64> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let creator: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+	let amount: u64 = 1_000_000;
+	let event_id: u64 = 1;
+	let created_slot: u64 = 5;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let arbiter_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let taker_pubkey = Pubkey::new_unique();
+	let reporter_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (conditions_pda, conditions_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"conditions",
+			escrow_pda.as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (event_pda, event_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"event",
+			reporter_pubkey.as_ref(),
+			event_id.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	let mut escrow = Escrow {
+		creator,
+		taker: Some(taker_pubkey),
+		amount,
+		status: ESCROW_STATUS_ACCEPTED,
+		escrow_bump: escrow_pda_bump,
+		counter,
+		created_slot,
+		..Default::default()
+	};
+	configure(&mut escrow);
+
+	program_test.add_account(escrow_pda, escrow_account(&escrow, 2 * amount));
+
+	program_test.add_account(
+		conditions_pda,
+		release_conditions_account(&ReleaseConditions {
+			escrow: escrow_pda,
+			escrow_created_slot: if stale { created_slot - 1 } else { created_slot },
+			creator_wins: vec![],
+			taker_wins: vec![
+				ConditionNode::HashRevealed { hash: preimage_hash(SECRET) },
+				ConditionNode::SignedBy { signer: arbiter_keypair.pubkey() },
+				ConditionNode::EventResult { event: event_pda, outcome: 1 },
+				ConditionNode::Threshold { count: 2, children: vec![0, 1, 2] },
+			],
+			conditions_bump: conditions_pda_bump,
+		}),
+	);
+
+	program_test.add_account(
+		event_pda,
+		event_account(&Event {
+			reporter: reporter_pubkey,
+			event_id,
+			start_time: 0,
+			outcomes: vec!["Late".to_string(), "On time".to_string()],
+			result: event_result,
+			score: None,
+			event_bump: event_pda_bump,
+		}),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let signers: &[&Keypair] = if arbiter_signs { &[&arbiter_keypair] } else { &[] };
+
+	let ix = nescrow_ix_interface::release_if_conditions_met_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		conditions_pda,
+		taker_pubkey,
+		signers,
+		&[event_pda],
+		creator,
+		counter,
+		preimages,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	assert_eq!(escrow.status, ESCROW_STATUS_COMPLETED);
+	assert_eq!(escrow.winner, Some(taker_pubkey));
+
+	let taker_account = banks_client.get_account(taker_pubkey).await.unwrap().unwrap();
+	Some(taker_account.lamports)
+}
+
+#[tokio::test]
+async fn release_if_conditions_met_ix_success() {
+	// ASSERTIONS
+	assert_eq!(release_with(vec![SECRET.to_vec()], true, None, false, |_| {}).await, Some(2_000_000));
+	assert_eq!(release_with(vec![SECRET.to_vec()], false, Some(1), false, |_| {}).await, Some(2_000_000));
+}
+
+#[tokio::test]
+async fn release_if_conditions_met_ix_rejects_unmet_conditions() {
+	// ASSERTIONS
+	assert_eq!(release_with(vec![SECRET.to_vec()], false, None, false, |_| {}).await, None);
+	assert_eq!(release_with(vec![b"wrong".to_vec()], true, Some(0), false, |_| {}).await, None);
+}
+
+#[tokio::test]
+async fn release_if_conditions_met_ix_rejects_conditions_of_closed_escrow() {
+	// ASSERTIONS
+	assert_eq!(release_with(vec![SECRET.to_vec()], true, None, true, |_| {}).await, None);
+}
+
+#[tokio::test]
+async fn release_if_conditions_met_ix_rejects_streams_and_recurring_payments() {
+	let stream = |escrow: &mut Escrow| escrow.stream = Some(Stream::default());
+	let schedule = |escrow: &mut Escrow| escrow.schedule = Some(Schedule::default());
+
+	// ASSERTIONS
+	assert_eq!(release_with(vec![SECRET.to_vec()], true, None, false, stream).await, None);
+	assert_eq!(release_with(vec![SECRET.to_vec()], true, None, false, schedule).await, None);
+}
+
+/// How a leg of a parlay stands when it is released or refunded
+#[derive(Clone, Copy)]
+enum Leg {
+	Won(Outcome),
+	Pending,
+	/// Completed without a winner, such as a pushed line
+	Pushed,
+	/// Closed and re-created at the same address after the tree was set
+	Recreated(Outcome),
+	/// Taken through fills and won by its takers, which completes without a winner
+	FillsTakersWon,
+}
+
+/// How a parlay is settled: released to the creator or the taker, or refunded
+#[derive(Clone, Copy)]
+enum Settle {
+	Release { to_creator: bool },
+	Refund,
+}
+
+/// Settle a parlay on two other escrows. The creator backs the creator of
+/// the first leg and the taker of the second. Returns the lamports received
+/// by the creator and the taker.
+async fn parlay_with(legs: [Leg; 2], settle: Settle) -> Option<(u64, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+	let picks = [Outcome::CreatorWins, Outcome::TakerWins];
+	let leg_created_slot: u64 = 3;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = Pubkey::new_unique();
+	let taker_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (conditions_pda, conditions_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"conditions",
+			escrow_pda.as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator: creator_pubkey,
+				taker: Some(taker_pubkey),
+				amount: AMOUNT,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				..Default::default()
+			},
+			2 * AMOUNT,
+		),
+	);
+
+	let mut leg_pdas = vec![];
+	for leg in legs {
+		let leg_creator = Pubkey::new_unique();
+		let leg_taker = Pubkey::new_unique();
+		let (leg_pda, leg_pda_bump) = Pubkey::find_program_address(
+			&[
+				b"escrow",
+				leg_creator.as_ref(),
+				counter.to_le_bytes().as_ref(),
+			],
+			&nescrow_ix_interface::ID,
+		);
+
+		let winner = |outcome: Outcome| match outcome {
+			Outcome::CreatorWins => Some(leg_creator),
+			Outcome::TakerWins => Some(leg_taker),
+		};
+
+		let (status, winner, created_slot) = match leg {
+			Leg::Won(outcome) => (ESCROW_STATUS_COMPLETED, winner(outcome), leg_created_slot),
+			Leg::Pending => (ESCROW_STATUS_ACCEPTED, None, leg_created_slot),
+			Leg::Pushed => (ESCROW_STATUS_COMPLETED, None, leg_created_slot),
+			Leg::Recreated(outcome) => (ESCROW_STATUS_COMPLETED, winner(outcome), leg_created_slot + 1),
+			Leg::FillsTakersWon => (ESCROW_STATUS_COMPLETED, None, leg_created_slot),
+		};
+		let through_fills = matches!(leg, Leg::FillsTakersWon);
+
+		program_test.add_account(
+			leg_pda,
+			escrow_account(
+				&Escrow {
+					creator: leg_creator,
+					taker: (!through_fills).then_some(leg_taker),
+					amount: AMOUNT,
+					status,
+					winner,
+					fills: through_fills.then_some(Fills { min_fill: AMOUNT, filled: AMOUNT, unclaimed: AMOUNT }),
+					escrow_bump: leg_pda_bump,
+					counter,
+					created_slot,
+					..Default::default()
+				},
+				0,
+			),
+		);
+		leg_pdas.push(leg_pda);
+	}
+
+	let missed = |pick: Outcome| match pick {
+		Outcome::CreatorWins => Outcome::TakerWins,
+		Outcome::TakerWins => Outcome::CreatorWins,
+	};
+
+	let node = |leg: usize, outcome: Outcome| ConditionNode::EscrowOutcome {
+		escrow: leg_pdas[leg],
+		created_slot: leg_created_slot,
+		outcome,
+	};
+
+	program_test.add_account(
+		conditions_pda,
+		release_conditions_account(&ReleaseConditions {
+			escrow: escrow_pda,
+			escrow_created_slot: 0,
+			creator_wins: vec![
+				node(0, picks[0]),
+				node(1, picks[1]),
+				ConditionNode::All { children: vec![0, 1] },
+			],
+			taker_wins: vec![
+				node(0, missed(picks[0])),
+				node(1, missed(picks[1])),
+				ConditionNode::Any { children: vec![0, 1] },
+			],
+			conditions_bump: conditions_pda_bump,
+		}),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = match settle {
+		Settle::Release { to_creator } => nescrow_ix_interface::release_if_conditions_met_ix_setup(
+			&fee_payer_keypair,
+			escrow_pda,
+			conditions_pda,
+			if to_creator { creator_pubkey } else { taker_pubkey },
+			&[],
+			&leg_pdas,
+			creator_pubkey,
+			counter,
+			vec![],
+			recent_blockhash,
+		),
+		Settle::Refund => nescrow_ix_interface::refund_parlay_ix_setup(
+			&fee_payer_keypair,
+			escrow_pda,
+			conditions_pda,
+			creator_pubkey,
+			taker_pubkey,
+			&leg_pdas,
+			counter,
+			recent_blockhash,
+		),
+	};
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	assert_eq!(escrow.status, ESCROW_STATUS_COMPLETED);
+
+	let lamports = |account: Option<Account>| account.map_or(0, |account| account.lamports);
+	Some((
+		lamports(banks_client.get_account(creator_pubkey).await.unwrap()),
+		lamports(banks_client.get_account(taker_pubkey).await.unwrap()),
+	))
+}
+
+const TO_CREATOR: Settle = Settle::Release { to_creator: true };
+const TO_TAKER: Settle = Settle::Release { to_creator: false };
+
+#[tokio::test]
+async fn release_if_conditions_met_ix_pays_parlay_when_every_leg_hits() {
+	let legs = [Leg::Won(Outcome::CreatorWins), Leg::Won(Outcome::TakerWins)];
+
+	// ASSERTIONS
+	assert_eq!(parlay_with(legs, TO_CREATOR).await, Some((2 * AMOUNT, 0)));
+	assert_eq!(parlay_with(legs, TO_TAKER).await, None);
+	assert_eq!(parlay_with(legs, Settle::Refund).await, None);
+}
+
+#[tokio::test]
+async fn release_if_conditions_met_ix_reads_fills_won_by_takers() {
+	let legs = [Leg::Won(Outcome::CreatorWins), Leg::FillsTakersWon];
+
+	// ASSERTIONS
+	assert_eq!(parlay_with(legs, TO_CREATOR).await, Some((2 * AMOUNT, 0)));
+	assert_eq!(parlay_with(legs, Settle::Refund).await, None);
+}
+
+#[tokio::test]
+async fn release_if_conditions_met_ix_pays_parlay_counterparty_when_a_leg_misses() {
+	let legs = [Leg::Won(Outcome::CreatorWins), Leg::Won(Outcome::CreatorWins)];
+
+	// ASSERTIONS
+	assert_eq!(parlay_with(legs, TO_TAKER).await, Some((0, 2 * AMOUNT)));
+	assert_eq!(parlay_with(legs, TO_CREATOR).await, None);
+}
+
+#[tokio::test]
+async fn release_if_conditions_met_ix_rejects_parlay_with_unsettled_leg() {
+	// ASSERTIONS
+	assert_eq!(parlay_with([Leg::Won(Outcome::TakerWins), Leg::Pending], TO_TAKER).await, None);
+	assert_eq!(parlay_with([Leg::Won(Outcome::CreatorWins), Leg::Pending], TO_CREATOR).await, None);
+	assert_eq!(parlay_with([Leg::Pushed, Leg::Pending], Settle::Refund).await, None);
+}
+
+#[tokio::test]
+async fn refund_parlay_ix_refunds_pushed_leg() {
+	let legs = [Leg::Won(Outcome::CreatorWins), Leg::Pushed];
+
+	// ASSERTIONS
+	assert_eq!(parlay_with(legs, TO_CREATOR).await, None);
+	assert_eq!(parlay_with(legs, TO_TAKER).await, None);
+	assert_eq!(parlay_with(legs, Settle::Refund).await, Some((AMOUNT, AMOUNT)));
+}
+
+#[tokio::test]
+async fn refund_parlay_ix_treats_recreated_leg_as_void() {
+	// The second leg now reads as a hit for the creator, but was re-created
+	let legs = [Leg::Won(Outcome::CreatorWins), Leg::Recreated(Outcome::TakerWins)];
+
+	// ASSERTIONS
+	assert_eq!(parlay_with(legs, TO_CREATOR).await, None);
+	assert_eq!(parlay_with(legs, Settle::Refund).await, Some((AMOUNT, AMOUNT)));
+}
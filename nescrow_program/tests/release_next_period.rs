@@ -0,0 +1,149 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Schedule},
+        src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const START_TIME: i64 = 1_700_000_000;
+const PERIOD_LENGTH: i64 = 86_400;
+const TRANCHE: u64 = 2_000_000;
+
+/// Release at `now` from a three period payment with `released` tranches
+/// already paid, returning the escrow and the payee's lamports
+async fn release_at(now: i64, released: u32) -> Option<(Escrow, u64)> {
+	release_from(START_TIME, now, released).await
+}
+
+/// Same as `release_at`, for a payment starting at `start_time`
+async fn release_from(start_time: i64, now: i64, released: u32) -> Option<(Escrow, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let creator: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+	let periods: u32 = 3;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let payee_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator,
+				taker: Some(payee_pubkey),
+				amount: TRANCHE * periods as u64,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				schedule: Some(Schedule {
+					tranche: TRANCHE,
+					start_time,
+					period_length: PERIOD_LENGTH,
+					periods,
+					released,
+				}),
+				..Default::default()
+			},
+			TRANCHE * (periods - released) as u64,
+		),
+	);
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: now,
+		..Default::default()
+	});
+
+	let ix = nescrow_ix_interface::release_next_period_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		payee_pubkey,
+		creator,
+		counter,
+		context.last_blockhash,
+	);
+
+	context.banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = context.banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	let payee_account = context.banks_client.get_account(payee_pubkey).await.unwrap().unwrap();
+	Some((escrow, payee_account.lamports))
+}
+
+#[tokio::test]
+async fn release_next_period_ix_success() {
+	let (escrow, payee_lamports) = release_at(START_TIME + 2 * PERIOD_LENGTH, 0).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(payee_lamports, TRANCHE);
+	assert_eq!(escrow.status, ESCROW_STATUS_ACCEPTED);
+	assert_eq!(escrow.schedule.unwrap().released, 1);
+}
+
+#[tokio::test]
+async fn release_next_period_ix_completes_last_period() {
+	let (escrow, payee_lamports) = release_at(START_TIME + 3 * PERIOD_LENGTH, 2).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(payee_lamports, TRANCHE);
+	assert_eq!(escrow.status, ESCROW_STATUS_COMPLETED);
+	assert_eq!(escrow.winner, escrow.taker);
+}
+
+#[tokio::test]
+async fn release_next_period_ix_requires_period_boundary() {
+	// ASSERTIONS
+	assert!(release_at(START_TIME + PERIOD_LENGTH - 1, 0).await.is_none());
+	assert!(release_at(START_TIME + 2 * PERIOD_LENGTH - 1, 1).await.is_none());
+}
+
+#[tokio::test]
+async fn release_next_period_ix_handles_distant_start() {
+	let (escrow, payee_lamports) = release_from(i64::MIN, START_TIME, 0).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(payee_lamports, TRANCHE);
+	assert_eq!(escrow.schedule.unwrap().released, 1);
+}
@@ -0,0 +1,102 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		event_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::generated::state::{Event, Score},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+/// Report a 110-104 score on an event starting at `start_time`, with
+/// `score` already posted, returning the stored score if the report went
+/// through
+async fn report(start_time: i64, score: Option<Score>) -> Option<Option<Score>> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let event_id: u64 = 7;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let reporter_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let reporter_pubkey = reporter_keypair.pubkey();
+
+	// PDA
+	let (event_pda, event_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"event",
+			reporter_pubkey.as_ref(),
+			event_id.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		event_pda,
+		event_account(&Event {
+			reporter: reporter_pubkey,
+			event_id,
+			start_time,
+			outcomes: vec!["Home".to_string(), "Away".to_string()],
+			result: None,
+			score,
+			event_bump: event_pda_bump,
+		}),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::report_event_score_ix_setup(
+		&fee_payer_keypair,
+		event_pda,
+		&reporter_keypair,
+		event_id,
+		110,
+		104,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let event_account = banks_client.get_account(event_pda).await.unwrap().unwrap();
+	let event = Event::deserialize(&mut &event_account.data[..]).unwrap();
+	assert_eq!(event.result, None);
+
+	Some(event.score)
+}
+
+#[tokio::test]
+async fn report_event_score_ix_success() {
+	// ASSERTIONS
+	assert_eq!(report(0, None).await, Some(Some(Score { home: 110, away: 104 })));
+}
+
+#[tokio::test]
+async fn report_event_score_ix_rejects_early_or_repeated_scores() {
+	// ASSERTIONS
+	assert_eq!(report(i64::MAX, None).await, None);
+	assert_eq!(report(0, Some(Score { home: 99, away: 98 })).await, None);
+}
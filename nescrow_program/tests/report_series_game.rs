@@ -0,0 +1,157 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Outcome, Series},
+        src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const AMOUNT: u64 = 1_000_000;
+const BALANCE: u64 = 1_000_000_000;
+
+/// Report `games` one by one on a best-of-`length` series, confirmed by
+/// both parties. Returns the escrow and the lamports gained by the creator
+/// and the taker if every report went through.
+async fn series_with(length: u8, games: &[Outcome]) -> Option<(Escrow, u64, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+	let taker_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+	let taker_pubkey = taker_keypair.pubkey();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [fee_payer_pubkey, creator_pubkey, taker_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: BALANCE,
+				data: vec![],
+				owner: Pubkey::default(),
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator: creator_pubkey,
+				taker: Some(taker_pubkey),
+				amount: AMOUNT,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				series: Some(Series { length, results: vec![] }),
+				..Default::default()
+			},
+			2 * AMOUNT,
+		),
+	);
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+
+	for outcome in games {
+		let ix = nescrow_ix_interface::report_series_game_ix_setup(
+			&fee_payer_keypair,
+			escrow_pda,
+			&creator_keypair,
+			&taker_keypair,
+			counter,
+			*outcome,
+			context.last_blockhash,
+		);
+
+		context.banks_client.process_transaction(ix).await.ok()?;
+
+		// Games with the same outcome would otherwise be the same transaction
+		context.get_new_latest_blockhash().await.unwrap();
+	}
+
+	let banks_client = context.banks_client;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+
+	let creator_account = banks_client.get_account(creator_pubkey).await.unwrap().unwrap();
+	let taker_account = banks_client.get_account(taker_pubkey).await.unwrap().unwrap();
+
+	Some((escrow, creator_account.lamports - BALANCE, taker_account.lamports - BALANCE))
+}
+
+#[tokio::test]
+async fn report_series_game_ix_success() {
+	let (escrow, creator_gain, taker_gain) = series_with(
+		3,
+		&[Outcome::CreatorWins, Outcome::TakerWins, Outcome::CreatorWins],
+	).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.status, ESCROW_STATUS_COMPLETED);
+	assert_eq!(escrow.winner, Some(escrow.creator));
+	assert_eq!((creator_gain, taker_gain), (2 * AMOUNT, 0));
+}
+
+#[tokio::test]
+async fn report_series_game_ix_settles_on_majority() {
+	let (escrow, creator_gain, taker_gain) = series_with(
+		5,
+		&[Outcome::TakerWins, Outcome::TakerWins, Outcome::TakerWins],
+	).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.status, ESCROW_STATUS_COMPLETED);
+	assert_eq!(escrow.series.unwrap().results.len(), 3);
+	assert_eq!((creator_gain, taker_gain), (0, 2 * AMOUNT));
+}
+
+#[tokio::test]
+async fn report_series_game_ix_keeps_series_open_without_majority() {
+	let (escrow, creator_gain, taker_gain) = series_with(
+		3,
+		&[Outcome::CreatorWins, Outcome::TakerWins],
+	).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.status, ESCROW_STATUS_ACCEPTED);
+	assert_eq!(escrow.series.unwrap().results, vec![Outcome::CreatorWins, Outcome::TakerWins]);
+	assert_eq!((creator_gain, taker_gain), (0, 0));
+}
+
+#[tokio::test]
+async fn report_series_game_ix_rejects_game_after_settlement() {
+	// ASSERTIONS
+	assert!(series_with(3, &[Outcome::CreatorWins, Outcome::CreatorWins, Outcome::TakerWins]).await.is_none());
+}
@@ -0,0 +1,283 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Outcome},
+        src::{
+            create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED},
+            oracle::{attestation_message, ORACLE_KIND_ATTESTATION},
+        },
+    },
+    solana_ed25519_program::new_ed25519_instruction_with_signature,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer, sysvar,
+    },
+};
+
+fn attestation(signer: &Keypair, escrow: &Pubkey, created_slot: u64, outcome: Outcome) -> Instruction {
+	let message = attestation_message(escrow, created_slot, outcome);
+	let signature = signer.sign_message(&message);
+
+	new_ed25519_instruction_with_signature(
+		&message,
+		signature.as_ref().try_into().unwrap(),
+		&signer.pubkey().to_bytes(),
+	)
+}
+
+#[tokio::test]
+async fn resolve_escrow_ix_success() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let creator: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+	let amount: u64 = 1_000_000;
+	let outcome = Outcome::TakerWins;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let resolver_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let taker_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator,
+				taker: Some(taker_pubkey),
+				amount,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				oracle_kind: ORACLE_KIND_ATTESTATION,
+				oracle_account: resolver_keypair.pubkey(),
+				..Default::default()
+			},
+			2 * amount,
+		),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::resolve_escrow_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		sysvar::instructions::ID,
+		taker_pubkey,
+		Some(attestation(&resolver_keypair, &escrow_pda, 0, outcome)),
+		creator,
+		counter,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let taker_account = banks_client.get_account(taker_pubkey).await.unwrap().unwrap();
+	assert_eq!(taker_account.lamports, 2 * amount);
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	assert_eq!(escrow.status, ESCROW_STATUS_COMPLETED);
+	assert_eq!(escrow.winner, Some(taker_pubkey));
+}
+
+#[tokio::test]
+async fn resolve_escrow_ix_rejects_other_signer() {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let creator: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+	let amount: u64 = 1_000_000;
+	let outcome = Outcome::CreatorWins;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let resolver_keypair = Keypair::new();
+	let impostor_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator,
+				taker: Some(Pubkey::new_unique()),
+				amount,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				oracle_kind: ORACLE_KIND_ATTESTATION,
+				oracle_account: resolver_keypair.pubkey(),
+				..Default::default()
+			},
+			2 * amount,
+		),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::resolve_escrow_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		sysvar::instructions::ID,
+		creator,
+		Some(attestation(&impostor_keypair, &escrow_pda, 0, outcome)),
+		creator,
+		counter,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_err());
+}
+
+/// Resolve an escrow created at `created_slot` in the creator's favour
+/// from an attestation signed for an escrow created at `signed_slot` at the
+/// same address. Returns the escrow's status if it was resolved.
+async fn resolve_with(created_slot: u64, signed_slot: u64) -> Option<u8> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let creator: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+	let amount: u64 = 1_000_000;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let resolver_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator,
+				taker: Some(Pubkey::new_unique()),
+				amount,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				oracle_kind: ORACLE_KIND_ATTESTATION,
+				oracle_account: resolver_keypair.pubkey(),
+				created_slot,
+				..Default::default()
+			},
+			2 * amount,
+		),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::resolve_escrow_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		sysvar::instructions::ID,
+		creator,
+		Some(attestation(&resolver_keypair, &escrow_pda, signed_slot, Outcome::CreatorWins)),
+		creator,
+		counter,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	Some(Escrow::deserialize(&mut &escrow_account.data[..]).unwrap().status)
+}
+
+#[tokio::test]
+async fn resolve_escrow_ix_rejects_attestation_for_closed_escrow() {
+	// ASSERTIONS
+	assert_eq!(resolve_with(5, 5).await, Some(ESCROW_STATUS_COMPLETED));
+	assert!(resolve_with(5, 4).await.is_none());
+}
@@ -0,0 +1,147 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		event_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Event, EventCondition},
+        src::{
+            create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED},
+            oracle::ORACLE_KIND_EVENT,
+        },
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+/// Resolve a Home (creator) vs Away (taker) wager on an event with the
+/// given result, returning the lamports received by `expected_winner`
+async fn resolve_with(result: Option<u8>, creator_wins: bool) -> Option<u64> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let creator: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+	let amount: u64 = 1_000_000;
+	let event_id: u64 = 1;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let taker_pubkey = Pubkey::new_unique();
+	let reporter_pubkey = Pubkey::new_unique();
+	let winner_pubkey = if creator_wins { creator } else { taker_pubkey };
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (event_pda, event_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"event",
+			reporter_pubkey.as_ref(),
+			event_id.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		event_pda,
+		event_account(&Event {
+			reporter: reporter_pubkey,
+			event_id,
+			start_time: 0,
+			outcomes: vec!["Home".to_string(), "Draw".to_string(), "Away".to_string()],
+			result,
+			score: None,
+			event_bump: event_pda_bump,
+		}),
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator,
+				taker: Some(taker_pubkey),
+				amount,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				oracle_kind: ORACLE_KIND_EVENT,
+				oracle_account: event_pda,
+				event_condition: Some(EventCondition {
+					creator_outcome: 0,
+					taker_outcome: 2,
+				}),
+				..Default::default()
+			},
+			2 * amount,
+		),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::resolve_escrow_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		event_pda,
+		winner_pubkey,
+		None,
+		creator,
+		counter,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	assert_eq!(escrow.status, ESCROW_STATUS_COMPLETED);
+	assert_eq!(escrow.winner, Some(winner_pubkey));
+
+	let winner_account = banks_client.get_account(winner_pubkey).await.unwrap().unwrap();
+	Some(winner_account.lamports)
+}
+
+#[tokio::test]
+async fn resolve_escrow_event_ix_success() {
+	// ASSERTIONS
+	assert_eq!(resolve_with(Some(0), true).await, Some(2_000_000));
+	assert_eq!(resolve_with(Some(2), false).await, Some(2_000_000));
+}
+
+#[tokio::test]
+async fn resolve_escrow_event_ix_requires_backed_result() {
+	// ASSERTIONS
+	assert_eq!(resolve_with(None, true).await, None);
+	assert_eq!(resolve_with(Some(1), true).await, None);
+}
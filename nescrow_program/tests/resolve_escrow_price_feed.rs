@@ -0,0 +1,193 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, PriceCondition},
+        src::{
+            create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED},
+            oracle::ORACLE_KIND_PRICE_FEED,
+            pyth::{
+                PYTH_ACCOUNT_TYPE_PRICE, PYTH_MAGIC, PYTH_PRICE_HEADER_LEN, PYTH_PROGRAM_ID, PYTH_STATUS_TRADING,
+                PYTH_VERSION,
+            },
+        },
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const NOW: i64 = 1_700_000_000;
+
+/// Pyth v2 price account with the given aggregate price, as a mock feed
+fn mock_price_account(price: i64, conf: u64, exponent: i32, publish_time: i64) -> Account {
+	let mut data = vec![0u8; PYTH_PRICE_HEADER_LEN];
+	data[0..4].copy_from_slice(&PYTH_MAGIC.to_le_bytes());
+	data[4..8].copy_from_slice(&PYTH_VERSION.to_le_bytes());
+	data[8..12].copy_from_slice(&PYTH_ACCOUNT_TYPE_PRICE.to_le_bytes());
+	data[20..24].copy_from_slice(&exponent.to_le_bytes());
+	data[96..104].copy_from_slice(&publish_time.to_le_bytes());
+	data[208..216].copy_from_slice(&price.to_le_bytes());
+	data[216..224].copy_from_slice(&conf.to_le_bytes());
+	data[224..228].copy_from_slice(&PYTH_STATUS_TRADING.to_le_bytes());
+
+	Account {
+		lamports: 1_000_000_000,
+		data,
+		owner: PYTH_PROGRAM_ID,
+		executable: false,
+		rent_epoch: 0,
+	}
+}
+
+/// Resolve a SOL/USD >= $150 wager against a mock feed, returning the result
+/// and the lamports received by the expected winner
+async fn resolve_against(price_feed_account: Account, creator_wins: bool) -> (bool, u64) {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let creator: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+	let amount: u64 = 1_000_000;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let taker_pubkey = Pubkey::new_unique();
+	let price_feed_pubkey = Pubkey::new_unique();
+	let winner_pubkey = if creator_wins { creator } else { taker_pubkey };
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(price_feed_pubkey, price_feed_account);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator,
+				taker: Some(taker_pubkey),
+				amount,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				oracle_kind: ORACLE_KIND_PRICE_FEED,
+				oracle_account: price_feed_pubkey,
+				price_condition: Some(PriceCondition {
+					threshold: 15_000_000_000,
+					exponent: -8,
+					resolve_after: NOW - 60,
+					max_staleness: 30,
+					max_confidence_bps: 100,
+				}),
+				..Default::default()
+			},
+			2 * amount,
+		),
+	);
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: NOW,
+		..Default::default()
+	});
+
+	let ix = nescrow_ix_interface::resolve_escrow_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		price_feed_pubkey,
+		winner_pubkey,
+		None,
+		creator,
+		counter,
+		context.last_blockhash,
+	);
+
+	let result = context.banks_client.process_transaction(ix).await;
+	if result.is_err() {
+		return (false, 0);
+	}
+
+	let escrow_account = context.banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	assert_eq!(escrow.status, ESCROW_STATUS_COMPLETED);
+	assert_eq!(escrow.winner, Some(winner_pubkey));
+
+	let winner_account = context.banks_client.get_account(winner_pubkey).await.unwrap().unwrap();
+	(true, winner_account.lamports)
+}
+
+#[tokio::test]
+async fn resolve_escrow_price_feed_ix_success() {
+	// $151.00 +/- $0.10, published 10 seconds ago
+	let feed = mock_price_account(15_100_000_000, 10_000_000, -8, NOW - 10);
+
+	let (resolved, winnings) = resolve_against(feed, true).await;
+
+	// ASSERTIONS
+	assert!(resolved);
+	assert_eq!(winnings, 2_000_000);
+}
+
+#[tokio::test]
+async fn resolve_escrow_price_feed_ix_below_threshold_pays_taker() {
+	// $149.00 +/- $0.10, published 10 seconds ago
+	let feed = mock_price_account(14_900_000_000, 10_000_000, -8, NOW - 10);
+
+	let (resolved, winnings) = resolve_against(feed, false).await;
+
+	// ASSERTIONS
+	assert!(resolved);
+	assert_eq!(winnings, 2_000_000);
+}
+
+#[tokio::test]
+async fn resolve_escrow_price_feed_ix_rejects_unreliable_prices() {
+	// Published 45 seconds ago, past the 30 second staleness limit
+	let stale = mock_price_account(15_100_000_000, 10_000_000, -8, NOW - 45);
+	assert!(!resolve_against(stale, true).await.0);
+
+	// +/- $3.00 is wider than 1% of the price
+	let uncertain = mock_price_account(15_100_000_000, 300_000_000, -8, NOW - 10);
+	assert!(!resolve_against(uncertain, true).await.0);
+}
+
+#[tokio::test]
+async fn resolve_escrow_price_feed_ix_rejects_feed_not_owned_by_pyth() {
+	// Same layout and price as a real feed, written by someone else
+	let mut forged = mock_price_account(15_100_000_000, 10_000_000, -8, NOW - 10);
+	forged.owner = Pubkey::new_unique();
+
+	// ASSERTIONS
+	assert!(!resolve_against(forged, true).await.0);
+}
@@ -0,0 +1,148 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		event_account,
+		get_program_test,
+		market_account,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Event, Market},
+        src::create_market::{MARKET_STATUS_OPEN, MARKET_STATUS_REFUNDED, MARKET_STATUS_RESOLVED},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const CREATOR_LAMPORTS: u64 = 1_000_000_000;
+
+/// Resolve a 2% fee market with 6 and 4 million lamports staked on the
+/// first two of three outcomes, on an event reporting `result`. Returns the
+/// market and the creator's lamports if resolution succeeded.
+async fn resolve_with(result: Option<u8>) -> Option<(Market, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+	let event_id: u64 = 1;
+	let reporter: Pubkey = Pubkey::new_unique();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (market_pda, market_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"market",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (event_pda, event_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"event",
+			reporter.as_ref(),
+			event_id.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for (pubkey, lamports) in [(fee_payer_pubkey, 1_000_000_000_000), (creator_pubkey, CREATOR_LAMPORTS)] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports,
+				data: vec![],
+				owner: Pubkey::default(),
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	program_test.add_account(
+		event_pda,
+		event_account(&Event {
+			reporter,
+			event_id,
+			start_time: 0,
+			outcomes: vec!["Home".to_string(), "Draw".to_string(), "Away".to_string()],
+			result,
+			score: None,
+			event_bump: event_pda_bump,
+		}),
+	);
+
+	program_test.add_account(
+		market_pda,
+		market_account(
+			&Market {
+				creator: creator_pubkey,
+				counter,
+				event: event_pda,
+				fee_bps: 200,
+				outcome_totals: vec![6_000_000, 4_000_000, 0],
+				status: MARKET_STATUS_OPEN,
+				market_bump: market_pda_bump,
+				..Default::default()
+			},
+			10_000_000,
+		),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::resolve_market_ix_setup(
+		&fee_payer_keypair,
+		market_pda,
+		event_pda,
+		creator_pubkey,
+		counter,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let market_account = banks_client.get_account(market_pda).await.unwrap().unwrap();
+	let creator_account = banks_client.get_account(creator_pubkey).await.unwrap().unwrap();
+	Some((Market::deserialize(&mut &market_account.data[..]).unwrap(), creator_account.lamports))
+}
+
+#[tokio::test]
+async fn resolve_market_ix_success() {
+	let (market, creator_lamports) = resolve_with(Some(1)).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(market.status, MARKET_STATUS_RESOLVED);
+	assert_eq!(market.winning_outcome, Some(1));
+	assert_eq!(market.payout_pool, 9_800_000);
+	assert_eq!(market.unclaimed_winning_stake, 4_000_000);
+	assert_eq!(creator_lamports, CREATOR_LAMPORTS + 200_000);
+}
+
+#[tokio::test]
+async fn resolve_market_ix_refunds_without_winning_stake() {
+	let (market, creator_lamports) = resolve_with(Some(2)).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(market.status, MARKET_STATUS_REFUNDED);
+	assert_eq!(creator_lamports, CREATOR_LAMPORTS);
+}
+
+#[tokio::test]
+async fn resolve_market_ix_requires_reported_event() {
+	// ASSERTIONS
+	assert!(resolve_with(None).await.is_none());
+}
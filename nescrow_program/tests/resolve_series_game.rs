@@ -0,0 +1,156 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Outcome, Series},
+        src::{
+            create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED},
+            oracle::{series_attestation_message, ORACLE_KIND_ATTESTATION},
+        },
+    },
+    solana_ed25519_program::new_ed25519_instruction_with_signature,
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer, sysvar,
+    },
+};
+
+const AMOUNT: u64 = 1_000_000;
+const CREATED_SLOT: u64 = 7;
+
+fn attestation(signer: &Keypair, escrow: &Pubkey, created_slot: u64, game: u8, outcome: Outcome) -> Instruction {
+	let message = series_attestation_message(escrow, created_slot, game, outcome);
+	let signature = signer.sign_message(&message);
+
+	new_ed25519_instruction_with_signature(
+		&message,
+		signature.as_ref().try_into().unwrap(),
+		&signer.pubkey().to_bytes(),
+	)
+}
+
+/// Resolve a best-of-3 series created at `CREATED_SLOT` from attestations
+/// of `games`, each given as the creation slot and game index the oracle
+/// signed and its outcome. Returns the escrow and the lamports received by
+/// the taker if every game was recorded.
+async fn resolve_with(games: &[(u64, u8, Outcome)]) -> Option<(Escrow, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let creator: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let resolver_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let taker_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator,
+				taker: Some(taker_pubkey),
+				amount: AMOUNT,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				oracle_kind: ORACLE_KIND_ATTESTATION,
+				oracle_account: resolver_keypair.pubkey(),
+				series: Some(Series { length: 3, results: vec![] }),
+				created_slot: CREATED_SLOT,
+				..Default::default()
+			},
+			2 * AMOUNT,
+		),
+	);
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+
+	for (created_slot, game, outcome) in games {
+		let ix = nescrow_ix_interface::resolve_series_game_ix_setup(
+			&fee_payer_keypair,
+			escrow_pda,
+			sysvar::instructions::ID,
+			creator,
+			taker_pubkey,
+			Some(attestation(&resolver_keypair, &escrow_pda, *created_slot, *game, *outcome)),
+			counter,
+			context.last_blockhash,
+		);
+
+		context.banks_client.process_transaction(ix).await.ok()?;
+
+		// A replayed attestation must fail on its own, not as a duplicate transaction
+		context.get_new_latest_blockhash().await.unwrap();
+	}
+
+	let banks_client = context.banks_client;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+
+	let taker_lamports = banks_client.get_account(taker_pubkey).await.unwrap().map_or(0, |account| account.lamports);
+	Some((escrow, taker_lamports))
+}
+
+#[tokio::test]
+async fn resolve_series_game_ix_success() {
+	let (escrow, taker_lamports) = resolve_with(&[
+		(CREATED_SLOT, 0, Outcome::TakerWins),
+		(CREATED_SLOT, 1, Outcome::CreatorWins),
+		(CREATED_SLOT, 2, Outcome::TakerWins),
+	]).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.status, ESCROW_STATUS_COMPLETED);
+	assert_eq!(escrow.winner, escrow.taker);
+	assert_eq!(taker_lamports, 2 * AMOUNT);
+}
+
+#[tokio::test]
+async fn resolve_series_game_ix_rejects_replayed_attestation() {
+	// ASSERTIONS
+	assert!(resolve_with(&[(CREATED_SLOT, 0, Outcome::TakerWins), (CREATED_SLOT, 0, Outcome::TakerWins)])
+		.await
+		.is_none());
+	assert!(resolve_with(&[(CREATED_SLOT, 1, Outcome::TakerWins)]).await.is_none());
+}
+
+#[tokio::test]
+async fn resolve_series_game_ix_rejects_attestation_for_closed_escrow() {
+	// ASSERTIONS
+	assert!(resolve_with(&[(CREATED_SLOT - 1, 0, Outcome::TakerWins)]).await.is_none());
+}
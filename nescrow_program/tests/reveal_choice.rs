@@ -0,0 +1,123 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Game},
+        src::{
+            create_escrow::ESCROW_STATUS_ACCEPTED,
+            game::{commitment, GAME_ROCK_PAPER_SCISSORS, RPS_PAPER, RPS_ROCK},
+        },
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+/// Reveal `choice` with `salt` for the creator of a game they committed to
+/// rock in, returning the stored escrow if the reveal succeeded
+async fn reveal_with(choice: u8, salt: [u8; 32]) -> Option<Escrow> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+	let amount: u64 = 1_000_000;
+	let creator_salt: [u8; 32] = [7; 32];
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+	let taker_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator: creator_pubkey,
+				taker: Some(taker_pubkey),
+				amount,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				game: Some(Game {
+					game_kind: GAME_ROCK_PAPER_SCISSORS,
+					reveal_window: 600,
+					reveal_deadline: i64::MAX,
+					creator_commitment: commitment(RPS_ROCK, &creator_salt, &creator_pubkey),
+					taker_commitment: commitment(RPS_PAPER, &[9; 32], &taker_pubkey),
+					..Default::default()
+				}),
+				..Default::default()
+			},
+			2 * amount,
+		),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::reveal_choice_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&creator_keypair,
+		creator_pubkey,
+		counter,
+		choice,
+		salt,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	Some(Escrow::deserialize(&mut &escrow_account.data[..]).unwrap())
+}
+
+#[tokio::test]
+async fn reveal_choice_ix_success() {
+	let escrow = reveal_with(RPS_ROCK, [7; 32]).await.unwrap();
+	let game = escrow.game.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(game.creator_choice, Some(RPS_ROCK));
+	assert_eq!(game.taker_choice, None);
+}
+
+#[tokio::test]
+async fn reveal_choice_ix_rejects_mismatched_reveal() {
+	// ASSERTIONS
+	assert!(reveal_with(RPS_PAPER, [7; 32]).await.is_none());
+	assert!(reveal_with(RPS_ROCK, [8; 32]).await.is_none());
+}
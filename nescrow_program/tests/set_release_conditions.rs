@@ -0,0 +1,174 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+		release_conditions_account,
+	},
+    nescrow::{
+        generated::state::{ConditionNode, Escrow, ReleaseConditions, Schedule, Stream},
+        src::create_escrow::ESCROW_STATUS_ACCEPTED,
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+/// Attach `creator_wins` to an accepted escrow created at slot 5 and further
+/// built by `configure`, over conditions already set for the escrow created
+/// at `previous`, if any. Returns the stored conditions if they were accepted.
+async fn set_with(
+	creator_wins: Vec<ConditionNode>,
+	previous: Option<u64>,
+	configure: impl FnOnce(&mut Escrow),
+) -> Option<ReleaseConditions> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+	let amount: u64 = 1_000_000;
+	let created_slot: u64 = 5;
+	let taker_wins: Vec<ConditionNode> = vec![ConditionNode::TimePassed { after: 0 }];
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+	let taker_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+	let taker_pubkey = taker_keypair.pubkey();
+	let system_program_pubkey = Pubkey::default();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (conditions_pda, conditions_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"conditions",
+			escrow_pda.as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	let mut escrow = Escrow {
+		creator: creator_pubkey,
+		taker: Some(taker_pubkey),
+		amount,
+		status: ESCROW_STATUS_ACCEPTED,
+		escrow_bump: escrow_pda_bump,
+		counter,
+		created_slot,
+		..Default::default()
+	};
+	configure(&mut escrow);
+
+	program_test.add_account(escrow_pda, escrow_account(&escrow, 2 * amount));
+
+	if let Some(previous) = previous {
+		program_test.add_account(
+			conditions_pda,
+			release_conditions_account(&ReleaseConditions {
+				escrow: escrow_pda,
+				escrow_created_slot: previous,
+				creator_wins: vec![],
+				taker_wins: vec![],
+				conditions_bump: conditions_pda_bump,
+			}),
+		);
+	}
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::set_release_conditions_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		conditions_pda,
+		&creator_keypair,
+		&taker_keypair,
+		system_program_pubkey,
+		counter,
+		creator_wins,
+		taker_wins,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let conditions_account = banks_client.get_account(conditions_pda).await.unwrap().unwrap();
+	Some(ReleaseConditions::deserialize(&mut &conditions_account.data[..]).unwrap())
+}
+
+#[tokio::test]
+async fn set_release_conditions_ix_success() {
+	let creator_wins = vec![
+		ConditionNode::TimePassed { after: 0 },
+		ConditionNode::HashRevealed { hash: [1; 32] },
+		ConditionNode::Any { children: vec![0, 1] },
+	];
+
+	let release_conditions = set_with(creator_wins.clone(), None, |_| {}).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(release_conditions.creator_wins, creator_wins);
+	assert_eq!(release_conditions.taker_wins, vec![ConditionNode::TimePassed { after: 0 }]);
+}
+
+#[tokio::test]
+async fn set_release_conditions_ix_rejects_malformed_tree() {
+	let leaf = ConditionNode::TimePassed { after: 0 };
+
+	// ASSERTIONS
+	// Child after its parent
+	assert!(set_with(vec![ConditionNode::All { children: vec![1] }, leaf.clone()], None, |_| {}).await.is_none());
+	// Threshold that can never be reached
+	assert!(set_with(vec![leaf.clone(), ConditionNode::Threshold { count: 2, children: vec![0] }], None, |_| {}).await.is_none());
+	// Too many nodes
+	assert!(set_with(vec![leaf; ReleaseConditions::MAX_NODES + 1], None, |_| {}).await.is_none());
+}
+
+#[tokio::test]
+async fn set_release_conditions_ix_replaces_conditions_of_closed_escrow() {
+	let creator_wins = vec![ConditionNode::TimePassed { after: 0 }];
+
+	let release_conditions = set_with(creator_wins.clone(), Some(4), |_| {}).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(release_conditions.escrow_created_slot, 5);
+	assert_eq!(release_conditions.creator_wins, creator_wins);
+	assert!(set_with(creator_wins, Some(5), |_| {}).await.is_none());
+}
+
+#[tokio::test]
+async fn set_release_conditions_ix_rejects_streams_and_recurring_payments() {
+	let creator_wins = vec![ConditionNode::TimePassed { after: 0 }];
+
+	// ASSERTIONS
+	assert!(set_with(creator_wins.clone(), None, |escrow| escrow.stream = Some(Stream::default())).await.is_none());
+	assert!(set_with(creator_wins, None, |escrow| escrow.schedule = Some(Schedule::default())).await.is_none());
+}
@@ -0,0 +1,164 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		event_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Event, EventCondition, Fills},
+        src::{
+            create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED},
+            oracle::ORACLE_KIND_EVENT,
+        },
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const AMOUNT: u64 = 5_000_000;
+const CREATOR_LAMPORTS: u64 = 1_000_000_000;
+
+/// Settle a Home (creator) vs Away (takers) escrow filled for 5 million
+/// lamports on an event with the given result, returning the escrow and the
+/// creator's lamports if settlement succeeded
+async fn settle_with(result: Option<u8>) -> Option<(Escrow, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+	let event_id: u64 = 1;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = Pubkey::new_unique();
+	let reporter_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (event_pda, event_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"event",
+			reporter_pubkey.as_ref(),
+			event_id.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for (pubkey, lamports) in [(fee_payer_pubkey, 1_000_000_000_000), (creator_pubkey, CREATOR_LAMPORTS)] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports,
+				data: vec![],
+				owner: Pubkey::default(),
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	program_test.add_account(
+		event_pda,
+		event_account(&Event {
+			reporter: reporter_pubkey,
+			event_id,
+			start_time: 0,
+			outcomes: vec!["Home".to_string(), "Draw".to_string(), "Away".to_string()],
+			result,
+			score: None,
+			event_bump: event_pda_bump,
+		}),
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator: creator_pubkey,
+				amount: AMOUNT,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				oracle_kind: ORACLE_KIND_EVENT,
+				oracle_account: event_pda,
+				event_condition: Some(EventCondition {
+					creator_outcome: 0,
+					taker_outcome: 2,
+				}),
+				fills: Some(Fills {
+					min_fill: AMOUNT,
+					filled: AMOUNT,
+					..Default::default()
+				}),
+				..Default::default()
+			},
+			2 * AMOUNT,
+		),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::settle_fills_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		event_pda,
+		creator_pubkey,
+		counter,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let creator_account = banks_client.get_account(creator_pubkey).await.unwrap().unwrap();
+	Some((
+		Escrow::deserialize(&mut &escrow_account.data[..]).unwrap(),
+		creator_account.lamports,
+	))
+}
+
+#[tokio::test]
+async fn settle_fills_ix_success() {
+	let (escrow, creator_lamports) = settle_with(Some(2)).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.status, ESCROW_STATUS_COMPLETED);
+	assert_eq!(escrow.winner, None);
+	assert_eq!(escrow.fills.unwrap().unclaimed, AMOUNT);
+	assert_eq!(creator_lamports, CREATOR_LAMPORTS);
+}
+
+#[tokio::test]
+async fn settle_fills_ix_pays_creator() {
+	let (escrow, creator_lamports) = settle_with(Some(0)).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.status, ESCROW_STATUS_COMPLETED);
+	assert_eq!(escrow.winner, Some(escrow.creator));
+	assert_eq!(creator_lamports, CREATOR_LAMPORTS + 2 * AMOUNT);
+}
+
+#[tokio::test]
+async fn settle_fills_ix_rejects_unreported_event() {
+	// ASSERTIONS
+	assert!(settle_with(None).await.is_none());
+}
@@ -0,0 +1,149 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Game},
+        src::{
+            create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED},
+            game::{GAME_ROCK_PAPER_SCISSORS, RPS_ROCK, RPS_SCISSORS},
+        },
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const REVEAL_DEADLINE: i64 = 1_700_000_000;
+
+/// Settle a rock paper scissors game with the given reveals at time `now`,
+/// returning the escrow and the lamports held by the creator and taker
+async fn settle_with(
+	creator_choice: Option<u8>,
+	taker_choice: Option<u8>,
+	now: i64,
+) -> Option<(Escrow, u64, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let creator: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+	let amount: u64 = 1_000_000;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let taker_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator,
+				taker: Some(taker_pubkey),
+				amount,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				game: Some(Game {
+					game_kind: GAME_ROCK_PAPER_SCISSORS,
+					reveal_window: 600,
+					reveal_deadline: REVEAL_DEADLINE,
+					creator_choice,
+					taker_choice,
+					..Default::default()
+				}),
+				..Default::default()
+			},
+			2 * amount,
+		),
+	);
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: now,
+		..Default::default()
+	});
+
+	let ix = nescrow_ix_interface::settle_game_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		creator,
+		taker_pubkey,
+		counter,
+		context.last_blockhash,
+	);
+
+	context.banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = context.banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	assert_eq!(escrow.status, ESCROW_STATUS_COMPLETED);
+
+	let lamports = |account: Option<Account>| account.map_or(0, |account| account.lamports);
+	let creator_lamports = lamports(context.banks_client.get_account(creator).await.unwrap());
+	let taker_lamports = lamports(context.banks_client.get_account(taker_pubkey).await.unwrap());
+	Some((escrow, creator_lamports, taker_lamports))
+}
+
+#[tokio::test]
+async fn settle_game_ix_success() {
+	let (escrow, creator_lamports, taker_lamports) =
+		settle_with(Some(RPS_ROCK), Some(RPS_SCISSORS), REVEAL_DEADLINE - 1).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.winner, Some(escrow.creator));
+	assert_eq!((creator_lamports, taker_lamports), (2_000_000, 0));
+}
+
+#[tokio::test]
+async fn settle_game_ix_refunds_tie() {
+	let (escrow, creator_lamports, taker_lamports) =
+		settle_with(Some(RPS_ROCK), Some(RPS_ROCK), REVEAL_DEADLINE - 1).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(escrow.winner, None);
+	assert_eq!((creator_lamports, taker_lamports), (1_000_000, 1_000_000));
+}
+
+#[tokio::test]
+async fn settle_game_ix_forfeits_unrevealed_choice() {
+	// ASSERTIONS
+	assert!(settle_with(None, Some(RPS_ROCK), REVEAL_DEADLINE).await.is_none());
+
+	let (escrow, creator_lamports, taker_lamports) =
+		settle_with(None, Some(RPS_ROCK), REVEAL_DEADLINE + 1).await.unwrap();
+	assert_eq!(escrow.winner, escrow.taker);
+	assert_eq!((creator_lamports, taker_lamports), (0, 2_000_000));
+}
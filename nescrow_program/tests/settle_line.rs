@@ -0,0 +1,167 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		event_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Event, Line, Score},
+        src::{
+            create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED},
+            oracle::ORACLE_KIND_EVENT_SCORE,
+        },
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const AMOUNT: u64 = 1_000_000;
+
+/// Settle an escrow on `line` against an event reporting `score`. Returns
+/// the lamports received by the creator and the taker.
+async fn settle_with(line: Line, score: Option<Score>) -> Option<(u64, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let creator: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+	let event_id: u64 = 1;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let taker_pubkey = Pubkey::new_unique();
+	let reporter_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (event_pda, event_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"event",
+			reporter_pubkey.as_ref(),
+			event_id.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator,
+				taker: Some(taker_pubkey),
+				amount: AMOUNT,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				oracle_kind: ORACLE_KIND_EVENT_SCORE,
+				oracle_account: event_pda,
+				line: Some(line),
+				..Default::default()
+			},
+			2 * AMOUNT,
+		),
+	);
+
+	program_test.add_account(
+		event_pda,
+		event_account(&Event {
+			reporter: reporter_pubkey,
+			event_id,
+			start_time: 0,
+			outcomes: vec!["Home".to_string(), "Away".to_string()],
+			result: None,
+			score,
+			event_bump: event_pda_bump,
+		}),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::settle_line_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		event_pda,
+		creator,
+		taker_pubkey,
+		counter,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	assert_eq!(escrow.status, ESCROW_STATUS_COMPLETED);
+
+	let lamports = |account: Option<Account>| account.map_or(0, |account| account.lamports);
+	let creator_lamports = lamports(banks_client.get_account(creator).await.unwrap());
+	let taker_lamports = lamports(banks_client.get_account(taker_pubkey).await.unwrap());
+
+	Some((creator_lamports, taker_lamports))
+}
+
+#[tokio::test]
+async fn settle_line_ix_success() {
+	// Home -3.5 covers by winning by 10, and not by winning by 2
+	let line = Line::Spread { creator_home: true, handicap: -7 };
+
+	// ASSERTIONS
+	assert_eq!(settle_with(line, Some(Score { home: 110, away: 100 })).await, Some((2 * AMOUNT, 0)));
+	assert_eq!(settle_with(line, Some(Score { home: 102, away: 100 })).await, Some((0, 2 * AMOUNT)));
+}
+
+#[tokio::test]
+async fn settle_line_ix_settles_totals() {
+	// Over 210.5, with the creator taking the under
+	let line = Line::Total { creator_over: false, total: 421 };
+
+	// ASSERTIONS
+	assert_eq!(settle_with(line, Some(Score { home: 105, away: 106 })).await, Some((0, 2 * AMOUNT)));
+	assert_eq!(settle_with(line, Some(Score { home: 105, away: 105 })).await, Some((2 * AMOUNT, 0)));
+}
+
+#[tokio::test]
+async fn settle_line_ix_refunds_push() {
+	// ASSERTIONS
+	let spread = Line::Spread { creator_home: false, handicap: 6 };
+	assert_eq!(settle_with(spread, Some(Score { home: 103, away: 100 })).await, Some((AMOUNT, AMOUNT)));
+
+	let total = Line::Total { creator_over: true, total: 420 };
+	assert_eq!(settle_with(total, Some(Score { home: 110, away: 100 })).await, Some((AMOUNT, AMOUNT)));
+}
+
+#[tokio::test]
+async fn settle_line_ix_rejects_unreported_score() {
+	// ASSERTIONS
+	assert_eq!(settle_with(Line::Total { creator_over: true, total: 421 }, None).await, None);
+}
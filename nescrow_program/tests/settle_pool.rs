@@ -0,0 +1,167 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		get_program_test,
+		nescrow_ix_interface,
+		participant_account,
+		pool_account,
+	},
+    nescrow::{
+        generated::state::{Participant, Pool},
+        src::create_pool::{POOL_STATUS_OPEN, POOL_STATUS_REFUNDED, POOL_STATUS_SETTLED},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+/// Settle a three participant pool naming the participants at `winners`
+/// as winners, with an outsider appended if `add_outsider`. Returns the pool
+/// if settlement succeeded.
+async fn settle_with(winners: &[usize], add_outsider: bool) -> Option<Pool> {
+	settle_by(winners, add_outsider, 0, i64::MAX).await
+}
+
+/// Same as `settle_with`, for a pool that can be joined until
+/// `join_deadline` and settled until `settle_deadline`
+async fn settle_by(winners: &[usize], add_outsider: bool, join_deadline: i64, settle_deadline: i64) -> Option<Pool> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+	let participants: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+	let outsider: Pubkey = Pubkey::new_unique();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let organizer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let organizer_pubkey = organizer_keypair.pubkey();
+
+	// PDA
+	let (pool_pda, pool_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"pool",
+			organizer_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let record_pda = |participant: &Pubkey| Pubkey::find_program_address(
+		&[
+			b"participant",
+			pool_pda.as_ref(),
+			participant.as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		pool_pda,
+		pool_account(&Pool {
+			organizer: organizer_pubkey,
+			counter,
+			deposit: 2_000_000,
+			max_participants: 3,
+			participants: 3,
+			join_deadline,
+			settle_deadline,
+			status: POOL_STATUS_OPEN,
+			pool_bump: pool_pda_bump,
+			..Default::default()
+		}),
+	);
+
+	for participant in &participants {
+		let (record, record_bump) = record_pda(participant);
+		program_test.add_account(
+			record,
+			participant_account(&Participant {
+				pool: pool_pda,
+				participant: *participant,
+				participant_bump: record_bump,
+			}),
+		);
+	}
+
+	let mut winner_keys: Vec<Pubkey> = winners.iter().map(|&i| participants[i]).collect();
+	if add_outsider {
+		winner_keys.push(outsider);
+	}
+	let winner_records: Vec<Pubkey> = winner_keys.iter().map(|winner| record_pda(winner).0).collect();
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::settle_pool_ix_setup(
+		&fee_payer_keypair,
+		pool_pda,
+		&organizer_keypair,
+		&winner_records,
+		counter,
+		winner_keys,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let pool_account = banks_client.get_account(pool_pda).await.unwrap().unwrap();
+	Some(Pool::deserialize(&mut &pool_account.data[..]).unwrap())
+}
+
+#[tokio::test]
+async fn settle_pool_ix_success() {
+	let pool = settle_with(&[0, 2], false).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(pool.status, POOL_STATUS_SETTLED);
+	assert_eq!(pool.winners.len(), 2);
+	assert_eq!(pool.unclaimed_winners, 2);
+}
+
+#[tokio::test]
+async fn settle_pool_ix_refunds_without_winners() {
+	let pool = settle_with(&[], false).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(pool.status, POOL_STATUS_REFUNDED);
+}
+
+#[tokio::test]
+async fn settle_pool_ix_rejects_invalid_winners() {
+	// ASSERTIONS
+	assert!(settle_with(&[0], true).await.is_none());
+	assert!(settle_with(&[1, 1], false).await.is_none());
+}
+
+#[tokio::test]
+async fn settle_pool_ix_rejects_settlement_before_join_deadline() {
+	// ASSERTIONS
+	assert!(settle_by(&[0], false, i64::MAX, i64::MAX).await.is_none());
+	assert!(settle_by(&[], false, i64::MAX, i64::MAX).await.is_none());
+}
+
+#[tokio::test]
+async fn settle_pool_ix_rejects_settlement_after_settle_deadline() {
+	// ASSERTIONS
+	assert!(settle_by(&[0], false, 0, 1).await.is_none());
+	assert!(settle_by(&[], false, 0, 1).await.is_none());
+}
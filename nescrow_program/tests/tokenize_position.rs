@@ -0,0 +1,150 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::Escrow,
+        src::{
+            create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_OPEN},
+            spl_token::{associated_token_address, TokenAccount, ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID},
+            tokenize_position::{POSITION_SIDE_CREATOR, POSITION_SIDE_TAKER},
+        },
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const AMOUNT: u64 = 1_000_000;
+
+/// Tokenize `side` of an escrow in `status`, signed by the party holding
+/// the side. Returns the party's token account if the side was tokenized.
+async fn tokenize_with(status: u8, side: u8) -> Option<TokenAccount> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+	let taker_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+	let taker_pubkey = taker_keypair.pubkey();
+	let system_program_pubkey = Pubkey::default();
+	let party_keypair = if side == POSITION_SIDE_CREATOR { &creator_keypair } else { &taker_keypair };
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	let (mint_pda, _) = Pubkey::find_program_address(
+		&[b"position_mint", escrow_pda.as_ref(), &[side]],
+		&nescrow_ix_interface::ID,
+	);
+	let token_account_pubkey = associated_token_address(&party_keypair.pubkey(), &mint_pda);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator: creator_pubkey,
+				taker: Some(taker_pubkey),
+				amount: AMOUNT,
+				status,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				..Default::default()
+			},
+			2 * AMOUNT,
+		),
+	);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::tokenize_position_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		party_keypair,
+		mint_pda,
+		token_account_pubkey,
+		system_program_pubkey,
+		TOKEN_PROGRAM_ID,
+		ASSOCIATED_TOKEN_PROGRAM_ID,
+		creator_pubkey,
+		counter,
+		side,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	let (tokenized, untouched) = if side == POSITION_SIDE_CREATOR {
+		(escrow.position_tokens.creator_mint, escrow.position_tokens.taker_mint)
+	} else {
+		(escrow.position_tokens.taker_mint, escrow.position_tokens.creator_mint)
+	};
+	assert_eq!(tokenized, Some(mint_pda));
+	assert_eq!(untouched, None);
+
+	let token_account = banks_client.get_account(token_account_pubkey).await.unwrap().unwrap();
+	Some(TokenAccount::unpack(&token_account.data).unwrap())
+}
+
+#[tokio::test]
+async fn tokenize_position_ix_success() {
+	let token_account = tokenize_with(ESCROW_STATUS_ACCEPTED, POSITION_SIDE_TAKER).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(token_account.amount, 1);
+}
+
+#[tokio::test]
+async fn tokenize_position_ix_mints_creator_side() {
+	let token_account = tokenize_with(ESCROW_STATUS_ACCEPTED, POSITION_SIDE_CREATOR).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(token_account.amount, 1);
+}
+
+#[tokio::test]
+async fn tokenize_position_ix_rejects_open_escrow() {
+	// ASSERTIONS
+	assert!(tokenize_with(ESCROW_STATUS_OPEN, POSITION_SIDE_CREATOR).await.is_none());
+}
+
+#[tokio::test]
+async fn tokenize_position_ix_rejects_unknown_side() {
+	// ASSERTIONS
+	assert!(tokenize_with(ESCROW_STATUS_ACCEPTED, 2).await.is_none());
+}
@@ -0,0 +1,163 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Game, Schedule, Stream},
+        src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED, ESCROW_STATUS_OPEN},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const AMOUNT: u64 = 2_000_000;
+const PRICE: u64 = 1_500_000;
+const BALANCE: u64 = 1_000_000_000;
+
+/// Sell the creator's or the taker's side of an accepted escrow built by
+/// `configure` for `PRICE`, then have the buyer concede the escrow. Returns
+/// the lamports of the seller and the buyer if the transfer succeeded.
+async fn transfer_with(configure: impl FnOnce(&mut Escrow), sell_creator: bool) -> Option<(u64, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let counter: u64 = Default::default();
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let creator_keypair = Keypair::new();
+	let taker_keypair = Keypair::new();
+	let buyer_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let creator_pubkey = creator_keypair.pubkey();
+	let taker_pubkey = taker_keypair.pubkey();
+	let buyer_pubkey = buyer_keypair.pubkey();
+	let system_program_pubkey = Pubkey::default();
+	let (seller_keypair, counterparty_pubkey) = if sell_creator {
+		(&creator_keypair, taker_pubkey)
+	} else {
+		(&taker_keypair, creator_pubkey)
+	};
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator_pubkey.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [fee_payer_pubkey, creator_pubkey, taker_pubkey, buyer_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: BALANCE,
+				data: vec![],
+				owner: Pubkey::default(),
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	let mut escrow = Escrow {
+		creator: creator_pubkey,
+		taker: Some(taker_pubkey),
+		amount: AMOUNT,
+		status: ESCROW_STATUS_ACCEPTED,
+		escrow_bump: escrow_pda_bump,
+		counter,
+		..Default::default()
+	};
+	configure(&mut escrow);
+
+	program_test.add_account(escrow_pda, escrow_account(&escrow, 2 * AMOUNT));
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = nescrow_ix_interface::transfer_position_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		seller_keypair,
+		&buyer_keypair,
+		system_program_pubkey,
+		creator_pubkey,
+		counter,
+		PRICE,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	if sell_creator {
+		assert_eq!(escrow.creator, buyer_pubkey);
+		assert_eq!(escrow.original_creator, Some(creator_pubkey));
+	} else {
+		assert_eq!(escrow.taker, Some(buyer_pubkey));
+		assert_eq!(escrow.original_creator, None);
+	}
+
+	let seller_account = banks_client.get_account(seller_keypair.pubkey()).await.unwrap().unwrap();
+	let buyer_account = banks_client.get_account(buyer_pubkey).await.unwrap().unwrap();
+
+	// The buyer now acts for the side, at the escrow's unchanged address
+	let ix = nescrow_ix_interface::concede_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&buyer_keypair,
+		counterparty_pubkey,
+		escrow.creator,
+		counter,
+		recent_blockhash,
+	);
+
+	banks_client.process_transaction(ix).await.unwrap();
+
+	let conceded_account = banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let conceded = Escrow::deserialize(&mut &conceded_account.data[..]).unwrap();
+	assert_eq!(conceded.status, ESCROW_STATUS_COMPLETED);
+	assert_eq!(conceded.winner, Some(counterparty_pubkey));
+
+	Some((seller_account.lamports, buyer_account.lamports))
+}
+
+#[tokio::test]
+async fn transfer_position_ix_success() {
+	// ASSERTIONS
+	assert_eq!(transfer_with(|_| {}, false).await, Some((BALANCE + PRICE, BALANCE - PRICE)));
+}
+
+#[tokio::test]
+async fn transfer_position_ix_keeps_address_of_original_creator() {
+	// ASSERTIONS
+	assert_eq!(transfer_with(|_| {}, true).await, Some((BALANCE + PRICE, BALANCE - PRICE)));
+}
+
+#[tokio::test]
+async fn transfer_position_ix_rejects_open_escrow() {
+	// ASSERTIONS
+	assert!(transfer_with(|escrow| escrow.status = ESCROW_STATUS_OPEN, true).await.is_none());
+}
+
+#[tokio::test]
+async fn transfer_position_ix_rejects_games_and_payments() {
+	// ASSERTIONS
+	assert!(transfer_with(|escrow| escrow.game = Some(Game::default()), false).await.is_none());
+	assert!(transfer_with(|escrow| escrow.stream = Some(Stream::default()), false).await.is_none());
+	assert!(transfer_with(|escrow| escrow.schedule = Some(Schedule::default()), false).await.is_none());
+}
@@ -0,0 +1,148 @@
+pub mod common;
+
+use {
+    borsh::BorshDeserialize,
+    common::{
+		escrow_account,
+		get_program_test,
+		nescrow_ix_interface,
+	},
+    nescrow::{
+        generated::state::{Escrow, Stream},
+        src::create_escrow::{ESCROW_STATUS_ACCEPTED, ESCROW_STATUS_COMPLETED},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+
+const START_TIME: i64 = 1_700_000_000;
+const END_TIME: i64 = START_TIME + 1_000;
+
+/// Withdraw at `now` from a 10 million lamport stream of which `withdrawn`
+/// was already taken, returning the escrow and the beneficiary's lamports
+async fn withdraw_at(now: i64, withdrawn: u64) -> Option<(Escrow, u64)> {
+	withdraw_from(START_TIME, END_TIME, now, withdrawn).await
+}
+
+/// Withdraw at `now` from a stream vesting between `start_time` and
+/// `end_time`, as [`withdraw_at`] does
+async fn withdraw_from(start_time: i64, end_time: i64, now: i64, withdrawn: u64) -> Option<(Escrow, u64)> {
+	let mut program_test = get_program_test();
+
+	// DATA
+	let creator: Pubkey = Pubkey::new_unique();
+	let counter: u64 = Default::default();
+	let amount: u64 = 10_000_000;
+
+	// KEYPAIR
+	let fee_payer_keypair = Keypair::new();
+	let beneficiary_keypair = Keypair::new();
+
+	// PUBKEY
+	let fee_payer_pubkey = fee_payer_keypair.pubkey();
+	let beneficiary_pubkey = beneficiary_keypair.pubkey();
+
+	// PDA
+	let (escrow_pda, escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow",
+			creator.as_ref(),
+			counter.to_le_bytes().as_ref(),
+		],
+		&nescrow_ix_interface::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		fee_payer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: Pubkey::default(),
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		escrow_pda,
+		escrow_account(
+			&Escrow {
+				creator,
+				taker: Some(beneficiary_pubkey),
+				amount,
+				status: ESCROW_STATUS_ACCEPTED,
+				escrow_bump: escrow_pda_bump,
+				counter,
+				stream: Some(Stream {
+					start_time,
+					end_time,
+					withdrawn,
+				}),
+				..Default::default()
+			},
+			amount - withdrawn,
+		),
+	);
+
+	// INSTRUCTIONS
+	let context = program_test.start_with_context().await;
+	context.set_sysvar(&Clock {
+		unix_timestamp: now,
+		..Default::default()
+	});
+
+	let ix = nescrow_ix_interface::withdraw_ix_setup(
+		&fee_payer_keypair,
+		escrow_pda,
+		&beneficiary_keypair,
+		creator,
+		counter,
+		context.last_blockhash,
+	);
+
+	context.banks_client.process_transaction(ix).await.ok()?;
+
+	let escrow_account = context.banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+	let escrow = Escrow::deserialize(&mut &escrow_account.data[..]).unwrap();
+	let beneficiary_account = context.banks_client.get_account(beneficiary_pubkey).await.unwrap().unwrap();
+	Some((escrow, beneficiary_account.lamports))
+}
+
+#[tokio::test]
+async fn withdraw_ix_success() {
+	let (escrow, beneficiary_lamports) = withdraw_at(START_TIME + 250, 1_000_000).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(beneficiary_lamports, 1_500_000);
+	assert_eq!(escrow.status, ESCROW_STATUS_ACCEPTED);
+	assert_eq!(escrow.stream.unwrap().withdrawn, 2_500_000);
+}
+
+#[tokio::test]
+async fn withdraw_ix_completes_stream() {
+	let (escrow, beneficiary_lamports) = withdraw_at(END_TIME + 1, 2_500_000).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(beneficiary_lamports, 7_500_000);
+	assert_eq!(escrow.status, ESCROW_STATUS_COMPLETED);
+	assert_eq!(escrow.winner, escrow.taker);
+}
+
+#[tokio::test]
+async fn withdraw_ix_rejects_nothing_vested() {
+	// ASSERTIONS
+	assert!(withdraw_at(START_TIME, 0).await.is_none());
+	assert!(withdraw_at(START_TIME + 250, 2_500_000).await.is_none());
+}
+
+#[tokio::test]
+async fn withdraw_ix_handles_widest_stream() {
+	let (escrow, beneficiary_lamports) = withdraw_from(i64::MIN, i64::MAX, 0, 0).await.unwrap();
+
+	// ASSERTIONS
+	assert_eq!(beneficiary_lamports, 5_000_000);
+	assert_eq!(escrow.stream.unwrap().withdrawn, 5_000_000);
+}